@@ -0,0 +1,100 @@
+use std::net::{Ipv4Addr, SocketAddr};
+
+use actix::{Actor, System};
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use log::debug;
+use socket2::{Domain, Protocol, Socket, Type};
+use tokio::{
+    net::UdpSocket,
+    sync::{mpsc, oneshot},
+};
+use url::Url;
+
+use common::{
+    constants::DISCOVERY_PORT,
+    messages::discovery::Announcement,
+    terminal::{actor::TerminalActor, messages::Stop},
+};
+
+use self::terminal::DiscoveryTerminal;
+
+pub mod terminal;
+
+/// A game announced on the local network
+#[derive(Debug, Clone)]
+pub struct DiscoveredGame {
+    pub address: SocketAddr, // where the announcement came from
+    pub announcement: Announcement,
+    pub last_seen: DateTime<Utc>,
+}
+
+impl DiscoveredGame {
+    /// The address the client connects to in order to join the game
+    ///
+    /// # Errors
+    /// - If the announced address is not a valid url
+    pub fn url(&self) -> anyhow::Result<Url> {
+        let scheme = if self.announcement.tls { "wss" } else { "ws" };
+        let address = SocketAddr::new(self.address.ip(), self.announcement.port);
+
+        Ok(Url::parse(&format!("{scheme}://{address}"))?)
+    }
+}
+
+/// Binds the discovery port; several clients on one computer can listen at the same time
+fn bind_discovery_socket() -> anyhow::Result<UdpSocket> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_reuse_address(true)?;
+    socket.set_nonblocking(true)?;
+    socket.bind(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, DISCOVERY_PORT)).into())?;
+
+    Ok(UdpSocket::from_std(socket.into())?)
+}
+
+async fn listen(
+    socket: UdpSocket,
+    tx: mpsc::UnboundedSender<(SocketAddr, Announcement)>,
+) -> anyhow::Result<()> {
+    let mut buffer = [0; 1024];
+
+    loop {
+        let (length, address) = socket.recv_from(&mut buffer).await?;
+
+        // anything else sent to the port is ignored
+        match serde_json::from_slice::<Announcement>(&buffer[..length]) {
+            Ok(announcement) => tx.send((address, announcement))?,
+            Err(e) => debug!("Ignoring a datagram from {address}: {e}"),
+        }
+    }
+}
+
+/// Lists the games announced on the local network and lets the student choose one of them.
+/// Returns the address of the chosen game, or `None` if the student quit instead.
+///
+/// # Errors
+/// - If the discovery port cannot be bound
+/// - If the terminal cannot be initialized
+pub async fn choose_game() -> anyhow::Result<Option<Url>> {
+    let socket = bind_discovery_socket()
+        .with_context(|| format!("Cannot listen on UDP port {DISCOVERY_PORT}"))?;
+
+    let (tx_announcements, rx_announcements) = mpsc::unbounded_channel();
+    let (tx_chosen, rx_chosen) = oneshot::channel();
+
+    let listener = tokio::spawn(listen(socket, tx_announcements));
+
+    let terminal = TerminalActor::new(DiscoveryTerminal::new(rx_announcements, tx_chosen))?.start();
+
+    // the sender is dropped without a game when the student quits
+    let chosen = rx_chosen.await.ok();
+    listener.abort();
+
+    if chosen.is_some() {
+        terminal.send(Stop).await??;
+    } else {
+        System::current().stop();
+    }
+
+    Ok(chosen)
+}
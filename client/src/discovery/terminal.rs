@@ -0,0 +1,189 @@
+use std::net::SocketAddr;
+
+use crossterm::event::KeyCode;
+use log::{debug, warn};
+use ratatui::{
+    backend::Backend,
+    prelude::*,
+    widgets::{List, ListItem, ListState, Paragraph},
+    Terminal,
+};
+use tokio::sync::{mpsc, oneshot};
+use url::Url;
+
+use common::{
+    constants::{DISCOVERY_EXPIRY_SECS, MINIMAL_SCREEN_HEIGHT, MINIMAL_SCREEN_WIDTH},
+    messages::discovery::Announcement,
+    terminal::{
+        actor::{TerminalDraw, TerminalHandleInput, TerminalHandleTick, TerminalStop},
+        input_utils::move_in_list,
+        render::{self, get_bordered_block, list_layout},
+    },
+};
+
+use super::DiscoveredGame;
+
+const TITLE: &str = "Clihoot";
+
+#[allow(clippy::module_name_repetitions)]
+pub struct DiscoveryTerminal {
+    pub games: Vec<DiscoveredGame>,
+    pub list_state: ListState,
+    announcements: mpsc::UnboundedReceiver<(SocketAddr, Announcement)>,
+    chosen: Option<oneshot::Sender<Url>>, // taken when the student chooses a game or quits
+}
+
+impl DiscoveryTerminal {
+    #[must_use]
+    pub fn new(
+        announcements: mpsc::UnboundedReceiver<(SocketAddr, Announcement)>,
+        chosen: oneshot::Sender<Url>,
+    ) -> Self {
+        Self {
+            games: Vec::new(),
+            list_state: ListState::default(),
+            announcements,
+            chosen: Some(chosen),
+        }
+    }
+
+    fn add_announcement(&mut self, address: SocketAddr, announcement: Announcement) {
+        let last_seen = chrono::Utc::now();
+
+        // the same game is announced over and over
+        if let Some(game) = self.games.iter_mut().find(|game| {
+            game.address.ip() == address.ip() && game.announcement.port == announcement.port
+        }) {
+            game.address = address;
+            game.announcement = announcement;
+            game.last_seen = last_seen;
+            return;
+        }
+
+        debug!("Discovered game '{}' at {address}", announcement.quiz_name);
+        self.games.push(DiscoveredGame {
+            address,
+            announcement,
+            last_seen,
+        });
+    }
+}
+
+impl TerminalHandleTick for DiscoveryTerminal {
+    fn handle_tick(&mut self) -> anyhow::Result<()> {
+        while let Ok((address, announcement)) = self.announcements.try_recv() {
+            self.add_announcement(address, announcement);
+        }
+
+        // servers which stopped announcing themselves are gone
+        let now = chrono::Utc::now();
+        self.games
+            .retain(|game| (now - game.last_seen).num_seconds() < DISCOVERY_EXPIRY_SECS);
+
+        let selected = match self.games.len() {
+            0 => None,
+            len => Some(self.list_state.selected().unwrap_or(0).min(len - 1)),
+        };
+        self.list_state.select(selected);
+
+        Ok(())
+    }
+}
+
+impl TerminalHandleInput for DiscoveryTerminal {
+    fn handle_input(&mut self, key_code: KeyCode) {
+        let Some(mut selected) = self.list_state.selected() else {
+            return;
+        };
+
+        if key_code == KeyCode::Enter {
+            let url = match self.games[selected].url() {
+                Ok(url) => url,
+                Err(e) => {
+                    warn!("Cannot connect to the chosen game: {e}");
+                    return;
+                }
+            };
+
+            if let Some(chosen) = self.chosen.take() {
+                let _ = chosen.send(url);
+            }
+            return;
+        }
+
+        move_in_list(&mut selected, self.games.len(), key_code);
+        self.list_state.select(Some(selected));
+    }
+}
+
+impl TerminalDraw for DiscoveryTerminal {
+    fn redraw<B: Backend>(&mut self, term: &mut Terminal<B>) -> anyhow::Result<()> {
+        term.draw(|frame| {
+            if frame.size().height < MINIMAL_SCREEN_HEIGHT
+                || frame.size().width < MINIMAL_SCREEN_WIDTH
+            {
+                render::resize(frame, TITLE, MINIMAL_SCREEN_HEIGHT, MINIMAL_SCREEN_WIDTH);
+                return;
+            }
+
+            render_games(frame, &self.games, &mut self.list_state);
+        })?;
+        Ok(())
+    }
+}
+
+impl TerminalStop for DiscoveryTerminal {
+    fn stop(&mut self) -> anyhow::Result<()> {
+        debug!("Stopping terminal actor for discovery");
+        // without a chosen game, the client quits
+        self.chosen = None;
+        Ok(())
+    }
+}
+
+fn render_games(frame: &mut Frame, games: &[DiscoveredGame], list_state: &mut ListState) {
+    let layout = list_layout(
+        frame,
+        vec![Constraint::Length(1), Constraint::Percentage(90)],
+        "Choose a game and press ENTER: ",
+        " Games on the local network ",
+        TITLE,
+    );
+
+    if games.is_empty() {
+        let paragraph = Paragraph::new("Looking for games...").block(get_bordered_block());
+        frame.render_widget(paragraph, layout[1]);
+        return;
+    }
+
+    let items: Vec<_> = games
+        .iter()
+        .map(|game| {
+            let announcement = &game.announcement;
+            let mut line = format!(
+                "{} ({}:{})",
+                announcement.quiz_name,
+                game.address.ip(),
+                announcement.port
+            );
+            if announcement.pin_required {
+                line.push_str(" [PIN]");
+            }
+            if announcement.tls {
+                line.push_str(" [TLS]");
+            }
+            if announcement.locked {
+                line.push_str(" [locked]");
+            }
+
+            ListItem::new(line)
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(get_bordered_block())
+        .highlight_style(Style::default().add_modifier(Modifier::ITALIC))
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(list, layout[1], list_state);
+}
@@ -1,3 +1,4 @@
+pub mod discovery;
 pub mod music_actor;
 pub mod student;
 pub mod websocket;
@@ -1,7 +1,7 @@
 use actix::{Actor, System};
 use anyhow::Result;
 use clap::Parser;
-use client::websocket::WebsocketActor;
+use client::{discovery, websocket::WebsocketActor};
 use log::error;
 use std::{fs::File, path::PathBuf, str::FromStr};
 use url::Url;
@@ -12,21 +12,41 @@ use simplelog::{
 };
 
 use client::music_actor::MusicActor;
-use common::terminal::highlight::Theme;
+use common::{terminal::highlight::Theme, tls};
 
 fn url_parser(arg: &str) -> Result<Url, String> {
-    let destination_addr = format!("ws://{arg}");
-    Ok(Url::from_str(destination_addr.as_str())
-        .map_err(|_| "This is not valid url. Help: <host>:[port]")?)
+    // plain websocket is the default, encrypted one has to be asked for
+    let destination_addr = if arg.contains("://") {
+        arg.to_string()
+    } else {
+        format!("ws://{arg}")
+    };
+
+    let url = Url::from_str(destination_addr.as_str())
+        .map_err(|_| "This is not valid url. Help: [ws:// or wss://]<host>:[port]")?;
+
+    if !matches!(url.scheme(), "ws" | "wss") {
+        return Err("Only ws:// and wss:// addresses are supported".to_string());
+    }
+
+    Ok(url)
 }
 
 #[derive(Parser)]
 #[clap(version = "1.0", author = "Pavol Kycina")]
 pub struct Args {
-    /// Url of the clihoot server
+    /// Url of the clihoot server; use wss://<host>:[port] if the server uses TLS
     #[clap(short, long, default_value="localhost:8080", value_parser=url_parser)]
     addr: Url,
 
+    /// Look for games on the local network and choose one of them instead of using --addr
+    #[clap(short, long)]
+    discover: bool,
+
+    /// PEM file with the certificate authority to trust for wss:// addresses (e.g. a self-signed certificate)
+    #[clap(long)]
+    ca_file: Option<PathBuf>,
+
     /// No music and sounds will be played with this option
     #[clap(short, long)]
     silent: bool,
@@ -62,20 +82,43 @@ fn main() -> Result<()> {
         ),
     ])?;
 
+    let discover = args.discover;
     let url = args.addr;
     let silent = args.silent;
+    let tls_connector = tls::connector(args.ca_file.as_deref())?;
 
     let sys = actix::System::new();
 
     sys.block_on(async move {
         let addr_music_actor = MusicActor::new(silent).start();
 
+        let url = if discover {
+            match discovery::choose_game().await {
+                Ok(Some(url)) => url,
+                Ok(None) => return, // the student quit
+                Err(e) => {
+                    error!("I can't look for clihoot games on the local network: {e}");
+                    System::current().stop();
+                    return;
+                }
+            }
+        } else {
+            url
+        };
+
         // start websocket actor
         let Ok(websocket_actor) =
-            WebsocketActor::new(url.clone(), uuid, addr_music_actor, args.syntax_theme).await
+            WebsocketActor::new(
+                url.clone(),
+                tls_connector,
+                uuid,
+                addr_music_actor,
+                args.syntax_theme,
+            )
+            .await
         else {
             error!(
-                "I can't contact the specified clihoot server on address: '{url}' I am sorry 😿\n[HINT] You can use --addr to specify the clihoot address."
+                "I can't contact the specified clihoot server on address: '{url}' I am sorry 😿\n[HINT] You can use --addr to specify the clihoot address, or --discover to find games on the local network."
             );
             System::current().stop();
             return;
@@ -8,7 +8,31 @@ use common::{
     terminal::render::{self, get_bordered_block, list_layout},
 };
 
-use crate::student::states::{ColorSelectionState, NameSelectionState};
+use crate::student::states::{ColorSelectionState, NameSelectionState, PinEntryState};
+
+pub fn render_pin_entry(frame: &mut Frame, state: &PinEntryState, quiz_name: &str) {
+    let layout = list_layout(
+        frame,
+        vec![
+            Constraint::Length(1),
+            Constraint::Length(3),
+            Constraint::Percentage(80),
+        ],
+        "PIN of the game (ask your teacher): ",
+        " Welcome! ",
+        quiz_name,
+    );
+
+    let paragraph_pin = Paragraph::new(format!("{}|", state.pin)).block(get_bordered_block());
+    frame.render_widget(paragraph_pin, layout[1]);
+
+    if let Some(rejection) = &state.rejection {
+        let paragraph_rejection = Paragraph::new(rejection.as_str())
+            .fg(Color::Red)
+            .block(Block::default());
+        frame.render_widget(paragraph_rejection, layout[2]);
+    }
+}
 
 pub fn render_name_selection(frame: &mut Frame, state: &NameSelectionState, quiz_name: &str) {
     let layout = list_layout(
@@ -60,7 +84,7 @@ pub fn render_help(frame: &mut Frame) {
     let help_text = [
         ("ENTER", "Move to the next state"),
         ("CTRL C", "Exit the game"),
-        ("SPACE", "Select an option"),
+        ("SPACE", "Select an option or grab an item to move"),
         ("h", "Show this help"),
         ("↑↓ | ws", "Move up and down"),
         ("←→ | ad", "Move left and right"),
@@ -12,6 +12,7 @@ use common::{
 use crate::student::{
     draw_states::{
         render_color_selection, render_help, render_multichoice_popup, render_name_selection,
+        render_pin_entry,
     },
     states::StudentTerminalState,
     terminal::StudentTerminal,
@@ -35,7 +36,13 @@ impl TerminalDraw for StudentTerminal {
 
             match &mut self.state {
                 StudentTerminalState::StartGame => {
-                    render::welcome(frame, &self.quiz_name);
+                    render::welcome(frame, None, &self.quiz_name);
+                }
+                StudentTerminalState::PinEntry(state) => {
+                    render_pin_entry(frame, state, &self.quiz_name);
+                }
+                StudentTerminalState::LobbyLocked => {
+                    render::lobby_locked(frame, &self.quiz_name);
                 }
                 StudentTerminalState::NameSelection(state) => {
                     render_name_selection(frame, state, &self.quiz_name);
@@ -49,6 +56,7 @@ impl TerminalDraw for StudentTerminal {
                         &mut self.players,
                         &mut state.list_state,
                         Some(self.uuid),
+                        None,
                         &self.quiz_name,
                     );
                 }
@@ -69,6 +77,8 @@ impl TerminalDraw for StudentTerminal {
                             state.players_answered_count,
                             &mut state.choice_grid,
                             Some(&mut state.choice_selector_state),
+                            Some(&state.written_answer),
+                            Some(&mut state.ordering_state),
                             usize::try_from(state.duration_from_start.num_seconds())
                                 .unwrap_or(usize::MAX),
                             state.answered,
@@ -78,6 +88,13 @@ impl TerminalDraw for StudentTerminal {
                         if state.multichoice_popup_visible {
                             render_multichoice_popup(frame);
                         }
+                        if state.paused {
+                            render::paused(
+                                frame,
+                                "The teacher paused the question",
+                                " Wait for the teacher ",
+                            );
+                        }
                     }
                 }
                 StudentTerminalState::Answers(state) => {
@@ -1,17 +1,23 @@
+use std::collections::HashSet;
+
 use actix::Addr;
 use crossterm::event::KeyCode;
 use ratatui::widgets::ListState;
 
 use common::{
-    constants::{COLORS, MAXIMAL_NAME_LENGTH},
+    constants::{COLORS, MAXIMAL_NAME_LENGTH, MAXIMAL_PIN_LENGTH, MAXIMAL_WRITTEN_ANSWER_LENGTH},
     messages::{
-        network::{AnswerSelected, JoinRequest, PlayerData},
+        network::{AnswerSelected, JoinRequest, PlayerData, TryJoinRequest, WrittenAnswer},
         ClientNetworkMessage,
     },
+    questions::QuestionType,
     terminal::{
         actor::TerminalHandleInput,
         input_utils::move_in_list,
-        widgets::choice::{Grid, SelectorState},
+        widgets::{
+            choice::{Grid, SelectorState},
+            ordering::OrderingState,
+        },
     },
 };
 use uuid::Uuid;
@@ -20,8 +26,8 @@ use crate::{
     music_actor::{MusicActor, SoundEffectMessage},
     student::{
         states::{
-            ColorSelectionState, NameSelectionState, QuestionState, StudentTerminalState,
-            WaitingForGameState,
+            ColorSelectionState, NameSelectionState, PinEntryState, QuestionState,
+            StudentTerminalState, WaitingForGameState,
         },
         terminal::StudentTerminal,
     },
@@ -37,9 +43,16 @@ impl TerminalHandleInput for StudentTerminal {
             return;
         }
 
-        if key_code == KeyCode::Char('h')
-            && !matches!(self.state, StudentTerminalState::NameSelection { .. })
-        {
+        // 'h' is an ordinary letter when the player is typing
+        let typing = match &self.state {
+            StudentTerminalState::NameSelection(_) | StudentTerminalState::PinEntry(_) => true,
+            StudentTerminalState::Question(state) => {
+                state.question.kind.is_written() && !state.answered
+            }
+            _ => false,
+        };
+
+        if key_code == KeyCode::Char('h') && !typing {
             self.music_address.do_send(SoundEffectMessage::Tap);
             self.help_visible = true;
             return;
@@ -47,18 +60,52 @@ impl TerminalHandleInput for StudentTerminal {
 
         match &mut self.state {
             StudentTerminalState::StartGame if key_code == KeyCode::Enter => {
+                if self.pin_required {
+                    self.state = StudentTerminalState::PinEntry(PinEntryState {
+                        pin: String::new(),
+                        rejection: None,
+                    });
+                    return;
+                }
+
                 self.state = StudentTerminalState::NameSelection(NameSelectionState {
                     name: String::new(),
                     name_already_used: false,
                 });
             }
+            StudentTerminalState::LobbyLocked if key_code == KeyCode::Enter => {
+                self.music_address.do_send(SoundEffectMessage::EnterPressed);
+
+                // the server answers with another `TryJoinResponse`
+                self.ws_actor_address
+                    .do_send(ClientNetworkMessage::TryJoinRequest(TryJoinRequest::new(
+                        self.uuid,
+                    )));
+            }
+            StudentTerminalState::PinEntry(state) => {
+                let confirmed = input_pin(&mut state.pin, key_code);
+
+                if confirmed {
+                    self.music_address.do_send(SoundEffectMessage::EnterPressed);
+                    state.rejection = None;
+
+                    // the server answers with another `TryJoinResponse`
+                    self.ws_actor_address
+                        .do_send(ClientNetworkMessage::TryJoinRequest(TryJoinRequest {
+                            pin: Some(state.pin.clone()),
+                            ..TryJoinRequest::new(self.uuid)
+                        }));
+                }
+            }
             StudentTerminalState::NameSelection(state) => {
-                if input_name(
+                let confirmed = input_name(
                     &mut state.name,
                     key_code,
                     &self.players,
                     &mut state.name_already_used,
-                ) {
+                );
+
+                if confirmed {
                     self.music_address.do_send(SoundEffectMessage::EnterPressed);
                     self.name = (*state.name).to_string();
                     self.state = StudentTerminalState::ColorSelection(ColorSelectionState {
@@ -110,12 +157,58 @@ impl TerminalHandleInput for StudentTerminal {
                 }
             }
             StudentTerminalState::Question(state) => {
-                if (usize::try_from(state.duration_from_start.num_seconds()).unwrap_or(usize::MAX))
-                    < state.question.show_choices_after
+                // the server does not accept answers while the question is paused
+                if state.paused
+                    || (usize::try_from(state.duration_from_start.num_seconds())
+                        .unwrap_or(usize::MAX))
+                        < state.question.show_choices_after
                 {
                     return;
                 }
 
+                if state.question.kind.is_written() {
+                    if state.answered {
+                        return;
+                    }
+
+                    if let Some(answer) = input_written_answer(key_code, state) {
+                        self.music_address.do_send(SoundEffectMessage::EnterPressed);
+                        state.answered = true;
+                        self.ws_actor_address
+                            .do_send(ClientNetworkMessage::AnswerSelected(AnswerSelected {
+                                player_uuid: self.uuid,
+                                question_index: state.question.question_index,
+                                answers: HashSet::new(),
+                                written_answer: Some(answer),
+                                order: None,
+                            }));
+                    }
+                    return;
+                }
+
+                if state.question.kind == QuestionType::Ordering {
+                    if state.answered {
+                        return;
+                    }
+
+                    if key_code == KeyCode::Enter {
+                        self.music_address.do_send(SoundEffectMessage::EnterPressed);
+                        state.answered = true;
+                        self.ws_actor_address
+                            .do_send(ClientNetworkMessage::AnswerSelected(AnswerSelected {
+                                player_uuid: self.uuid,
+                                question_index: state.question.question_index,
+                                answers: HashSet::new(),
+                                written_answer: None,
+                                order: Some(state.ordering_state.order()),
+                            }));
+                        return;
+                    }
+
+                    move_in_ordering(key_code, &mut state.ordering_state, &self.music_address);
+                    return;
+                }
+
                 if state.multichoice_popup_visible {
                     if key_code == KeyCode::Char('y') {
                         self.music_address.do_send(SoundEffectMessage::EnterPressed);
@@ -181,6 +274,24 @@ fn empty_name(name: &str) -> bool {
     name.trim().is_empty()
 }
 
+/// Edits the PIN, returns true once the player confirms it
+fn input_pin(pin: &mut String, key_code: KeyCode) -> bool {
+    match key_code {
+        KeyCode::Backspace => {
+            pin.pop();
+            false
+        }
+        KeyCode::Char(char) if char.is_ascii_digit() => {
+            if pin.len() < MAXIMAL_PIN_LENGTH {
+                pin.push(char);
+            }
+            false
+        }
+        KeyCode::Enter => !pin.is_empty(),
+        _ => false,
+    }
+}
+
 fn input_name(
     name: &mut String,
     key_code: KeyCode,
@@ -208,6 +319,24 @@ fn input_name(
     }
 }
 
+/// Edits the written answer, returns it once the player confirms a valid one
+fn input_written_answer(key_code: KeyCode, state: &mut QuestionState) -> Option<WrittenAnswer> {
+    match key_code {
+        KeyCode::Backspace => {
+            state.written_answer.pop();
+            None
+        }
+        KeyCode::Char(char) => {
+            if state.written_answer.chars().count() < MAXIMAL_WRITTEN_ANSWER_LENGTH {
+                state.written_answer.push(char);
+            }
+            None
+        }
+        KeyCode::Enter => WrittenAnswer::parse(state.question.kind, &state.written_answer),
+        _ => None,
+    }
+}
+
 fn move_in_answers(
     key_code: KeyCode,
     choice_selector_state: &mut SelectorState,
@@ -245,10 +374,32 @@ fn move_in_answers(
     }
 }
 
+fn move_in_ordering(
+    key_code: KeyCode,
+    ordering_state: &mut OrderingState,
+    music_address: &Addr<MusicActor>,
+) {
+    let moved = match key_code {
+        KeyCode::Char(' ') => {
+            ordering_state.toggle_grab();
+            true
+        }
+        KeyCode::Down | KeyCode::Char('s') => ordering_state.move_down(),
+        KeyCode::Up | KeyCode::Char('w') => ordering_state.move_up(),
+        _ => false,
+    };
+
+    if moved {
+        music_address.do_send(SoundEffectMessage::Tap);
+    }
+}
+
 fn handle_send(ws_actor_address: &Addr<WebsocketActor>, uuid: Uuid, state: &mut QuestionState) {
     ws_actor_address.do_send(ClientNetworkMessage::AnswerSelected(AnswerSelected {
         player_uuid: uuid,
         question_index: state.question.question_index,
         answers: state.choice_selector_state.selected(),
+        written_answer: None,
+        order: None,
     }));
 }
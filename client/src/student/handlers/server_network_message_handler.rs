@@ -4,17 +4,26 @@ use std::collections::HashSet;
 use uuid::Uuid;
 
 use common::{
-    constants::NICKNAME_ALREADY_TAKEN_MSG,
-    messages::{network::CanJoin, ServerNetworkMessage},
-    terminal::{actor::TerminalHandleServerNetworkMessage, widgets::choice::SelectorState},
+    constants::{
+        LOBBY_LOCKED_MSG, NICKNAME_ALREADY_TAKEN_MSG, PIN_REQUIRED_MSG, TOO_MANY_PIN_ATTEMPTS_MSG,
+        WRONG_PIN_MSG,
+    },
+    messages::{
+        network::{CanJoin, GameSnapshot, ReconnectResponse},
+        ServerNetworkMessage,
+    },
+    terminal::{
+        actor::TerminalHandleServerNetworkMessage,
+        widgets::{choice::SelectorState, ordering::OrderingState},
+    },
 };
 
 use crate::{
     music_actor::{MusicMessage, SoundEffectMessage},
     student::{
         states::{
-            AnswersState, ErrorState, NameSelectionState, QuestionState, ResultsState,
-            StudentTerminalState, WaitingForGameState,
+            AnswersState, ErrorState, NameSelectionState, PinEntryState, QuestionState,
+            ResultsState, StudentTerminalState, WaitingForGameState,
         },
         terminal::StudentTerminal,
     },
@@ -35,28 +44,42 @@ impl TerminalHandleServerNetworkMessage for StudentTerminal {
                             name: self.name.clone(),
                             name_already_used: true,
                         });
+                    } else if message == LOBBY_LOCKED_MSG {
+                        self.state = StudentTerminalState::LobbyLocked;
                     } else {
                         self.state = StudentTerminalState::Error(ErrorState { message });
                     }
                     return Ok(());
                 }
-                self.state = StudentTerminalState::WaitingForGame(WaitingForGameState {
-                    list_state: ListState::default().with_selected(Some(0)),
-                });
+
+                // a late player catches up with the game right away
+                if join.snapshot == GameSnapshot::WaitingForGame {
+                    self.state = StudentTerminalState::WaitingForGame(WaitingForGameState {
+                        list_state: ListState::default().with_selected(Some(0)),
+                    });
+                } else {
+                    self.show_snapshot(join.snapshot);
+                }
             }
             ServerNetworkMessage::NextQuestion(question) => {
                 debug!("Student: handling next question");
                 self.music_address.do_send(MusicMessage::Countdown);
-                self.state = StudentTerminalState::Question(QuestionState {
+                let mut state = QuestionState {
                     question: question.clone(),
                     players_answered_count: 0,
                     answered: false,
                     start_time: chrono::Utc::now(),
                     duration_from_start: chrono::Duration::zero(),
+                    ordering_state: OrderingState::new(&question.question.choices),
                     choice_grid: question.question.into(),
                     choice_selector_state: SelectorState::default(),
+                    written_answer: String::new(),
                     multichoice_popup_visible: false,
-                });
+                    paused: false,
+                };
+                // the question may have taken a while to arrive
+                state.sync_countdown(self.clock_offset, chrono::Duration::zero());
+                self.state = StudentTerminalState::Question(state);
             }
             ServerNetworkMessage::QuestionUpdate(update) => {
                 debug!("Student: handling question update");
@@ -71,26 +94,69 @@ impl TerminalHandleServerNetworkMessage for StudentTerminal {
                 self.music_address.do_send(SoundEffectMessage::Beep);
                 state.players_answered_count = update.players_answered_count;
             }
+            ServerNetworkMessage::PauseUpdate(update) => {
+                debug!("Student: handling pause update");
+                let StudentTerminalState::Question(state) = &mut self.state else {
+                    anyhow::bail!("Terminal is not showing the question");
+                };
+
+                if state.question.question_index != update.question_index {
+                    anyhow::bail!("Terminal is not showing the question with given index");
+                }
+
+                let music = if update.paused {
+                    MusicMessage::NoMusic
+                } else {
+                    MusicMessage::Countdown
+                };
+                self.music_address.do_send(music);
+
+                // the countdown continues from the time the server measured
+                let elapsed =
+                    chrono::Duration::seconds(update.elapsed_seconds.try_into().unwrap_or(0));
+                state.paused = update.paused;
+                state.question.deadline = update.deadline;
+                state.sync_countdown(self.clock_offset, elapsed);
+            }
+            ServerNetworkMessage::QuestionTimeUpdate(update) => {
+                debug!("Student: handling question time update");
+                let StudentTerminalState::Question(state) = &mut self.state else {
+                    anyhow::bail!("Terminal is not showing the question");
+                };
+
+                if state.question.question_index != update.question_index {
+                    anyhow::bail!("Terminal is not showing the question with given index");
+                }
+
+                // the deadline moves along with the time of the question
+                let elapsed = state.duration_from_start;
+                state.question.question.time_seconds = update.time_seconds;
+                state.question.deadline = update.deadline;
+                state.sync_countdown(self.clock_offset, elapsed);
+            }
             ServerNetworkMessage::QuestionEnded(question) => {
                 debug!("Student: handling question ended");
                 self.music_address.do_send(SoundEffectMessage::Gong);
                 self.music_address.do_send(MusicMessage::NoMusic);
 
-                let correct_uuids: HashSet<Uuid> = question
-                    .question
-                    .choices
-                    .iter()
-                    .filter(|x| x.is_correct)
-                    .map(|x| x.id)
-                    .collect();
+                let correct_uuids: HashSet<Uuid> = question.question.correct_choices();
 
                 if let Some(player_answer) = question.player_answer.clone() {
-                    let sound_to_play =
-                        if player_answer.iter().any(|ans| correct_uuids.contains(ans)) {
-                            SoundEffectMessage::CorrectAnswer
-                        } else {
-                            SoundEffectMessage::WrongAnswer
-                        };
+                    let correct = if question.question.kind.has_choices() {
+                        player_answer.iter().any(|ans| correct_uuids.contains(ans))
+                    } else {
+                        question.question.is_answered_correctly(
+                            &player_answer,
+                            question.player_written_answer.as_ref(),
+                            question.player_order.as_deref(),
+                        )
+                    };
+
+                    let sound_to_play = if correct {
+                        SoundEffectMessage::CorrectAnswer
+                    } else {
+                        SoundEffectMessage::WrongAnswer
+                    };
 
                     self.music_address.do_send(sound_to_play);
                 }
@@ -114,11 +180,119 @@ impl TerminalHandleServerNetworkMessage for StudentTerminal {
                     message: "Teacher disconnected from the game".to_string(),
                 });
             }
-            ServerNetworkMessage::TryJoinResponse(_) => {
+            ServerNetworkMessage::TryJoinResponse(response) => {
+                // the first response starts the student, later ones answer the entered PIN or a retry in a locked lobby
                 debug!("Student: handling try join response");
-                unreachable!("Student should not receive TryJoinResponse");
+                match response.can_join {
+                    CanJoin::Yes => {
+                        self.state = StudentTerminalState::NameSelection(NameSelectionState {
+                            name: self.name.clone(),
+                            name_already_used: false,
+                        });
+                    }
+                    CanJoin::No(message) if message == LOBBY_LOCKED_MSG => {
+                        self.state = StudentTerminalState::LobbyLocked;
+                    }
+                    CanJoin::No(message) if message == PIN_REQUIRED_MSG => {
+                        self.state = StudentTerminalState::PinEntry(PinEntryState {
+                            pin: String::new(),
+                            rejection: None,
+                        });
+                    }
+                    CanJoin::No(message)
+                        if message == WRONG_PIN_MSG || message == TOO_MANY_PIN_ATTEMPTS_MSG =>
+                    {
+                        self.state = StudentTerminalState::PinEntry(PinEntryState {
+                            pin: String::new(),
+                            rejection: Some(message),
+                        });
+                    }
+                    CanJoin::No(message) => {
+                        self.state = StudentTerminalState::Error(ErrorState { message });
+                    }
+                }
+            }
+            ServerNetworkMessage::ReconnectResponse(reconnect) => {
+                debug!("Student: handling reconnect response");
+                self.restore_from_snapshot(reconnect);
+            }
+            ServerNetworkMessage::TeacherLoginResponse(_)
+            | ServerNetworkMessage::LockUpdate(_)
+            | ServerNetworkMessage::QuestionList(_)
+            | ServerNetworkMessage::AnswerDistribution(_)
+            | ServerNetworkMessage::AnswerDetails(_)
+            | ServerNetworkMessage::GameAnalytics(_) => {
+                debug!("Student: ignoring a message meant for the teacher");
             }
         }
         Ok(())
     }
 }
+
+impl StudentTerminal {
+    fn restore_from_snapshot(&mut self, reconnect: ReconnectResponse) {
+        if let CanJoin::No(message) = reconnect.can_reconnect {
+            self.music_address.do_send(MusicMessage::NoMusic);
+            self.state = StudentTerminalState::Error(ErrorState { message });
+            return;
+        }
+
+        self.players = reconnect.players;
+        if let Some(player) = reconnect.player {
+            self.name = player.nickname;
+            self.color = player.color;
+        }
+
+        self.show_snapshot(reconnect.snapshot);
+    }
+
+    fn show_snapshot(&mut self, snapshot: GameSnapshot) {
+        self.state = match snapshot {
+            GameSnapshot::WaitingForGame => {
+                self.music_address.do_send(MusicMessage::Lobby);
+                StudentTerminalState::WaitingForGame(WaitingForGameState {
+                    list_state: ListState::default().with_selected(Some(0)),
+                })
+            }
+            GameSnapshot::Question {
+                question,
+                players_answered_count,
+                player_answer,
+                elapsed_seconds,
+                paused,
+            } => {
+                if !paused {
+                    self.music_address.do_send(MusicMessage::Countdown);
+                }
+                let elapsed = chrono::Duration::seconds(elapsed_seconds.try_into().unwrap_or(0));
+
+                let mut state = QuestionState {
+                    question: question.clone(),
+                    players_answered_count,
+                    answered: player_answer.is_some(),
+                    start_time: chrono::Utc::now(),
+                    duration_from_start: chrono::Duration::zero(),
+                    ordering_state: OrderingState::new(&question.question.choices),
+                    choice_grid: question.question.into(),
+                    choice_selector_state: SelectorState::default(),
+                    written_answer: String::new(),
+                    multichoice_popup_visible: false,
+                    paused,
+                };
+                state.sync_countdown(self.clock_offset, elapsed);
+                StudentTerminalState::Question(state)
+            }
+            GameSnapshot::QuestionEnded(answers) => {
+                self.music_address.do_send(MusicMessage::NoMusic);
+                StudentTerminalState::Answers(AnswersState { answers })
+            }
+            GameSnapshot::Leaderboard(results) => {
+                self.music_address.do_send(MusicMessage::NoMusic);
+                StudentTerminalState::Results(ResultsState {
+                    results,
+                    table_state: TableState::default().with_selected(Some(0)),
+                })
+            }
+        };
+    }
+}
@@ -8,6 +8,10 @@ impl TerminalHandleTick for StudentTerminal {
             return Ok(());
         };
 
+        if state.paused {
+            return Ok(());
+        }
+
         let current_time = chrono::Utc::now();
 
         state.duration_from_start = current_time - state.start_time;
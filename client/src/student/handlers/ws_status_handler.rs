@@ -33,6 +33,11 @@ impl TerminalHandleClientWebsocketStatus for StudentTerminal {
                 self.music_address.do_send(MusicMessage::NoMusic);
                 self.state = StudentTerminalState::Error(ErrorState { message });
             }
+            ClientWebsocketStatus::Reconnecting(attempt) => {
+                self.state = StudentTerminalState::Error(ErrorState {
+                    message: format!("Connection lost, reconnecting (attempt {attempt})..."),
+                });
+            }
         }
         Ok(())
     }
@@ -2,9 +2,18 @@ use ratatui::widgets::{ListState, TableState};
 
 use common::{
     messages::network::{NextQuestion, QuestionEnded, ShowLeaderboard},
-    terminal::widgets::choice::{Grid, SelectorState},
+    terminal::widgets::{
+        choice::{Grid, SelectorState},
+        ordering::OrderingState,
+    },
 };
 
+#[derive(Debug)]
+pub struct PinEntryState {
+    pub(super) pin: String,
+    pub(super) rejection: Option<String>, // why the last PIN was not accepted
+}
+
 #[derive(Debug)]
 pub struct NameSelectionState {
     pub(super) name: String,
@@ -30,7 +39,28 @@ pub struct QuestionState {
     pub(super) duration_from_start: chrono::Duration,
     pub(super) choice_grid: Grid,
     pub(super) choice_selector_state: SelectorState,
+    pub(super) written_answer: String, // what the player typed in a text or numeric question
+    pub(super) ordering_state: OrderingState,
     pub(super) multichoice_popup_visible: bool,
+    pub(super) paused: bool, // the countdown is frozen until the teacher resumes the question
+}
+
+impl QuestionState {
+    /// Moves the start of the countdown, so that it runs out right at the deadline of the server;
+    /// if the server did not send the deadline (or the question is paused), `elapsed` is taken as it is
+    pub(super) fn sync_countdown(
+        &mut self,
+        clock_offset: chrono::Duration,
+        elapsed: chrono::Duration,
+    ) {
+        let now = chrono::Utc::now();
+
+        self.start_time = self
+            .question
+            .local_start_time(clock_offset)
+            .unwrap_or(now - elapsed);
+        self.duration_from_start = now - self.start_time;
+    }
 }
 
 #[derive(Debug)]
@@ -52,6 +82,8 @@ pub struct ErrorState {
 #[derive(Debug)]
 pub enum StudentTerminalState {
     StartGame,
+    PinEntry(PinEntryState),
+    LobbyLocked, // the teacher does not let anyone in at the moment
     NameSelection(NameSelectionState),
     ColorSelection(ColorSelectionState),
     WaitingForGame(WaitingForGameState),
@@ -5,7 +5,8 @@ use ratatui::style::Color;
 use uuid::Uuid;
 
 use common::{
-    messages::network::PlayerData,
+    constants::{LOBBY_LOCKED_MSG, PIN_REQUIRED_MSG},
+    messages::network::{CanJoin, PlayerData},
     terminal::{
         actor::{TerminalActor, TerminalStop},
         highlight::Theme,
@@ -14,7 +15,7 @@ use common::{
 
 use crate::{
     music_actor::{MusicActor, MusicMessage},
-    student::states::StudentTerminalState,
+    student::states::{ErrorState, StudentTerminalState},
     websocket::WebsocketActor,
 };
 
@@ -24,6 +25,8 @@ pub struct StudentTerminal {
     pub name: String,
     pub color: Color,
     pub quiz_name: String,
+    pub pin_required: bool, // the player has to enter the PIN of the game before choosing a name
+    pub clock_offset: chrono::Duration, // how far the clock of the server is ahead of ours
     pub syntax_theme: Theme,
     pub help_visible: bool,
     pub players: Vec<PlayerData>,
@@ -46,6 +49,8 @@ impl StudentTerminal {
             name: String::new(),
             color: Color::default(),
             quiz_name,
+            pin_required: false,
+            clock_offset: chrono::Duration::zero(),
             help_visible: false,
             players: Vec::new(),
             ws_actor_address: ws_addr,
@@ -67,20 +72,38 @@ impl TerminalStop for StudentTerminal {
 pub fn run_student(
     uuid: Uuid,
     quiz_name: String,
+    can_join: CanJoin,
+    clock_offset: chrono::Duration,
     ws_actor_addr: Addr<WebsocketActor>,
     music_actor_addr: &Addr<MusicActor>,
     syntax_theme: Theme,
 ) -> anyhow::Result<Addr<TerminalActor<StudentTerminal>>> {
-    let term = TerminalActor::new(StudentTerminal::new(
+    let mut student = StudentTerminal::new(
         uuid,
         quiz_name,
         ws_actor_addr,
         music_actor_addr.clone(),
         syntax_theme,
-    ))?
-    .start();
+    );
+    student.clock_offset = clock_offset;
 
-    music_actor_addr.do_send(MusicMessage::Lobby);
+    // show the student why the server turned us down, unless a PIN or an unlocked lobby is all that is missing
+    match can_join {
+        CanJoin::No(message) if message == LOBBY_LOCKED_MSG => {
+            student.state = StudentTerminalState::LobbyLocked;
+            music_actor_addr.do_send(MusicMessage::Lobby);
+        }
+        CanJoin::No(message) if message != PIN_REQUIRED_MSG => {
+            student.state = StudentTerminalState::Error(ErrorState { message });
+        }
+        CanJoin::No(_) => {
+            student.pin_required = true;
+            music_actor_addr.do_send(MusicMessage::Lobby);
+        }
+        CanJoin::Yes => music_actor_addr.do_send(MusicMessage::Lobby),
+    }
+
+    let term = TerminalActor::new(student)?.start();
 
     Ok(term)
 }
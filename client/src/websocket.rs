@@ -6,18 +6,25 @@ use futures::{SinkExt, StreamExt};
 use log::{debug, info, warn};
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::time::Duration;
 
+use chrono::{DateTime, Utc};
 use url::Url;
 use uuid::Uuid;
 
 use tokio::net::TcpStream;
 use tokio_tungstenite::MaybeTlsStream;
-use tokio_tungstenite::{connect_async, tungstenite, WebSocketStream};
+use tokio_tungstenite::{connect_async_tls_with_config, tungstenite, Connector, WebSocketStream};
 use tungstenite::Error::ConnectionClosed;
 
 use common::{
+    clock::estimate_clock_offset,
+    constants::{
+        INCOMPATIBLE_SERVER_MSG, MINIMAL_PROTOCOL_VERSION, RECONNECT_MAX_ATTEMPTS,
+        RECONNECT_MAX_BACKOFF_SECS,
+    },
     messages::{
-        network::{self, CanJoin::No, TryJoinRequest},
+        network::{self, CanJoin, CanJoin::No, Feature, ReconnectRequest, TryJoinRequest},
         status::ClientWebsocketStatus,
         ClientNetworkMessage, ServerNetworkMessage,
         ServerNetworkMessage::TryJoinResponse,
@@ -37,11 +44,22 @@ pub struct SubscribeStatus(pub Recipient<ClientWebsocketStatus>);
 
 type Sender = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, tungstenite::protocol::Message>;
 type Receiver = SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>;
+type Stream = WebSocketStream<MaybeTlsStream<TcpStream>>;
 
 // actor which represents a gateway to the server, one can send it a request for sending a message or
 // just subscribe for incoming messages
 #[allow(clippy::module_name_repetitions)]
 pub struct WebsocketActor {
+    url: Url,
+    tls_connector: Option<Connector>, // trusts a custom certificate authority for wss:// addresses
+    uuid: Uuid,
+    student_started: bool, // the first `TryJoinResponse` starts the student terminal
+    joined: bool,          // the server knows us, so we can reconnect if the connection drops
+    reconnect_secret: String, // proves to the server that we are the player who joined
+    game_ended: bool,      // nothing to reconnect to anymore
+    reconnecting: bool,    // errors of the dropped connection are expected until we are back
+    server_features: Vec<Feature>, // optional messages the server understands
+    try_join_sent_at: DateTime<Utc>, // the clock offset is estimated from the round trip of the first request
     ws_stream_tx: Rc<RefCell<Sender>>,
     ws_stream_rx: Option<Receiver>,
     subscribers_network_messages: Vec<Recipient<ServerNetworkMessage>>,
@@ -53,22 +71,34 @@ pub struct WebsocketActor {
 impl WebsocketActor {
     pub async fn new(
         url: Url,
+        tls_connector: Option<Connector>,
         uuid: Uuid,
         music_actor_addr: Addr<MusicActor>,
         syntax_theme: Theme,
     ) -> anyhow::Result<Self> {
-        let (ws_stream, _) = connect_async(url).await?;
+        let ws_stream = connect(url.clone(), tls_connector.clone()).await?;
 
         let (tx, rx) = ws_stream.split();
         let tx_rc = Rc::new(RefCell::new(tx));
 
+        let try_join_sent_at = Utc::now();
         send_message_directly(
             tx_rc.clone(),
-            ClientNetworkMessage::TryJoinRequest(TryJoinRequest { uuid }),
+            ClientNetworkMessage::TryJoinRequest(TryJoinRequest::new(uuid)),
         )
         .await?;
 
         Ok(WebsocketActor {
+            url,
+            tls_connector,
+            uuid,
+            student_started: false,
+            joined: false,
+            reconnect_secret: String::new(),
+            game_ended: false,
+            reconnecting: false,
+            server_features: Vec::new(),
+            try_join_sent_at,
             ws_stream_rx: Some(rx),
             ws_stream_tx: tx_rc,
             subscribers_network_messages: vec![],
@@ -83,23 +113,50 @@ impl WebsocketActor {
         message: ServerNetworkMessage,
         ctx: &mut <WebsocketActor as Actor>::Context,
     ) {
+        // the student is already running, it handles the answers to the PINs it sent
+        if self.student_started {
+            return;
+        }
+
         // check if the message is the type we are looking for, otherwise ignore
         let TryJoinResponse(network::TryJoinResponse {
             uuid,
-            can_join,
+            mut can_join,
             quiz_name,
+            protocol_version,
+            features,
+            server_time,
         }) = message
         else {
             return;
         };
 
-        if let No(reason) = can_join {
+        // an older server does not send its time, so we have to trust our clock
+        let clock_offset = server_time.map_or_else(chrono::Duration::zero, |server_time| {
+            estimate_clock_offset(self.try_join_sent_at, Utc::now(), server_time)
+        });
+        debug!("the clock of the server is {clock_offset} ahead of ours");
+
+        // the server checks our version, but an older server does not know how to
+        if protocol_version < MINIMAL_PROTOCOL_VERSION {
+            can_join = No(format!(
+                "{INCOMPATIBLE_SERVER_MSG}: it speaks protocol version {protocol_version}, \
+                 but the client needs at least {MINIMAL_PROTOCOL_VERSION}"
+            ));
+        }
+
+        if let No(reason) = &can_join {
             info!("server does not allow us to join, reason: {}", reason);
         }
 
+        self.server_features = features;
+        self.student_started = true;
+
         if let Ok(student_actor_addr) = run_student(
             uuid,
             quiz_name,
+            can_join,
+            clock_offset,
             ctx.address(),
             &self.music_actor_addr,
             self.syntax_theme,
@@ -111,6 +168,102 @@ impl WebsocketActor {
             ctx.notify(SubscribeStatus(student_actor_addr.recipient()));
         };
     }
+
+    fn track_game_progress(&mut self, message: &ServerNetworkMessage) {
+        match message {
+            ServerNetworkMessage::JoinResponse(response) => {
+                self.joined = response.can_join == CanJoin::Yes;
+                if let Some(secret) = &response.reconnect_secret {
+                    self.reconnect_secret.clone_from(secret);
+                }
+            }
+            ServerNetworkMessage::ReconnectResponse(response) => {
+                self.joined = response.can_reconnect == CanJoin::Yes;
+            }
+            ServerNetworkMessage::ShowLeaderboard(leaderboard) => {
+                self.game_ended = leaderboard.was_final_round;
+            }
+            ServerNetworkMessage::TeacherDisconnected(_) => {
+                self.game_ended = true;
+            }
+            _ => {}
+        }
+    }
+
+    fn spawn_listener(&mut self, ws_stream_rx: Receiver, ctx: &mut Context<Self>) {
+        let websocket_actor_address = ctx.address().clone();
+
+        async move {
+            if let Err(_error) =
+                listen_for_messages(ws_stream_rx, websocket_actor_address.clone()).await
+            {
+                warn!("websocket failed listening");
+                websocket_actor_address.do_send(ClientWebsocketStatus::ListeningFail);
+            }
+        }
+        .into_actor(self)
+        .spawn(ctx);
+    }
+
+    /// Connects to the server again and asks it to reattach us to our player
+    fn reconnect(&mut self, ctx: &mut Context<Self>) {
+        info!("connection to the server dropped, reconnecting");
+        self.reconnecting = true;
+
+        connect_with_backoff(self.url.clone(), self.tls_connector.clone(), ctx.address())
+            .into_actor(self)
+            .map(|result, actor, ctx| {
+                actor.reconnecting = false;
+
+                let Ok(ws_stream) = result else {
+                    warn!("could not reconnect to the server");
+                    actor.joined = false;
+                    ctx.notify(ClientWebsocketStatus::ListeningFail);
+                    return;
+                };
+
+                let (tx, rx) = ws_stream.split();
+                *actor.ws_stream_tx.borrow_mut() = tx;
+                actor.spawn_listener(rx, ctx);
+
+                ctx.notify(ClientNetworkMessage::ReconnectRequest(ReconnectRequest {
+                    player_uuid: actor.uuid,
+                    secret: actor.reconnect_secret.clone(),
+                }));
+            })
+            .spawn(ctx);
+    }
+}
+
+/**
+This function connects to the server, over TLS if the url is a wss:// one.
+*/
+async fn connect(url: Url, tls_connector: Option<Connector>) -> anyhow::Result<Stream> {
+    let (ws_stream, _) = connect_async_tls_with_config(url, None, false, tls_connector).await?;
+    Ok(ws_stream)
+}
+
+/**
+This function tries to connect to the server repeatedly, waiting longer after every failed attempt.
+*/
+async fn connect_with_backoff(
+    url: Url,
+    tls_connector: Option<Connector>,
+    my_address: Addr<WebsocketActor>,
+) -> anyhow::Result<Stream> {
+    for attempt in 1..=RECONNECT_MAX_ATTEMPTS {
+        my_address.do_send(ClientWebsocketStatus::Reconnecting(attempt));
+
+        let delay = 2_u64.pow(attempt - 1).min(RECONNECT_MAX_BACKOFF_SECS);
+        tokio::time::sleep(Duration::from_secs(delay)).await;
+
+        match connect(url.clone(), tls_connector.clone()).await {
+            Ok(ws_stream) => return Ok(ws_stream),
+            Err(error) => debug!("reconnect attempt {attempt} failed: {error}"),
+        }
+    }
+
+    anyhow::bail!("server is not reachable")
 }
 
 // handler for message requests from another local actors
@@ -163,6 +316,7 @@ impl Handler<ServerNetworkMessage> for WebsocketActor {
     fn handle(&mut self, msg: ServerNetworkMessage, ctx: &mut Self::Context) -> Self::Result {
         debug!("get message from server: {:?}", msg);
 
+        self.track_game_progress(&msg);
         self.handle_try_join_response(msg.clone(), ctx);
 
         for sub in &self.subscribers_network_messages {
@@ -179,6 +333,26 @@ impl Handler<ClientWebsocketStatus> for WebsocketActor {
     fn handle(&mut self, msg: ClientWebsocketStatus, ctx: &mut Self::Context) -> Self::Result {
         debug!("get status message: {:?}", msg);
 
+        let connection_dropped = matches!(
+            msg,
+            ClientWebsocketStatus::ListeningFail
+                | ClientWebsocketStatus::CantSendMessage
+                | ClientWebsocketStatus::SocketClosed
+        );
+
+        if connection_dropped && self.reconnecting {
+            return Ok(()); // the old connection is already being replaced
+        }
+
+        if connection_dropped
+            && self.joined
+            && !self.game_ended
+            && self.server_features.contains(&Feature::Reconnect)
+        {
+            self.reconnect(ctx);
+            return Ok(());
+        }
+
         for sub in &self.subscribers_status {
             sub.do_send(msg.clone());
         }
@@ -190,6 +364,7 @@ impl Handler<ClientWebsocketStatus> for WebsocketActor {
             | ClientWebsocketStatus::CloseFrameReceived(_) => {
                 ctx.stop(); // stop websocket actor
             }
+            ClientWebsocketStatus::Reconnecting(_) => {}
         }
         Ok(())
     }
@@ -223,23 +398,13 @@ impl Actor for WebsocketActor {
             .ws_stream_rx
             .take()
             .expect("websocket receiver is None"); // this cant fail if it is correctly programmed
-        let websocket_actor_address = ctx.address().clone();
 
-        async move {
-            if let Err(_error) =
-                listen_for_messages(ws_stream_rx, websocket_actor_address.clone()).await
-            {
-                warn!("websocket failed listening");
-                websocket_actor_address.do_send(ClientWebsocketStatus::ListeningFail);
-            }
-        }
-        .into_actor(self)
-        .spawn(ctx);
+        self.spawn_listener(ws_stream_rx, ctx);
     }
 }
 
 async fn listen_for_messages(
-    mut rx_stream: SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>,
+    mut rx_stream: Receiver,
     websocket_actor_address: Addr<WebsocketActor>,
 ) -> anyhow::Result<()> {
     // listen for messages from server
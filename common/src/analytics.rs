@@ -0,0 +1,193 @@
+use std::{fmt::Write, fs, path::PathBuf};
+
+use anyhow::Context;
+
+use crate::messages::network::{GameAnalytics, PlayerAnalytics, QuestionAnalytics};
+
+/// Kind of document the summary of a game can be saved as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnalyticsFormat {
+    Markdown,
+    Html,
+}
+
+impl AnalyticsFormat {
+    #[must_use]
+    pub fn extension(self) -> &'static str {
+        match self {
+            AnalyticsFormat::Markdown => "md",
+            AnalyticsFormat::Html => "html",
+        }
+    }
+
+    #[must_use]
+    pub fn render(self, analytics: &GameAnalytics) -> String {
+        match self {
+            AnalyticsFormat::Markdown => to_markdown(analytics),
+            AnalyticsFormat::Html => to_html(analytics),
+        }
+    }
+}
+
+/// Formats a time in milliseconds as seconds with one decimal place
+#[must_use]
+pub fn format_time(ms: i64) -> String {
+    format!("{}.{} s", ms / 1000, ms % 1000 / 100)
+}
+
+/// Cells of a row of the table of the questions
+#[must_use]
+pub fn question_cells(question: &QuestionAnalytics) -> [String; 5] {
+    [
+        (question.question_index + 1).to_string(),
+        if question.voided {
+            format!("{} (voided)", question.text)
+        } else {
+            question.text.clone()
+        },
+        format!("{} %", question.percent_correct),
+        question
+            .distractor
+            .as_ref()
+            .map_or_else(String::new, |distractor| {
+                format!("{} ({}x)", distractor.answer, distractor.count)
+            }),
+        question
+            .median_answer_time_ms
+            .map_or_else(String::new, format_time),
+    ]
+}
+
+/// Cells of a row of the table of the players
+#[must_use]
+pub fn player_cells(player: &PlayerAnalytics) -> [String; 3] {
+    [
+        player.nickname.clone(),
+        format!("{} %", player.accuracy_percent),
+        player
+            .missed_questions
+            .iter()
+            .map(|index| (index + 1).to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+    ]
+}
+
+pub const QUESTION_HEADER: [&str; 5] = [
+    "#",
+    "Question",
+    "Correct",
+    "Common wrong answer",
+    "Median time",
+];
+pub const PLAYER_HEADER: [&str; 3] = ["Player", "Accuracy", "Missed questions"];
+
+/// The summary as Markdown tables, ready to be pasted into notes
+#[must_use]
+pub fn to_markdown(analytics: &GameAnalytics) -> String {
+    fn row(cells: impl IntoIterator<Item = impl AsRef<str>>) -> String {
+        let cells = cells
+            .into_iter()
+            .map(|cell| cell.as_ref().replace('|', "\\|").replace('\n', " "))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        format!("| {cells} |\n")
+    }
+
+    fn table<const N: usize>(header: [&str; N], rows: impl Iterator<Item = [String; N]>) -> String {
+        let mut table = row(header);
+        table.push_str(&row(["---"; N]));
+        for cells in rows {
+            table.push_str(&row(cells));
+        }
+        table
+    }
+
+    let mut markdown = format!("# {}\n\n", analytics.quiz_name);
+
+    markdown.push_str("## Questions\n\n");
+    markdown.push_str(&table(
+        QUESTION_HEADER,
+        analytics.questions.iter().map(question_cells),
+    ));
+
+    markdown.push_str("\n## Players\n\n");
+    markdown.push_str(&table(
+        PLAYER_HEADER,
+        analytics.players.iter().map(player_cells),
+    ));
+
+    markdown
+}
+
+/// The summary as a standalone HTML page
+#[must_use]
+pub fn to_html(analytics: &GameAnalytics) -> String {
+    fn escape(text: &str) -> String {
+        text.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+
+    fn table<const N: usize>(header: [&str; N], rows: impl Iterator<Item = [String; N]>) -> String {
+        let mut table = String::from("<table>\n<tr>");
+        for cell in header {
+            let _ = write!(table, "<th>{}</th>", escape(cell));
+        }
+        table.push_str("</tr>\n");
+
+        for cells in rows {
+            table.push_str("<tr>");
+            for cell in cells {
+                let _ = write!(table, "<td>{}</td>", escape(&cell));
+            }
+            table.push_str("</tr>\n");
+        }
+
+        table.push_str("</table>\n");
+        table
+    }
+
+    let title = escape(&analytics.quiz_name);
+
+    format!(
+        "<!DOCTYPE html>\n\
+         <html>\n\
+         <head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n</head>\n\
+         <body>\n\
+         <h1>{title}</h1>\n\
+         <h2>Questions</h2>\n{}\
+         <h2>Players</h2>\n{}\
+         </body>\n\
+         </html>\n",
+        table(
+            QUESTION_HEADER,
+            analytics.questions.iter().map(question_cells)
+        ),
+        table(PLAYER_HEADER, analytics.players.iter().map(player_cells)),
+    )
+}
+
+/// Saves the summary into the current directory, in a file named after the quiz; returns the path of the file
+/// # Errors
+/// - when the file cannot be written to
+pub fn export(analytics: &GameAnalytics, format: AnalyticsFormat) -> anyhow::Result<PathBuf> {
+    let name: String = analytics
+        .quiz_name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let path = PathBuf::from(format!("{name}-analytics.{}", format.extension()));
+
+    fs::write(&path, format.render(analytics))
+        .context(format!("Cannot write \"{}\"", path.display()))?;
+
+    Ok(path)
+}
@@ -0,0 +1,19 @@
+use chrono::{DateTime, Duration, Utc};
+
+/// Estimates how far the clock of the server is ahead of the local one (negative if it is behind).
+/// The request was sent at `sent` and its answer, made at `server_time`, arrived at `received` --
+/// the server is assumed to answer halfway through the round trip.
+#[must_use]
+pub fn estimate_clock_offset(
+    sent: DateTime<Utc>,
+    received: DateTime<Utc>,
+    server_time: DateTime<Utc>,
+) -> Duration {
+    server_time - (sent + (received - sent) / 2)
+}
+
+/// Converts an instant on the clock of the server to the local clock
+#[must_use]
+pub fn to_local_time(server_time: DateTime<Utc>, clock_offset: Duration) -> DateTime<Utc> {
+    server_time - clock_offset
+}
@@ -1,10 +1,25 @@
 use ratatui::style::Color;
 
+use crate::messages::network::Feature;
+
 pub const DEFAULT_QUIZ_NAME: &str = "Quiz";
 pub const LOBBY_LOCKED_MSG: &str = "The lobby is locked";
 pub const PLAYER_NOT_IN_WAITING_LIST_MSG: &str = "Player not in waiting list";
 pub const NICKNAME_ALREADY_TAKEN_MSG: &str = "Nickname already taken";
+pub const PIN_REQUIRED_MSG: &str = "The game is protected by a PIN";
+pub const WRONG_PIN_MSG: &str = "Wrong PIN";
+pub const TOO_MANY_PIN_ATTEMPTS_MSG: &str = "Too many wrong PINs, try again later";
+pub const TOO_MANY_ATTEMPTS_MSG: &str = "Too many failed attempts, try again later";
+pub const WRONG_TEACHER_TOKEN_MSG: &str = "Wrong teacher token";
+pub const PLAYER_ALREADY_JOINED_MSG: &str = "Player has already joined";
+pub const INCOMPATIBLE_CLIENT_MSG: &str = "Your client is not compatible with this server";
+pub const INCOMPATIBLE_SERVER_MSG: &str = "The server is not compatible with your client";
+pub const UNSUPPORTED_MESSAGE_MSG: &str =
+    "Message not understood, your client may not be compatible with this server";
 pub const DEFAULT_PORT: u16 = 8080;
+pub const DISCOVERY_PORT: u16 = 8079; // UDP port the servers announce themselves to
+pub const DISCOVERY_INTERVAL_MS: u64 = 1000;
+pub const DISCOVERY_EXPIRY_SECS: i64 = 5; // a game not announced for this long is no longer listed
 pub const DEFAULT_GOODBYE_MESSAGE: &str = "Goodbye";
 pub const MINIMAL_SCREEN_HEIGHT: u16 = 13;
 pub const MINIMAL_SCREEN_WIDTH: u16 = 45;
@@ -16,8 +31,30 @@ pub const MAXIMAL_CHOICE_LENGTH: usize = 200;
 pub const MAXIMAL_QUESTION_LENGTH: usize = 200;
 pub const MAXIMAL_CODE_LENGTH: usize = 400;
 pub const MAXIMAL_NAME_LENGTH: usize = 20;
+pub const MINIMAL_PIN_LENGTH: usize = 4;
+pub const MAXIMAL_PIN_LENGTH: usize = 8;
+pub const MAXIMAL_FAILED_ATTEMPTS: u32 = 5; // wrong PINs or secrets from one address before it has to wait
+pub const LOCKOUT_SECS: i64 = 60;
+pub const MAXIMAL_WRITTEN_ANSWER_LENGTH: usize = 50;
+pub const MAXIMAL_ORDERING_ITEMS: usize = 6;
 pub const TICK_PERIOD_MS: u64 = 500;
+pub const ANSWER_GRACE_PERIOD_MS: u64 = 500; // answers are accepted a bit after the deadline, as they travel
+pub const MAXIMAL_ANSWER_GRACE_PERIOD_MS: u64 = 5000;
+pub const SHORT_ADDED_TIME_SECS: usize = 15;
+pub const LONG_ADDED_TIME_SECS: usize = 30;
+pub const MAXIMAL_ADDED_TIME_SECS: usize = 300; // at once, so that a typo does not stall the game
 pub const PLAYER_KICKED_MESSAGE: &str = "You were kicked by the teacher";
+pub const PLAYER_CANNOT_RECONNECT_MSG: &str = "You are no longer part of this game";
+pub const RECONNECT_GRACE_PERIOD_SECS: u64 = 60;
+pub const RECONNECT_MAX_ATTEMPTS: u32 = 6;
+pub const RECONNECT_MAX_BACKOFF_SECS: u64 = 16;
+pub const PROTOCOL_VERSION: u32 = 1;
+pub const MINIMAL_PROTOCOL_VERSION: u32 = 1; // the oldest version the other side may speak
+pub const SUPPORTED_FEATURES: [Feature; 3] = [
+    Feature::Reconnect,
+    Feature::WrittenAnswers,
+    Feature::Ordering,
+];
 pub const COLORS: [Color; 7] = [
     Color::Red,
     Color::Blue,
@@ -1,6 +1,11 @@
+pub mod analytics;
+pub mod clock;
 pub mod constants;
+pub mod lint;
 pub mod messages;
+pub mod question_bank;
 pub mod questions;
 pub mod terminal;
 pub mod test_macros;
 pub mod test_utils;
+pub mod tls;
@@ -0,0 +1,372 @@
+use std::{
+    collections::HashSet,
+    fmt,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use crate::questions::{
+    normalize_text_answer, walk_file, FileVisitor, Part, Question, QuestionFile, QuestionType,
+};
+use crate::terminal::render::question::minimal_code_area;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The quiz can be played, but something about it is probably not intended
+    Warning,
+    /// The quiz cannot be loaded
+    Error,
+}
+
+/// A problem found in a quiz file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub file: PathBuf,
+    /// Line and column (both starting at 1), if the problem can be pinned down
+    pub location: Option<(usize, usize)>,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if let Some((line, column)) = self.location {
+            write!(f, ":{line}:{column}")?;
+        }
+
+        let severity = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, ": {severity}: {}", self.message)
+    }
+}
+
+/// Checks the quiz files and the files they include, each file only once
+#[must_use]
+pub fn lint_files(paths: &[PathBuf]) -> Vec<Diagnostic> {
+    let mut linter = Linter::default();
+    for path in paths {
+        // every problem is reported and the walk goes on, so it does not fail
+        let _ = walk_file(path, &mut linter);
+    }
+
+    linter.diagnostics
+}
+
+/// Lines of a question in the file, and the column of the `-` it starts with
+struct Item {
+    lines: Range<usize>,
+    column: usize,
+}
+
+/// Lines of a quiz file, in which the questions and their keys are looked for
+struct Source<'a> {
+    lines: Vec<&'a str>,
+}
+
+impl<'a> Source<'a> {
+    fn new(text: &'a str) -> Self {
+        Self {
+            lines: text.lines().collect(),
+        }
+    }
+
+    fn is_blank(line: &str) -> bool {
+        let line = line.trim_start();
+        line.is_empty() || line.starts_with('#')
+    }
+
+    fn indent(line: &str) -> usize {
+        line.len() - line.trim_start_matches(' ').len()
+    }
+
+    fn starts_item(line: &str) -> bool {
+        let line = line.trim_start_matches(' ');
+        line == "-" || line.starts_with("- ")
+    }
+
+    /// Where the key is in the lines (possibly after the `-` of a sequence item), as a location
+    fn find_key(&self, lines: Range<usize>, key: &str) -> Option<(usize, usize)> {
+        let pattern = format!("{key}:");
+        lines.into_iter().find_map(|index| {
+            let line = self.lines.get(index)?;
+            let content = line.trim_start_matches([' ', '-']);
+            content
+                .starts_with(&pattern)
+                .then(|| (index + 1, line.len() - content.len() + 1))
+        })
+    }
+
+    fn top_level_key(&self, key: &str) -> Option<(usize, usize)> {
+        let pattern = format!("{key}:");
+        self.lines
+            .iter()
+            .position(|line| line.starts_with(&pattern))
+            .map(|index| (index + 1, 1))
+    }
+
+    /// Where the sequence item with the (possibly quoted) value is
+    fn find_value(&self, value: &str) -> Option<(usize, usize)> {
+        self.lines.iter().enumerate().find_map(|(index, line)| {
+            let item = line.trim().strip_prefix("- ")?.trim_start();
+            (item.trim_matches(['"', '\'']) == value)
+                .then(|| (index + 1, line.len() - item.len() + 1))
+        })
+    }
+
+    /// Items of the top-level `questions` sequence; only the block style of YAML is understood
+    fn questions(&self) -> Vec<Item> {
+        let Some((start, _)) = self.top_level_key("questions") else {
+            return Vec::new();
+        };
+
+        let mut items: Vec<Item> = Vec::new();
+        let mut item_indent = None;
+        let mut end = self.lines.len();
+
+        for (index, line) in self.lines.iter().enumerate().skip(start) {
+            if Self::is_blank(line) {
+                continue;
+            }
+
+            let indent = Self::indent(line);
+            if Self::starts_item(line) && item_indent.is_none_or(|item| item == indent) {
+                item_indent = Some(indent);
+                if let Some(previous) = items.last_mut() {
+                    previous.lines.end = index;
+                }
+                items.push(Item {
+                    lines: index..index,
+                    column: indent + 1,
+                });
+            } else if indent == 0 && !Self::starts_item(line) {
+                end = index;
+                break;
+            }
+        }
+
+        if let Some(last) = items.last_mut() {
+            last.lines.end = end;
+        }
+
+        items
+    }
+
+    /// Text of the item with its `-` turned into a space, so that it can be parsed on its own
+    /// with the same line and column numbers (apart from the lines before it)
+    fn item_text(&self, item: &Item) -> String {
+        let mut lines: Vec<String> = self.lines[item.lines.clone()]
+            .iter()
+            .map(|line| (*line).to_owned())
+            .collect();
+        if let Some(first) = lines.first_mut() {
+            first.replace_range(item.column - 1..item.column, " ");
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Message of a YAML error without the location, which is reported separately
+fn split_yaml_error(error: &serde_yaml::Error) -> (Option<(usize, usize)>, String) {
+    let location = error
+        .location()
+        .map(|location| (location.line(), location.column()));
+
+    let mut message = error.to_string();
+    if let Some((line, column)) = location {
+        message = message.replacen(&format!(" at line {line} column {column}"), "", 1);
+    }
+
+    (location, message)
+}
+
+#[derive(Default)]
+struct Linter {
+    diagnostics: Vec<Diagnostic>,
+    linted: HashSet<PathBuf>,
+}
+
+impl FileVisitor for Linter {
+    fn enter(&mut self, canonical: &Path) -> bool {
+        self.linted.insert(canonical.to_owned())
+    }
+
+    fn visit(&mut self, path: &Path, text: &str, file: QuestionFile) -> anyhow::Result<()> {
+        let source = Source::new(text);
+        let items = source.questions();
+        let items = (items.len() == file.questions.len()).then_some(items);
+
+        for (index, value) in file.questions.into_iter().enumerate() {
+            let item = items.as_ref().map(|items| &items[index]);
+            self.lint_question(path, &source, item, index, value);
+        }
+
+        Ok(())
+    }
+
+    fn problem(
+        &mut self,
+        path: &Path,
+        text: Option<&str>,
+        part: Part,
+        error: anyhow::Error,
+    ) -> anyhow::Result<()> {
+        let source = Source::new(text.unwrap_or_default());
+        let (location, message) = match part {
+            Part::File => (None, format!("Cannot read file: {error}")),
+            Part::Yaml => match error.downcast_ref::<serde_yaml::Error>() {
+                Some(error) => split_yaml_error(error),
+                None => (None, format!("{error:#}")),
+            },
+            Part::Defaults => (source.top_level_key("defaults"), format!("{error:#}")),
+            Part::Include(pattern) => (
+                source
+                    .find_value(pattern)
+                    .or_else(|| source.top_level_key("include")),
+                format!("{error:#}"),
+            ),
+        };
+
+        self.report(path, location, Severity::Error, message);
+        Ok(())
+    }
+}
+
+impl Linter {
+    fn report(
+        &mut self,
+        file: &Path,
+        location: Option<(usize, usize)>,
+        severity: Severity,
+        message: impl Into<String>,
+    ) {
+        self.diagnostics.push(Diagnostic {
+            file: file.to_owned(),
+            location,
+            severity,
+            message: message.into(),
+        });
+    }
+
+    fn lint_question(
+        &mut self,
+        path: &Path,
+        source: &Source,
+        item: Option<&Item>,
+        index: usize,
+        value: serde_yaml::Value,
+    ) {
+        let start = item.map(|item| (item.lines.start + 1, item.column));
+        // keys filled in from the defaults are not in the file, so the start of the question is used
+        let key = |key: &str| {
+            item.and_then(|item| source.find_key(item.lines.clone(), key))
+                .or(start)
+        };
+        let number = index + 1;
+
+        let mut question: Question = match serde_yaml::from_value(value) {
+            Ok(question) => question,
+            Err(error) => {
+                let (_, message) = split_yaml_error(&error);
+                // parsing the text of the question on its own tells where exactly the problem is
+                // (and in which field), unless it fails on something the defaults fill in
+                let (location, message) = item
+                    .and_then(|item| {
+                        let text = source.item_text(item);
+                        let error = serde_yaml::from_str::<Question>(&text).err()?;
+                        let (location, text_message) = split_yaml_error(&error);
+                        let (line, column) = location?;
+                        text_message
+                            .ends_with(&message)
+                            .then_some((Some((line + item.lines.start, column)), text_message))
+                    })
+                    .unwrap_or((start, message));
+                self.report(
+                    path,
+                    location,
+                    Severity::Error,
+                    format!("Question {number}: {message}"),
+                );
+                return;
+            }
+        };
+
+        if let Err(error) = question.prepare() {
+            self.report(
+                path,
+                key("choices"),
+                Severity::Error,
+                format!("Question {number}: {error}"),
+            );
+            return;
+        }
+
+        let mut seen = HashSet::new();
+        for choice in &question.choices {
+            if !seen.insert(normalize_text_answer(&choice.text)) {
+                self.report(
+                    path,
+                    key("choices"),
+                    Severity::Warning,
+                    format!(
+                        "Question {number}: choice \"{}\" is there more than once",
+                        choice.text
+                    ),
+                );
+            }
+        }
+
+        if question.kind == QuestionType::Choice
+            && !question.is_multichoice
+            && question.correct_choices().len() > 1
+        {
+            self.report(
+                path,
+                key("choices"),
+                Severity::Warning,
+                format!(
+                    "Question {number}: more choices are right, but the players can select only one (set `is_multichoice`)"
+                ),
+            );
+        }
+
+        let reading_time = question.get_reading_time_estimate();
+        if question.time_seconds < reading_time {
+            self.report(
+                path,
+                key("time_seconds"),
+                Severity::Warning,
+                format!(
+                    "Question {number}: {} s is shorter than the time needed to read it (about {reading_time} s)",
+                    question.time_seconds
+                ),
+            );
+        }
+
+        if let Some(code_block) = &question.code_block {
+            let area = minimal_code_area();
+            let lines = code_block.code.lines().count();
+            let width = code_block
+                .code
+                .lines()
+                .map(|line| line.chars().count())
+                .max()
+                .unwrap_or(0);
+
+            if lines > usize::from(area.height) || width > usize::from(area.width) {
+                self.report(
+                    path,
+                    key("code_block"),
+                    Severity::Warning,
+                    format!(
+                        "Question {number}: code of {lines} lines with up to {width} chars does not fit on small screens (at most {} lines of {} chars)",
+                        area.height, area.width
+                    ),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use actix::{
+    dev::{MessageResponse, OneshotSender},
+    Actor, Message,
+};
+use serde::{Deserialize, Serialize};
+
+/// Broadcast by the server over UDP, so that students on the local network can find running games
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct Announcement {
+    pub protocol_version: u32,
+    pub quiz_name: String,
+    pub port: u16, // the websocket port, the address is the one the announcement came from
+    pub tls: bool, // connect using wss://
+    pub locked: bool, // new players cannot join
+    pub pin_required: bool,
+}
+
+impl<A, M> MessageResponse<A, M> for Announcement
+where
+    A: Actor,
+    M: Message<Result = Announcement>,
+{
+    fn handle(self, _ctx: &mut A::Context, tx: Option<OneshotSender<M::Result>>) {
+        if let Some(tx) = tx {
+            let _ = tx.send(self);
+        }
+    }
+}
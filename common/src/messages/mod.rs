@@ -1,11 +1,15 @@
 use serde::{Deserialize, Serialize};
 
 use self::network::{
-    AnswerSelected, JoinRequest, JoinResponse, NextQuestion, PlayersUpdate, QuestionEnded,
-    QuestionUpdate, ShowLeaderboard, TeacherDisconnected, TryJoinRequest, TryJoinResponse,
+    AnswerDetails, AnswerDistribution, AnswerSelected, GameAnalytics, JoinRequest, JoinResponse,
+    LockUpdate, NextQuestion, PauseUpdate, PlayersUpdate, QuestionEnded, QuestionList,
+    QuestionTimeUpdate, QuestionUpdate, ReconnectRequest, ReconnectResponse, ShowLeaderboard,
+    TeacherCommand, TeacherDisconnected, TeacherLoginRequest, TeacherLoginResponse, TryJoinRequest,
+    TryJoinResponse,
 };
 use actix::Message;
 
+pub mod discovery;
 pub mod network;
 pub mod status;
 
@@ -16,6 +20,9 @@ pub enum ClientNetworkMessage {
     TryJoinRequest(TryJoinRequest),
     JoinRequest(JoinRequest),
     AnswerSelected(AnswerSelected),
+    ReconnectRequest(ReconnectRequest),
+    TeacherLoginRequest(TeacherLoginRequest),
+    TeacherCommand(TeacherCommand), // only accepted after the teacher logged in
 }
 
 /// The messages that can be sent over the websocket FROM the server TO the client
@@ -30,4 +37,13 @@ pub enum ServerNetworkMessage {
     TeacherDisconnected(TeacherDisconnected),
     JoinResponse(JoinResponse),
     TryJoinResponse(TryJoinResponse),
+    ReconnectResponse(ReconnectResponse),
+    TeacherLoginResponse(TeacherLoginResponse),
+    LockUpdate(LockUpdate), // only sent to the teachers
+    PauseUpdate(PauseUpdate),
+    QuestionTimeUpdate(QuestionTimeUpdate),
+    QuestionList(QuestionList),             // only sent to the teachers
+    AnswerDistribution(AnswerDistribution), // only sent to the teachers
+    AnswerDetails(AnswerDetails),           // only sent to the teachers
+    GameAnalytics(GameAnalytics),           // only sent to the teachers
 }
@@ -1,14 +1,20 @@
 use std::{
     collections::{HashMap, HashSet},
+    fmt,
     ops::Deref,
 };
 
-use crate::questions::{Question, QuestionCensored};
+use crate::{
+    clock::to_local_time,
+    constants::{PROTOCOL_VERSION, SUPPORTED_FEATURES},
+    questions::{Question, QuestionCensored, QuestionType},
+};
 use actix::{
     dev::{MessageResponse, OneshotSender},
     prelude::Message,
     Actor,
 };
+use chrono::{DateTime, Utc};
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -22,12 +28,41 @@ pub struct PlayerData {
     pub color: Color,
 }
 
+/// Optional parts of the protocol, which one side can tell the other it understands
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Feature {
+    Reconnect,      // `ReconnectRequest` and `ReconnectResponse`
+    WrittenAnswers, // text and numeric questions answered with a `WrittenAnswer`
+    Ordering,       // ordering questions answered with an order of the items
+    #[serde(other)]
+    Unknown, // a feature of a newer version of the protocol
+}
+
 // these models (structs) describe messages used in network communication between client - server - teacher
 
-#[derive(Message, Debug, Serialize, Deserialize, Clone)]
-#[rtype(result = "TryJoinResponse")]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TryJoinRequest {
     pub uuid: Uuid,
+    #[serde(default)]
+    pub protocol_version: u32, // clients older than the handshake do not send it, so they get 0
+    #[serde(default)]
+    pub features: Vec<Feature>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pin: Option<String>, // if the game is protected by a PIN, the request can be repeated with it
+}
+
+impl TryJoinRequest {
+    /// Creates a request speaking the current version of the protocol with all the features we know
+    #[must_use]
+    pub fn new(uuid: Uuid) -> Self {
+        Self {
+            uuid,
+            protocol_version: PROTOCOL_VERSION,
+            features: SUPPORTED_FEATURES.to_vec(),
+            pin: None,
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -41,6 +76,12 @@ pub struct TryJoinResponse {
     pub uuid: Uuid,
     pub can_join: CanJoin,
     pub quiz_name: String,
+    #[serde(default)]
+    pub protocol_version: u32, // servers older than the handshake do not send it, so they get 0
+    #[serde(default)]
+    pub features: Vec<Feature>,
+    #[serde(default)]
+    pub server_time: Option<DateTime<Utc>>, // lets the client estimate how far its clock is off
 }
 
 impl<A, M> MessageResponse<A, M> for TryJoinResponse
@@ -61,6 +102,10 @@ pub struct JoinResponse {
     pub can_join: CanJoin,
     pub quiz_name: String,
     pub players: Vec<PlayerData>,
+    #[serde(default)]
+    pub snapshot: GameSnapshot, // the game may already be under way when the player joins
+    #[serde(default)]
+    pub reconnect_secret: Option<String>, // proves that a reconnecting player is the one who joined
 }
 
 impl<A, M> MessageResponse<A, M> for JoinResponse
@@ -84,6 +129,8 @@ pub struct JoinRequest {
 #[rtype(result = "anyhow::Result<()>")]
 pub struct PlayersUpdate {
     pub players: Vec<PlayerData>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub late_joiners: Vec<Uuid>, // players who joined after the game started
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Message, PartialEq)]
@@ -93,6 +140,25 @@ pub struct NextQuestion {
     pub questions_count: usize,
     pub question: QuestionCensored,
     pub show_choices_after: usize,
+    // the instants on the clock of the server; none while the question is paused
+    #[serde(default)]
+    pub choices_visible_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub deadline: Option<DateTime<Utc>>,
+}
+
+impl NextQuestion {
+    /// When the question started on the local clock, so that the countdown runs out right at the deadline.
+    /// Returns `None` if the server did not send the deadline.
+    #[must_use]
+    pub fn local_start_time(&self, clock_offset: chrono::Duration) -> Option<DateTime<Utc>> {
+        let duration = self.show_choices_after + self.question.time_seconds;
+
+        self.deadline.map(|deadline| {
+            to_local_time(deadline, clock_offset)
+                - chrono::Duration::seconds(duration.try_into().unwrap_or(0))
+        })
+    }
 }
 
 impl Deref for NextQuestion {
@@ -103,12 +169,83 @@ impl Deref for NextQuestion {
     }
 }
 
+/// Answer to a question which has no choices
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum WrittenAnswer {
+    Text(String),
+    Number(f64),
+}
+
+impl WrittenAnswer {
+    /// Parses what the player typed as an answer to a question of the given type.
+    /// Returns `None` if the input is empty or not a number where one is expected.
+    #[must_use]
+    pub fn parse(kind: QuestionType, input: &str) -> Option<Self> {
+        let input = input.trim();
+        if input.is_empty() {
+            return None;
+        }
+
+        match kind {
+            QuestionType::Text => Some(WrittenAnswer::Text(input.to_string())),
+            QuestionType::Numeric => Self::parse_number(input).map(WrittenAnswer::Number),
+            QuestionType::Choice | QuestionType::TrueFalse | QuestionType::Ordering => None,
+        }
+    }
+
+    /// Parses a number, which may have its digits grouped by commas (`1,000.5`),
+    /// or a decimal comma instead of a point (`340,5`)
+    fn parse_number(input: &str) -> Option<f64> {
+        let number = if !input.contains(',') {
+            input.to_string()
+        } else if Self::has_digit_groups(input.split('.').next().unwrap_or_default()) {
+            input.replace(',', "")
+        } else if !input.contains('.') && input.matches(',').count() == 1 {
+            input.replace(',', ".")
+        } else {
+            return None;
+        };
+
+        number
+            .parse::<f64>()
+            .ok()
+            .filter(|number| number.is_finite())
+    }
+
+    /// Whether the integer part of a number has its digits in groups of three separated by commas
+    fn has_digit_groups(integer: &str) -> bool {
+        let is_group = |group: &str| group.bytes().all(|digit| digit.is_ascii_digit());
+
+        let mut groups = integer.trim_start_matches(['-', '+']).split(',');
+        let first = groups.next().unwrap_or_default();
+
+        (1..=3).contains(&first.len())
+            && !first.starts_with('0')
+            && is_group(first)
+            && groups.all(|group| group.len() == 3 && is_group(group))
+    }
+}
+
+impl fmt::Display for WrittenAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WrittenAnswer::Text(text) => write!(f, "{text}"),
+            WrittenAnswer::Number(number) => write!(f, "{number}"),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Message, Clone)]
 #[rtype(result = "anyhow::Result<()>")]
 pub struct AnswerSelected {
     pub player_uuid: Uuid,
     pub question_index: usize,
     pub answers: HashSet<Uuid>, // player can choose multiple answers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub written_answer: Option<WrittenAnswer>, // for text and numeric questions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<Vec<Uuid>>, // for ordering questions
 }
 
 impl Deref for AnswerSelected {
@@ -126,17 +263,97 @@ pub struct QuestionUpdate {
     pub question_index: usize,
 }
 
+/// Freezes or restarts the countdown of the question; answers are not accepted while it is paused
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PauseUpdate {
+    pub question_index: usize,
+    pub paused: bool,
+    pub elapsed_seconds: usize, // how long the question has been running, not counting the pauses
+    #[serde(default)]
+    pub deadline: Option<DateTime<Utc>>, // the new deadline on the clock of the server, once resumed
+}
+
+/// The teacher gave the question more time; carries the new answering time of the question
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct QuestionTimeUpdate {
+    pub question_index: usize,
+    pub time_seconds: usize,
+    #[serde(default)]
+    pub deadline: Option<DateTime<Utc>>, // none while the question is paused
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ChoiceStats {
     pub players_answered_count: usize,
 }
 
+/// How many players picked each of the choices of the active question so far; sent with every `QuestionUpdate`,
+/// but only to the teachers, so that the players cannot tell what the others answered
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AnswerDistribution {
+    pub question_index: usize,
+    pub stats: HashMap<Uuid, ChoiceStats>, // how many answers has the option with given uuid
+}
+
+/// What each of the players answered to the question which has just ended; sent right after `QuestionEnded`,
+/// but only to the teachers
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AnswerDetails {
+    pub question_index: usize,
+    pub players: Vec<PlayerAnswerDetail>, // in the order they answered, the players who did not answer last
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PlayerAnswerDetail {
+    pub player: PlayerData,
+    pub chosen_answers: Vec<String>, // texts of the choices, or the written answer
+    pub correct: bool,
+    pub answer_order: Option<usize>, // None if the player did not answer
+    pub response_time_ms: Option<i64>, // time from the start of the question
+    pub points: i64,
+}
+
+/// Summary of the whole game, sent to the teachers when it ends
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GameAnalytics {
+    pub quiz_name: String,
+    pub questions: Vec<QuestionAnalytics>, // only the questions which were asked, in the order of the quiz
+    pub players: Vec<PlayerAnalytics>,     // sorted by nickname
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct QuestionAnalytics {
+    pub question_index: usize,
+    pub text: String,
+    pub voided: bool,           // not counted into the accuracy of the players
+    pub percent_correct: usize, // of all the players, the ones who did not answer included
+    pub distractor: Option<Distractor>, // the wrong answer given most often
+    pub median_answer_time_ms: Option<i64>, // None if nobody answered
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Distractor {
+    pub answer: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct PlayerAnalytics {
+    pub nickname: String,
+    pub accuracy_percent: usize,
+    pub missed_questions: Vec<usize>, // indices of the questions the player did not answer correctly
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Message)]
 #[rtype(result = "anyhow::Result<()>")]
 pub struct QuestionEnded {
     pub question_index: usize,
     pub question: Question, // here we want also right choices unlike in NextQuestion, so no censoring
     pub player_answer: Option<HashSet<Uuid>>, // optional -- if player did not answer, this is None
+    #[serde(default)]
+    pub player_written_answer: Option<WrittenAnswer>, // for text and numeric questions
+    #[serde(default)]
+    pub player_order: Option<Vec<Uuid>>, // for ordering questions
     pub stats: HashMap<Uuid, ChoiceStats>, // how many answers has the option with given uuid
 }
 
@@ -147,6 +364,24 @@ pub struct ShowLeaderboard {
     pub was_final_round: bool,
 }
 
+/// Tells the teachers whether new players can join; sent whenever the lock changes
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct LockUpdate {
+    pub locked: bool,
+}
+
+/// The questions of the game, so that the teacher can pick one to go to; sent whenever a question starts
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct QuestionList {
+    pub questions: Vec<QuestionListItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct QuestionListItem {
+    pub text: String,
+    pub asked: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TeacherDisconnected {
     // no data
@@ -155,4 +390,114 @@ pub struct TeacherDisconnected {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ReconnectRequest {
     pub player_uuid: Uuid,
+    #[serde(default)]
+    pub secret: String, // the `reconnect_secret` of the `JoinResponse`
+}
+
+/// Everything a reconnecting (or late) player needs to catch up with the game
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub enum GameSnapshot {
+    #[default]
+    WaitingForGame,
+    Question {
+        question: NextQuestion,
+        players_answered_count: usize,
+        player_answer: Option<HashSet<Uuid>>, // if the player answered before the connection dropped
+        elapsed_seconds: usize, // how long the question has been running on the server
+        #[serde(default)]
+        paused: bool,
+    },
+    QuestionEnded(QuestionEnded),
+    Leaderboard(ShowLeaderboard),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReconnectResponse {
+    pub uuid: Uuid,
+    pub can_reconnect: CanJoin,
+    pub quiz_name: String,
+    pub player: Option<PlayerData>,
+    pub players: Vec<PlayerData>,
+    pub snapshot: GameSnapshot,
+}
+
+impl<A, M> MessageResponse<A, M> for ReconnectResponse
+where
+    A: Actor,
+    M: Message<Result = ReconnectResponse>,
+{
+    fn handle(self, _ctx: &mut A::Context, tx: Option<OneshotSender<M::Result>>) {
+        if let Some(tx) = tx {
+            let _ = tx.send(self);
+        }
+    }
+}
+
+/// Logs a remote teacher in; the token is printed by the server when it starts
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TeacherLoginRequest {
+    pub token: String,
+    #[serde(default)]
+    pub protocol_version: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TeacherLoginResponse {
+    pub can_login: CanJoin,
+    pub quiz_name: String,
+    pub pin: Option<String>, // the teacher shows it to the students
+    pub protocol_version: u32,
+    #[serde(default)]
+    pub server_time: Option<DateTime<Utc>>,
+}
+
+impl<A, M> MessageResponse<A, M> for TeacherLoginResponse
+where
+    A: Actor,
+    M: Message<Result = TeacherLoginResponse>,
+{
+    fn handle(self, _ctx: &mut A::Context, tx: Option<OneshotSender<M::Result>>) {
+        if let Some(tx) = tx {
+            let _ = tx.send(self);
+        }
+    }
+}
+
+/// What the teacher can make the lobby do, from the terminal on the server or remotely after logging in
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Message)]
+#[rtype(result = "anyhow::Result<()>")]
+pub enum TeacherCommand {
+    StartQuestion,
+    EndQuestion {
+        index: usize,
+    },
+    SwitchToLeaderboard,
+    KickPlayer {
+        player_uuid: Uuid,
+        reason: Option<String>,
+    },
+    SetLock {
+        locked: bool,
+    },
+    PauseQuestion,
+    ResumeQuestion,
+    AddTime {
+        seconds: usize,
+    },
+    ReopenQuestion {
+        index: usize,
+        discard_results: bool,
+    },
+    JumpToQuestion {
+        index: usize,
+    },
+    /// Takes away the points of everyone for the question which has just ended
+    VoidQuestion {
+        index: usize,
+    },
+    /// Grades the question which has just ended again, with `correct_choices` being the correct ones
+    RegradeQuestion {
+        index: usize,
+        correct_choices: HashSet<Uuid>,
+    },
 }
@@ -8,4 +8,5 @@ pub enum ClientWebsocketStatus {
     CantSendMessage,
     SocketClosed,
     CloseFrameReceived(String),
+    Reconnecting(u32), // number of the current attempt
 }
@@ -0,0 +1,153 @@
+use std::{fs, path::Path};
+
+use anyhow::{bail, Context};
+use log::info;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::constants::DEFAULT_QUIZ_NAME;
+use crate::questions::{Difficulty, Question, QuestionSet, Scoring};
+
+fn default_quiz_name() -> String {
+    DEFAULT_QUIZ_NAME.to_owned()
+}
+
+/// Questions loaded from all the question files in a directory
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuestionBank {
+    pub questions: Vec<Question>,
+}
+
+/// Describes which questions of a question bank make up a quiz
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Recipe {
+    #[serde(default = "default_quiz_name")]
+    pub quiz_name: String,
+
+    #[serde(default)]
+    pub scoring: Scoring,
+
+    /// Seed of the random generator, so that the same quiz can be made again
+    #[serde(default)]
+    pub seed: Option<u64>,
+
+    pub picks: Vec<Pick>,
+}
+
+/// Picks `count` random questions that have all the `tags` and the `difficulty` (if given)
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+pub struct Pick {
+    pub count: usize,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    #[serde(default)]
+    pub difficulty: Option<Difficulty>,
+}
+
+impl Pick {
+    #[must_use]
+    pub fn matches(&self, question: &Question) -> bool {
+        self.tags.iter().all(|tag| question.tags.contains(tag))
+            && self
+                .difficulty
+                .is_none_or(|difficulty| question.difficulty == Some(difficulty))
+    }
+}
+
+impl Recipe {
+    /// Loads a recipe from a file
+    /// # Errors
+    /// If the file cannot be read or the YAML cannot be parsed
+    pub fn from_file(path: &Path) -> anyhow::Result<Recipe> {
+        let data = fs::read_to_string(path)?;
+        let recipe: Recipe = serde_yaml::from_str(&data).context(format!(
+            "Error while evaluating recipe \"{}\"",
+            path.display()
+        ))?;
+
+        if recipe.picks.is_empty() {
+            bail!("Recipe \"{}\" does not pick any questions", path.display());
+        }
+
+        Ok(recipe)
+    }
+}
+
+impl QuestionBank {
+    /// Loads all the question files (`.yaml` or `.yml`) in the directory, in alphabetical order
+    /// # Errors
+    /// If the directory cannot be read or any of the files is not a valid question file
+    pub fn from_directory(path: &Path) -> anyhow::Result<QuestionBank> {
+        let mut files = fs::read_dir(path)
+            .context(format!(
+                "Cannot read question bank directory \"{}\"",
+                path.display()
+            ))?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        files.retain(|file| {
+            file.is_file()
+                && file
+                    .extension()
+                    .is_some_and(|extension| extension == "yaml" || extension == "yml")
+        });
+        files.sort();
+
+        let mut questions = Vec::new();
+        for file in files {
+            questions.extend(QuestionSet::from_file(&file)?.questions);
+        }
+
+        if questions.is_empty() {
+            bail!(
+                "Question bank directory \"{}\" does not contain any questions",
+                path.display()
+            );
+        }
+
+        Ok(QuestionBank { questions })
+    }
+
+    /// Makes a quiz of the questions the recipe picks; a question is picked at most once
+    /// # Errors
+    /// If there are not enough questions for some of the picks
+    pub fn sample(&self, recipe: &Recipe) -> anyhow::Result<QuestionSet> {
+        let seed = recipe.seed.unwrap_or_else(|| rand::thread_rng().gen());
+        info!(
+            "Sampling questions of quiz \"{}\" with seed {seed}",
+            recipe.quiz_name
+        );
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        let mut picked = vec![false; self.questions.len()];
+        let mut questions = Vec::new();
+
+        for (index, pick) in recipe.picks.iter().enumerate() {
+            let candidates: Vec<usize> = (0..self.questions.len())
+                .filter(|&i| !picked[i] && pick.matches(&self.questions[i]))
+                .collect();
+
+            if candidates.len() < pick.count {
+                bail!(
+                    "Pick {} of the recipe wants {} questions, but only {} are left in the question bank",
+                    index + 1,
+                    pick.count,
+                    candidates.len()
+                );
+            }
+
+            for &i in candidates.choose_multiple(&mut rng, pick.count) {
+                picked[i] = true;
+                questions.push(self.questions[i].clone());
+            }
+        }
+
+        let mut questions = QuestionSet::new(questions);
+        questions.quiz_name = recipe.quiz_name.clone();
+        questions.scoring = recipe.scoring;
+
+        Ok(questions)
+    }
+}
@@ -1,14 +1,18 @@
-use anyhow::{bail, Context};
+use anyhow::{anyhow, bail, Context};
+use clap::ValueEnum;
 use serde::{de, Deserialize, Deserializer, Serialize};
+use std::collections::HashSet;
 use std::fs;
 use std::ops::{Deref, DerefMut};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use syntect::parsing::{SyntaxReference, SyntaxSet};
 use uuid::Uuid;
 
 use crate::constants::{
-    DEFAULT_QUIZ_NAME, MAXIMAL_CHOICE_LENGTH, MAXIMAL_CODE_LENGTH, MAXIMAL_QUESTION_LENGTH,
+    DEFAULT_QUIZ_NAME, MAXIMAL_CHOICE_LENGTH, MAXIMAL_CODE_LENGTH, MAXIMAL_ORDERING_ITEMS,
+    MAXIMAL_QUESTION_LENGTH, MAXIMAL_WRITTEN_ANSWER_LENGTH,
 };
+use crate::messages::network::WrittenAnswer;
 
 fn falsy() -> bool {
     false
@@ -30,6 +34,26 @@ pub struct QuestionSet {
 
     #[serde(default = "default_quiz_name")]
     pub quiz_name: String,
+
+    #[serde(default)]
+    pub scoring: Scoring,
+}
+
+/// How points are awarded for the answers
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Default, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum Scoring {
+    /// Points for correct choices, more for players who answer before the others
+    #[default]
+    Classic,
+    /// Points shrink with the time it took to answer
+    TimeDecay,
+    /// One point for a fully correct answer
+    Flat,
+    /// Classic points with a bonus for consecutive fully correct answers
+    Streak,
+    /// Wrong choices take away as much as correct choices give
+    NegativeMarking,
 }
 
 /// We want to be able to iterate over the questions in the set directly
@@ -51,19 +75,91 @@ fn new_uuid() -> Uuid {
     Uuid::new_v4()
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+/// What kind of answer the question expects
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum QuestionType {
+    /// One or more of up to 4 choices
+    #[default]
+    Choice,
+    /// Either "True" or "False"
+    TrueFalse,
+    /// A short text, which is compared with a list of accepted answers
+    Text,
+    /// A number, which has to match the right value (up to a tolerance)
+    Numeric,
+    /// The choices have to be put in the right order
+    Ordering,
+}
+
+impl QuestionType {
+    /// Whether the question is answered by selecting from its choices
+    #[must_use]
+    pub fn has_choices(self) -> bool {
+        matches!(self, QuestionType::Choice | QuestionType::TrueFalse)
+    }
+
+    /// Whether the player types the answer
+    #[must_use]
+    pub fn is_written(self) -> bool {
+        matches!(self, QuestionType::Text | QuestionType::Numeric)
+    }
+}
+
+/// How hard a question is, used to pick questions from a question bank
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+/// The right answer of a numeric question
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy, Default)]
+pub struct NumericAnswer {
+    pub value: f64,
+    /// Answers in `value - tolerance ..= value + tolerance` are correct
+    #[serde(default)]
+    pub tolerance: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct Question {
+    #[serde(rename = "type", default)]
+    pub kind: QuestionType,
     #[serde(deserialize_with = "deserialize_question_text")]
     pub text: String,
     pub code_block: Option<CodeBlock>,
     pub time_seconds: usize,
+    #[serde(default)]
     pub is_multichoice: bool,
-    #[serde(deserialize_with = "deserialize_choices")]
+    /// Items of an ordering question are listed in the right order
+    #[serde(default, deserialize_with = "deserialize_choices")]
     pub choices: Vec<Choice>,
+    /// Whether an ordering question gives points for every item in the right position,
+    /// instead of only for the whole order being right
+    #[serde(default)]
+    pub partial_credit: bool,
+    /// The right answer of a true/false question, turned into choices when the quiz is loaded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_true: Option<bool>,
+    /// Answers accepted for a text question; case and whitespace do not matter
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accepted_answers: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub numeric_answer: Option<NumericAnswer>,
+    /// Topics of the question, used to pick questions from a question bank
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<Difficulty>,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct QuestionCensored {
+    #[serde(rename = "type", default)]
+    pub kind: QuestionType,
     #[serde(deserialize_with = "deserialize_question_text")]
     pub text: String,
     pub code_block: Option<CodeBlock>,
@@ -75,6 +171,7 @@ pub struct QuestionCensored {
 impl From<Question> for QuestionCensored {
     fn from(question: Question) -> Self {
         Self {
+            kind: question.kind,
             text: question.text,
             code_block: question.code_block,
             time_seconds: question.time_seconds,
@@ -108,6 +205,181 @@ impl Question {
 
         estimate_secs
     }
+
+    #[must_use]
+    pub fn correct_choices(&self) -> HashSet<Uuid> {
+        self.choices
+            .iter()
+            .filter(|choice| choice.is_correct)
+            .map(|choice| choice.id)
+            .collect()
+    }
+
+    /// Whether the written answer is right; always false for questions with choices
+    #[must_use]
+    pub fn accepts(&self, answer: &WrittenAnswer) -> bool {
+        match (self.kind, answer) {
+            (QuestionType::Text, WrittenAnswer::Text(text)) => {
+                let text = normalize_text_answer(text);
+                self.accepted_answers
+                    .iter()
+                    .any(|accepted| normalize_text_answer(accepted) == text)
+            }
+            (QuestionType::Numeric, WrittenAnswer::Number(number)) => {
+                self.numeric_answer.is_some_and(|right| {
+                    // a little slack, so that e.g. 0.1 + 0.2 is still accepted for 0.3
+                    (number - right.value).abs() <= right.tolerance + 1e-9
+                })
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns how many items of an ordering question are in the right position
+    #[must_use]
+    pub fn correct_positions(&self, order: &[Uuid]) -> usize {
+        self.choices
+            .iter()
+            .zip(order)
+            .filter(|(choice, id)| choice.id == **id)
+            .count()
+    }
+
+    /// Whether the answer is fully correct -- exactly the right choices were selected,
+    /// the written answer was accepted or all the items are in the right order
+    #[must_use]
+    pub fn is_answered_correctly(
+        &self,
+        choices: &HashSet<Uuid>,
+        written_answer: Option<&WrittenAnswer>,
+        order: Option<&[Uuid]>,
+    ) -> bool {
+        if self.kind.has_choices() {
+            return *choices == self.correct_choices();
+        }
+
+        if self.kind == QuestionType::Ordering {
+            return order.is_some_and(|order| {
+                order.len() == self.choices.len()
+                    && self.correct_positions(order) == self.choices.len()
+            });
+        }
+
+        written_answer.is_some_and(|answer| self.accepts(answer))
+    }
+
+    /// Checks that the question has exactly the fields its type needs,
+    /// and turns true/false questions into questions with two choices
+    /// # Errors
+    /// If the question is not valid for its type
+    pub fn prepare(&mut self) -> anyhow::Result<()> {
+        if self.kind != QuestionType::Choice && self.is_multichoice {
+            bail!("Only choice questions can be multichoice");
+        }
+
+        if self.kind != QuestionType::TrueFalse && self.is_true.is_some() {
+            bail!("Only true/false questions can have `is_true`");
+        }
+
+        if self.kind != QuestionType::Text && !self.accepted_answers.is_empty() {
+            bail!("Only text questions can have `accepted_answers`");
+        }
+
+        if self.kind != QuestionType::Numeric && self.numeric_answer.is_some() {
+            bail!("Only numeric questions can have `numeric_answer`");
+        }
+
+        if self.kind != QuestionType::Ordering && self.partial_credit {
+            bail!("Only ordering questions can have `partial_credit`");
+        }
+
+        if !matches!(self.kind, QuestionType::Choice | QuestionType::Ordering)
+            && !self.choices.is_empty()
+        {
+            bail!("Only choice and ordering questions can have `choices`");
+        }
+
+        match self.kind {
+            QuestionType::Choice => {
+                if self.choices.is_empty() || self.choices.len() > 4 {
+                    bail!("Choice question must have 1 to 4 choices");
+                }
+
+                if !self.choices.iter().any(|choice| choice.is_correct) {
+                    bail!("At least one choice must be right");
+                }
+            }
+            QuestionType::Ordering => {
+                if self.choices.len() < 2 || self.choices.len() > MAXIMAL_ORDERING_ITEMS {
+                    bail!("Ordering question must have 2 to {MAXIMAL_ORDERING_ITEMS} items");
+                }
+
+                if self.choices.iter().any(|choice| choice.is_correct) {
+                    bail!("Items of an ordering question cannot be marked as correct, list them in the right order instead");
+                }
+            }
+            QuestionType::TrueFalse => {
+                let Some(is_true) = self.is_true.take() else {
+                    bail!("True/false question must have `is_true`");
+                };
+
+                self.choices = vec![
+                    Choice {
+                        id: new_uuid(),
+                        text: "True".to_string(),
+                        is_correct: is_true,
+                    },
+                    Choice {
+                        id: new_uuid(),
+                        text: "False".to_string(),
+                        is_correct: !is_true,
+                    },
+                ];
+            }
+            QuestionType::Text => {
+                if self
+                    .accepted_answers
+                    .iter()
+                    .all(|answer| normalize_text_answer(answer).is_empty())
+                {
+                    bail!("Text question must have at least one accepted answer");
+                }
+
+                if self
+                    .accepted_answers
+                    .iter()
+                    .any(|answer| answer.chars().count() > MAXIMAL_WRITTEN_ANSWER_LENGTH)
+                {
+                    bail!("Accepted answers must be at most {MAXIMAL_WRITTEN_ANSWER_LENGTH} chars");
+                }
+            }
+            QuestionType::Numeric => {
+                let Some(answer) = self.numeric_answer else {
+                    bail!("Numeric question must have `numeric_answer`");
+                };
+
+                if !answer.value.is_finite() || !answer.tolerance.is_finite() {
+                    bail!("Numeric answer must be a finite number");
+                }
+
+                if answer.tolerance < 0.0 {
+                    bail!("Tolerance of a numeric answer cannot be negative");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Makes text answers comparable -- ignores case, surrounding whitespace
+/// and the amount of whitespace between words
+#[must_use]
+pub fn normalize_text_answer(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -199,18 +471,8 @@ where
 {
     let choices: Vec<Choice> = Deserialize::deserialize(deserializer)?;
 
-    if choices.is_empty() || choices.len() > 4 {
-        return Err(serde::de::Error::invalid_length(
-            choices.len(),
-            &"1 to 4 choices",
-        ));
-    }
-
-    let right_answers = choices.iter().filter(|choice| choice.is_correct).count();
-
-    if right_answers == 0 {
-        return Err(de::Error::custom("At least one choice must be right"));
-    }
+    // the number of choices and the right ones depend on the type of the question,
+    // so they are checked in `Question::prepare`
 
     // max length of text of a choice is 50 chars
     if choices
@@ -225,18 +487,252 @@ where
     Ok(choices)
 }
 
+/// Values for the fields that the questions of a file leave out
+#[derive(Debug, Deserialize, Default)]
+struct Defaults {
+    time_seconds: Option<usize>,
+    /// Only used for choice questions
+    is_multichoice: Option<bool>,
+    /// Language of the code blocks
+    language: Option<String>,
+}
+
+impl Defaults {
+    fn apply(&self, question: &mut serde_yaml::Value) {
+        // anything else than a mapping fails to deserialize later
+        let Some(question) = question.as_mapping_mut() else {
+            return;
+        };
+
+        if let Some(time_seconds) = self.time_seconds {
+            question
+                .entry("time_seconds".into())
+                .or_insert(time_seconds.into());
+        }
+
+        let is_choice = question
+            .get("type")
+            .is_none_or(|kind| kind.as_str() == Some("choice"));
+        if let (Some(is_multichoice), true) = (self.is_multichoice, is_choice) {
+            question
+                .entry("is_multichoice".into())
+                .or_insert(is_multichoice.into());
+        }
+
+        if let (Some(language), Some(code_block)) = (
+            &self.language,
+            question
+                .get_mut("code_block")
+                .and_then(serde_yaml::Value::as_mapping_mut),
+        ) {
+            code_block
+                .entry("language".into())
+                .or_insert(language.as_str().into());
+        }
+    }
+}
+
+/// How a question set is written in a file
+#[derive(Debug, Deserialize)]
+pub(crate) struct QuestionFile {
+    // kept as YAML until the defaults are filled in
+    #[serde(default)]
+    pub(crate) questions: Vec<serde_yaml::Value>,
+
+    #[serde(default = "default_quiz_name")]
+    quiz_name: String,
+
+    #[serde(default)]
+    scoring: Scoring,
+
+    #[serde(default)]
+    defaults: Defaults,
+
+    /// Other question files (or glob patterns), relative to this file; their questions come first
+    #[serde(default)]
+    include: Vec<String>,
+}
+
+/// Files matching the pattern of an include, in alphabetical order
+fn resolve_include(path: &Path, pattern: &str) -> anyhow::Result<Vec<PathBuf>> {
+    let directory = path.parent().unwrap_or(Path::new(""));
+    let pattern = Path::new(&glob::Pattern::escape(&directory.to_string_lossy())).join(pattern);
+    let pattern = pattern.to_string_lossy();
+
+    let files = glob::glob(&pattern)
+        .context(format!("Invalid include pattern \"{pattern}\""))?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if files.is_empty() {
+        bail!("No file matches include \"{pattern}\"");
+    }
+
+    Ok(files)
+}
+
+/// Part of a question file that a problem found while walking the files is about
+pub(crate) enum Part<'a> {
+    /// The file cannot be read
+    File,
+    /// The YAML is not valid or does not describe a question set
+    Yaml,
+    Defaults,
+    /// The pattern of an include matches no file, or a file that is already being walked
+    Include(&'a str),
+}
+
+/// What is done with the files met while walking a question file and the files it includes
+pub(crate) trait FileVisitor {
+    /// Called before a file is read; returning `false` skips it
+    fn enter(&mut self, _canonical: &Path) -> bool {
+        true
+    }
+
+    /// Handles a file with the defaults filled in to its questions,
+    /// after the files it includes were visited
+    fn visit(&mut self, path: &Path, text: &str, file: QuestionFile) -> anyhow::Result<()>;
+
+    /// Handles a problem in a file (`text` is there if the file could be read);
+    /// returning an error stops the walk
+    fn problem(
+        &mut self,
+        path: &Path,
+        text: Option<&str>,
+        part: Part,
+        error: anyhow::Error,
+    ) -> anyhow::Result<()>;
+}
+
+/// Walks a question file and, depth first, the files it includes
+/// # Errors
+/// If the visitor stops the walk
+pub(crate) fn walk_file(path: &Path, visitor: &mut impl FileVisitor) -> anyhow::Result<()> {
+    walk(path, visitor, &mut Vec::new())
+}
+
+/// `including` are the files that are being walked, each one included by the previous one
+fn walk(
+    path: &Path,
+    visitor: &mut impl FileVisitor,
+    including: &mut Vec<PathBuf>,
+) -> anyhow::Result<()> {
+    let (canonical, text) = match fs::canonicalize(path)
+        .and_then(|canonical| Ok((canonical, fs::read_to_string(path)?)))
+    {
+        Ok(file) => file,
+        Err(error) => return visitor.problem(path, None, Part::File, error.into()),
+    };
+    if !visitor.enter(&canonical) {
+        return Ok(());
+    }
+
+    let mut file: QuestionFile = match serde_yaml::from_str(&text) {
+        Ok(file) => file,
+        Err(error) => return visitor.problem(path, Some(&text), Part::Yaml, error.into()),
+    };
+
+    if let Some(language) = &file.defaults.language {
+        if let Err(error) = find_syntax(language, None) {
+            visitor.problem(path, Some(&text), Part::Defaults, error)?;
+        }
+    }
+
+    including.push(canonical);
+    for pattern in &file.include {
+        let files = match resolve_include(path, pattern) {
+            Ok(files) => files,
+            Err(error) => {
+                visitor.problem(path, Some(&text), Part::Include(pattern), error)?;
+                continue;
+            }
+        };
+
+        for included in files {
+            let is_cycle =
+                fs::canonicalize(&included).is_ok_and(|canonical| including.contains(&canonical));
+            if is_cycle {
+                let error = anyhow!(
+                    "Cycle of includes: file \"{}\" is already being loaded",
+                    included.display()
+                );
+                visitor.problem(path, Some(&text), Part::Include(pattern), error)?;
+                continue;
+            }
+
+            walk(&included, visitor, including).context(format!(
+                "Error in file \"{}\" included from \"{}\"",
+                included.display(),
+                path.display()
+            ))?;
+        }
+    }
+    including.pop();
+
+    for question in &mut file.questions {
+        file.defaults.apply(question);
+    }
+    visitor.visit(path, &text, file)
+}
+
+/// Collects the questions of the files, stopping at the first problem
+#[derive(Default)]
+struct Loader {
+    questions: Vec<Question>,
+    quiz_name: String,
+    scoring: Scoring,
+}
+
+impl FileVisitor for Loader {
+    fn visit(&mut self, path: &Path, _text: &str, file: QuestionFile) -> anyhow::Result<()> {
+        for (index, value) in file.questions.into_iter().enumerate() {
+            let question = serde_yaml::from_value::<Question>(value)
+                .map_err(anyhow::Error::from)
+                .and_then(|mut question| question.prepare().map(|()| question))
+                .context(format!(
+                    "Error in question {} of file \"{}\"",
+                    index + 1,
+                    path.display()
+                ))?;
+            self.questions.push(question);
+        }
+
+        // the file that was loaded is visited last, after the files it includes
+        self.quiz_name = file.quiz_name;
+        self.scoring = file.scoring;
+        Ok(())
+    }
+
+    fn problem(
+        &mut self,
+        path: &Path,
+        _text: Option<&str>,
+        part: Part,
+        error: anyhow::Error,
+    ) -> anyhow::Result<()> {
+        let path = path.display();
+        Err(match part {
+            Part::File => error.context(format!("Cannot read file \"{path}\"")),
+            Part::Yaml => error.context(format!("Error while evaluating file \"{path}\"")),
+            Part::Defaults => error.context(format!("Error in defaults of file \"{path}\"")),
+            Part::Include(_) => error,
+        })
+    }
+}
+
 impl QuestionSet {
-    /// Loads a question set from a file
+    /// Loads a question set from a file, together with the questions of the files it includes
     /// # Errors
-    /// If the file cannot be read or the YAML cannot be parsed
+    /// If the file cannot be read, the YAML cannot be parsed or the files include each other
     pub fn from_file(path: &Path) -> anyhow::Result<QuestionSet> {
-        let data = fs::read_to_string(path)?;
-        let questions = serde_yaml::from_str(&data).context(format!(
-            "Error while evaluating file \"{}\"",
-            path.display()
-        ))?;
-
-        Ok(questions)
+        let mut loader = Loader::default();
+        walk_file(path, &mut loader)?;
+
+        Ok(QuestionSet {
+            questions: loader.questions,
+            quiz_name: loader.quiz_name,
+            scoring: loader.scoring,
+            ..Self::new(Vec::new())
+        })
     }
 
     #[must_use]
@@ -246,6 +742,7 @@ impl QuestionSet {
             randomize_answers: false,
             randomize_questions: false,
             quiz_name: DEFAULT_QUIZ_NAME.to_owned(),
+            scoring: Scoring::default(),
         }
     }
 }
@@ -1,7 +1,9 @@
 use actix::Addr;
 use crossterm::event::{Event, EventStream, KeyCode, KeyEventKind, KeyModifiers};
 use futures::{FutureExt, StreamExt};
-use std::marker::Unpin;
+use std::{marker::Unpin, time::Duration};
+
+use crate::constants::TICK_PERIOD_MS;
 
 use crate::terminal::actor::{
     TerminalActor, TerminalDraw, TerminalHandleInput, TerminalHandleTick,
@@ -16,6 +18,7 @@ where
     T: 'static + Unpin + TerminalDraw + TerminalHandleInput + TerminalStop + TerminalHandleTick,
 {
     let mut reader = EventStream::new();
+    let mut alive_check = tokio::time::interval(Duration::from_millis(TICK_PERIOD_MS));
 
     loop {
         let crossterm_event = reader.next().fuse();
@@ -23,16 +26,14 @@ where
         tokio::select! {
             maybe_event = crossterm_event => {
                 match maybe_event {
-                    Some(Ok(Event::Key(key))) => {
-                        if key.kind == KeyEventKind::Press {
-                            // we are in raw mode, so we need to handle this ourselves
-                            if key.code == KeyCode::Char('c') && key.modifiers == KeyModifiers::CONTROL {
-                                term.send(Stop).await??;
-                                return Ok(())
-                            }
-
-                            term.send(KeyPress { key_code: key.code}).await??;
+                    Some(Ok(Event::Key(key))) if key.kind == KeyEventKind::Press => {
+                        // we are in raw mode, so we need to handle this ourselves
+                        if key.code == KeyCode::Char('c') && key.modifiers == KeyModifiers::CONTROL {
+                            term.send(Stop).await??;
+                            return Ok(())
                         }
+
+                        term.send(KeyPress { key_code: key.code}).await??;
                     }
                     Some(Ok(Event::Resize(_,_))) => {
                         term.send(Redraw).await??;
@@ -41,6 +42,12 @@ where
                     _ => {}
                 }
             }
+            _ = alive_check.tick() => {
+                // another terminal takes the input over when this one stops
+                if !term.connected() {
+                    return Ok(())
+                }
+            }
         }
     }
 }
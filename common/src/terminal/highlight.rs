@@ -42,7 +42,7 @@ impl From<Theme> for &str {
 // NICE TO HAVE: store the result of this function
 // in the state so it doesn't get called with every redraw
 #[must_use]
-pub fn code_block(block: &CodeBlock, syntax_theme: Theme) -> Paragraph {
+pub fn code_block(block: &CodeBlock, syntax_theme: Theme) -> Paragraph<'_> {
     let ss = SyntaxSet::load_defaults_newlines();
     let ts = ThemeSet::load_defaults();
 
@@ -82,7 +82,7 @@ pub fn code_block(block: &CodeBlock, syntax_theme: Theme) -> Paragraph {
     highlighted_paragraph.style(RatatuiStyle::default().bg(translated_color))
 }
 
-fn range_to_span((style, content): (Style, &str), use_bg_color: bool) -> Span {
+fn range_to_span((style, content): (Style, &str), use_bg_color: bool) -> Span<'_> {
     let bg = if use_bg_color {
         translate_color(Some(style.background))
     } else {
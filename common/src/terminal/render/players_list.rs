@@ -50,6 +50,7 @@ pub fn waiting(
     players: &mut [PlayerData],
     list_state: &mut ListState,
     player_uuid: Option<Uuid>,
+    pin: Option<&str>,
     quiz_name: &str,
 ) {
     let constraints = if player_uuid.is_none() {
@@ -61,11 +62,15 @@ pub fn waiting(
     } else {
         vec![Constraint::Length(1), Constraint::Percentage(90)]
     };
+    // students who are late need to see the PIN as well
+    let title = pin.map_or(" Welcome! ".to_string(), |pin| {
+        format!(" Join with PIN {pin} ")
+    });
     let layout = list_layout(
         frame,
         constraints.clone(),
         "Players waiting for the game to start:",
-        " Welcome! ",
+        &title,
         quiz_name,
     );
 
@@ -4,11 +4,11 @@ use ratatui::{
     style::{self},
     widgets::{
         block::{Position, Title},
-        Block, BorderType, Clear, Padding, Row, Table,
+        Bar, BarChart, BarGroup, Block, BorderType, Clear, List, ListState, Padding, Row, Table,
     },
 };
 
-use super::{get_bordered_block, get_centered_paragraph};
+use super::{get_bordered_block, get_centered_paragraph, get_highlighted_style};
 
 fn popup_block<'a>(title: &'a str, bottom_title: &'a str) -> Block<'a> {
     let title = Title::from(title)
@@ -62,6 +62,67 @@ pub fn confirm(frame: &mut Frame, message: &str) {
     frame.render_widget(paragraph, area);
 }
 
+/// Covers the middle of the screen while the question is paused
+pub fn paused(frame: &mut Frame, message: &str, hint: &str) {
+    let popup_block = popup_block(" Paused ", hint);
+
+    let area = centered_rect(frame.size(), 50, 20);
+
+    let paragraph = get_centered_paragraph(message, popup_block);
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(paragraph, area);
+}
+
+/// Lets the user choose one of `items`, the selected one is kept in `list_state`
+pub fn picker(
+    frame: &mut Frame,
+    title: &str,
+    hint: &str,
+    items: &[String],
+    list_state: &mut ListState,
+) {
+    let popup_block = popup_block(title, hint);
+
+    let area = centered_rect(frame.size(), 70, 70);
+
+    let list = List::new(items.iter().map(String::as_str))
+        .block(popup_block)
+        .highlight_style(get_highlighted_style())
+        .highlight_symbol(">> ");
+
+    frame.render_widget(Clear, area);
+    frame.render_stateful_widget(list, area, list_state);
+}
+
+/// Shows a horizontal bar for every item of `bars`, made of its label, value and color
+pub fn bar_chart(frame: &mut Frame, title: &str, hint: &str, bars: &[(String, u64, Color)]) {
+    let popup_block = popup_block(title, hint);
+
+    let area = centered_rect(frame.size(), 70, 50);
+
+    let bars: Vec<_> = bars
+        .iter()
+        .map(|(label, value, color)| {
+            Bar::default()
+                .label(Line::from(label.as_str()))
+                .value(*value)
+                .style(Style::default().fg(*color))
+                .value_style(Style::default().fg(Color::Black).bg(*color))
+        })
+        .collect();
+
+    let chart = BarChart::default()
+        .block(popup_block)
+        .direction(Direction::Horizontal)
+        .bar_width(1)
+        .bar_gap(1)
+        .data(BarGroup::default().bars(&bars));
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(chart, area);
+}
+
 // source: https://ratatui.rs/how-to/layout/center-a-rect/
 fn centered_rect(r: Rect, percent_x: u16, percent_y: u16) -> Rect {
     let popup_layout = Layout::default()
@@ -1,10 +1,17 @@
 use crate::terminal::highlight;
-use crate::{constants::COLORS, messages::network::QuestionEnded, questions::CodeBlock};
+use crate::{
+    constants::{COLORS, MINIMAL_QUESTION_HEIGHT, MINIMAL_QUESTION_WIDTH},
+    messages::network::{QuestionEnded, WrittenAnswer},
+    questions::{CodeBlock, Question, QuestionType},
+};
 use crate::{
     messages::network::NextQuestion,
     terminal::{
         highlight::Theme,
-        widgets::choice::{Grid, Selector, SelectorState},
+        widgets::{
+            choice::{Grid, Selector, SelectorState},
+            ordering::{OrderSelector, OrderingState},
+        },
     },
 };
 use log::{debug, trace};
@@ -53,10 +60,13 @@ fn time(
 
     let text = format!(
         "Type: {}",
-        if question.is_multichoice {
-            "Multi choice"
-        } else {
-            "Single choice"
+        match question.kind {
+            QuestionType::Choice if question.is_multichoice => "Multi choice",
+            QuestionType::Choice => "Single choice",
+            QuestionType::TrueFalse => "True / False",
+            QuestionType::Text => "Text answer",
+            QuestionType::Numeric => "Numeric answer",
+            QuestionType::Ordering => "Ordering",
         }
     );
 
@@ -68,15 +78,9 @@ fn time(
     frame.render_widget(answered_paragraph, counts_layout[2]);
 }
 
-fn question_layout(frame: &mut Frame, title: &str, text: &str, quiz_name: &str) -> Rc<[Rect]> {
-    let outer_block = get_outer_block(quiz_name);
-
-    let inner_block = get_inner_block(title);
-    let inner = outer_block.inner(frame.size());
-
-    let content_space = inner_block.inner(inner);
-
-    let layout = Layout::default()
+/// Rows of the question screen: the time, the text, the code and the choices
+fn question_rows(content_space: Rect) -> Rc<[Rect]> {
+    Layout::default()
         .direction(Direction::Vertical)
         .constraints(vec![
             Constraint::Length(3),
@@ -84,7 +88,30 @@ fn question_layout(frame: &mut Frame, title: &str, text: &str, quiz_name: &str)
             Constraint::Percentage(40),
             Constraint::Percentage(40),
         ])
-        .split(content_space);
+        .split(content_space)
+}
+
+/// Space for the code of a question on the smallest screen the question is shown on;
+/// lines of code that do not fit are cut off
+#[must_use]
+pub fn minimal_code_area() -> Rect {
+    let screen = Rect::new(0, 0, MINIMAL_QUESTION_WIDTH, MINIMAL_QUESTION_HEIGHT);
+    let content_space = get_inner_block("").inner(get_outer_block("").inner(screen));
+
+    code_block_frame().inner(question_rows(content_space)[2])
+}
+
+fn code_block_frame() -> Block<'static> {
+    get_bordered_block().padding(Padding::new(1, 1, 1, 1))
+}
+
+fn question_layout(frame: &mut Frame, title: &str, text: &str, quiz_name: &str) -> Rc<[Rect]> {
+    let outer_block = get_outer_block(quiz_name);
+
+    let inner_block = get_inner_block(title);
+    let inner = outer_block.inner(frame.size());
+
+    let layout = question_rows(inner_block.inner(inner));
 
     let paragraph =
         get_centered_paragraph(text, Block::default().padding(Padding::new(1, 1, 1, 1)));
@@ -97,9 +124,193 @@ fn question_layout(frame: &mut Frame, title: &str, text: &str, quiz_name: &str)
     layout
 }
 
-fn code(frame: &mut Frame, code_block: &CodeBlock, theme: Theme, layout: &[Rect]) {
-    let code_paragraph = highlight::code_block(code_block, theme)
+/// True/false questions have only two short choices, so they do not need the whole area
+fn compact_area(area: Rect) -> Rect {
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(5), Constraint::Min(0)])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![
+            Constraint::Percentage(20),
+            Constraint::Percentage(60),
+            Constraint::Percentage(20),
+        ])
+        .split(rows[0])[1]
+}
+
+fn choices_area(question_type: QuestionType, area: Rect) -> Rect {
+    if question_type == QuestionType::TrueFalse {
+        compact_area(area)
+    } else {
+        area
+    }
+}
+
+/// Shows what the player is typing -- or, if `input` is `None` (teacher), just a notice
+fn written_answer_input(
+    frame: &mut Frame,
+    question_type: QuestionType,
+    input: Option<&str>,
+    area: Rect,
+) {
+    let Some(input) = input else {
+        let paragraph = get_centered_paragraph(
+            "Players are writing their answers...",
+            Block::default().padding(Padding::new(0, 0, area.height / 2, 0)),
+        );
+        frame.render_widget(paragraph, area);
+        return;
+    };
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![
+            Constraint::Length(3),
+            Constraint::Length(1),
+            Constraint::Min(0),
+        ])
+        .split(area);
+
+    let title = if question_type == QuestionType::Numeric {
+        " Your answer (a number) "
+    } else {
+        " Your answer "
+    };
+
+    let paragraph = Paragraph::new(format!("{input}|")).block(get_bordered_block().title(title));
+    frame.render_widget(paragraph, layout[0]);
+
+    if question_type == QuestionType::Numeric
+        && !input.trim().is_empty()
+        && WrittenAnswer::parse(question_type, input).is_none()
+    {
+        let warning = Paragraph::new("Not a number").fg(Color::Red);
+        frame.render_widget(warning, layout[1]);
+    }
+}
+
+fn correct_written_answer(question: &Question) -> String {
+    match (question.kind, question.numeric_answer) {
+        (QuestionType::Numeric, Some(answer)) if answer.tolerance > 0.0 => {
+            format!("{} ± {}", answer.value, answer.tolerance)
+        }
+        (QuestionType::Numeric, Some(answer)) => answer.value.to_string(),
+        _ => question.accepted_answers.join(" / "),
+    }
+}
+
+/// Shows the right answer of a text or numeric question together with the answer of the player
+fn written_answer_result(frame: &mut Frame, question: &QuestionEnded, area: Rect) {
+    let mut lines = vec![
+        Line::from(vec![
+            Span::raw("Correct answer: "),
+            Span::styled(
+                correct_written_answer(&question.question),
+                Style::default().bold().fg(Color::Green),
+            ),
+        ]),
+        Line::from(""),
+    ];
+
+    if let Some(answer) = &question.player_written_answer {
+        let color = if question.question.accepts(answer) {
+            Color::Green
+        } else {
+            Color::Red
+        };
+
+        lines.push(Line::from(vec![
+            Span::raw("Your answer: "),
+            Span::styled(answer.to_string(), Style::default().bold().fg(color)),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .alignment(Alignment::Center)
         .block(get_bordered_block().padding(Padding::new(1, 1, 1, 1)));
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Lets the player arrange the items -- or, if `state` is `None` (teacher), just shows them
+fn ordering_input(
+    frame: &mut Frame,
+    question: &NextQuestion,
+    state: Option<&mut OrderingState>,
+    area: Rect,
+) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(2), Constraint::Min(0)])
+        .split(area);
+
+    let selector = OrderSelector::new(question.choices.clone())
+        .vertical_gap(1)
+        .current_item_style(Style::default().bg(Color::White))
+        .grabbed_item_style(Style::default().bold());
+
+    if let Some(state) = state {
+        let hint = Paragraph::new("SPACE grabs and releases an item, ENTER confirms the order")
+            .italic()
+            .alignment(Alignment::Center);
+        frame.render_widget(hint, layout[0]);
+        frame.render_stateful_widget(selector, layout[1], state);
+    } else {
+        let selector = selector.current_item_style(Style::default());
+        frame.render_widget(selector, layout[1]);
+    }
+}
+
+/// Shows the right order of the items next to the order chosen by the player
+fn ordering_result(frame: &mut Frame, question: &QuestionEnded, area: Rect) {
+    let items = &question.question.choices;
+
+    let lines: Vec<_> = items
+        .iter()
+        .enumerate()
+        .flat_map(|(position, item)| {
+            let mut spans = vec![Span::styled(
+                format!("{}. {}", position + 1, item.text),
+                Style::default().bold(),
+            )];
+
+            let player_item = question
+                .player_order
+                .as_ref()
+                .and_then(|order| order.get(position))
+                .and_then(|id| items.iter().find(|x| x.id == *id));
+
+            if let Some(player_item) = player_item {
+                let color = if player_item.id == item.id {
+                    Color::Green
+                } else {
+                    Color::Red
+                };
+
+                spans.push(Span::raw("   you: "));
+                spans.push(Span::styled(
+                    player_item.text.clone(),
+                    Style::default().fg(color),
+                ));
+            }
+
+            [Line::from(spans), Line::from("")]
+        })
+        .collect();
+
+    let paragraph = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .block(get_bordered_block().padding(Padding::new(1, 1, 1, 1)));
+
+    frame.render_widget(paragraph, area);
+}
+
+fn code(frame: &mut Frame, code_block: &CodeBlock, theme: Theme, layout: &[Rect]) {
+    let code_paragraph = highlight::code_block(code_block, theme).block(code_block_frame());
     frame.render_widget(code_paragraph, layout[2]);
 }
 
@@ -110,6 +321,8 @@ pub fn question(
     players_answered_count: usize,
     choice_grid: &mut Grid,
     choice_selector_state: Option<&mut SelectorState>,
+    written_answer: Option<&str>,
+    ordering_state: Option<&mut OrderingState>,
     time_from_start: usize,
     answered: bool,
     theme: Theme,
@@ -150,7 +363,12 @@ pub fn question(
         let time = question.show_choices_after.saturating_sub(time_from_start);
 
         let text = format!(
-            "Choices will be displayed in {} second{}!",
+            "{} in {} second{}!",
+            if question.kind.is_written() {
+                "You can answer"
+            } else {
+                "Choices will be displayed"
+            },
             time,
             if time == 1 { "" } else { "s" }
         );
@@ -163,21 +381,26 @@ pub fn question(
         return;
     }
 
+    if question.kind.is_written() {
+        written_answer_input(frame, question.kind, written_answer, layout[3]);
+        return;
+    }
+
+    if question.kind == QuestionType::Ordering {
+        ordering_input(frame, question, ordering_state, layout[3]);
+        return;
+    }
+
     let mut items = choice_grid.clone().items();
 
     let mut color_index = 0;
     for (row, items) in items.iter_mut().enumerate() {
-        for (col, mut items) in items.iter_mut().enumerate() {
+        for (col, item) in items.iter_mut().enumerate() {
             trace!("row: {row}, col: {col}");
-            match &mut items {
-                Some(item) => {
-                    color_index += 1;
+            if let Some(item) = item {
+                color_index += 1;
 
-                    item.set_style_ref(
-                        style::Style::default().fg(COLORS[color_index % COLORS.len()]),
-                    );
-                }
-                None => {}
+                item.set_style_ref(style::Style::default().fg(COLORS[color_index % COLORS.len()]));
             }
         }
     }
@@ -196,11 +419,12 @@ pub fn question(
         )
         .block(Block::default());
 
+    let area = choices_area(question.kind, layout[3]);
     if let Some(state) = choice_selector_state {
-        frame.render_stateful_widget(choice_selector, layout[3], state);
+        frame.render_stateful_widget(choice_selector, area, state);
     } else {
         let choice_selector = choice_selector.current_item_style(Style::default());
-        frame.render_widget(choice_selector, layout[3]);
+        frame.render_widget(choice_selector, area);
     }
 }
 
@@ -216,43 +440,50 @@ pub fn answers(frame: &mut Frame, question: &QuestionEnded, theme: Theme, quiz_n
         code(frame, code_block, theme, &layout);
     }
 
+    if question.question.kind.is_written() {
+        written_answer_result(frame, question, layout[3]);
+        return;
+    }
+
+    if question.question.kind == QuestionType::Ordering {
+        ordering_result(frame, question, layout[3]);
+        return;
+    }
+
     let mut choice_grid: Grid = question.clone().question.into();
     let mut items = choice_grid.clone().items();
 
     for (row, items) in items.iter_mut().enumerate() {
-        for (col, mut items) in items.iter_mut().enumerate() {
-            match &mut items {
-                Some(item) => {
-                    item.set_style_ref(Style::default());
-
-                    let was_selected_by_user = question
-                        .player_answer
-                        .iter()
-                        .any(|choice| choice.contains(&item.get_uuid()));
-
-                    debug!("was_selected_by_user {row} {col}: {was_selected_by_user}");
-
-                    let answers_count = match question.stats.get(&item.get_uuid()) {
-                        Some(count) => count.players_answered_count,
-                        None => 0,
-                    };
-
-                    let title = Title::from(answers_count.to_string())
-                        .alignment(Alignment::Right)
-                        .position(Position::Top);
-
-                    if was_selected_by_user {
-                        item.set_block_ref(
-                            get_bordered_block()
-                                .border_type(BorderType::Double)
-                                .title(title),
-                        );
-                        item.set_style_ref(Style::default().bold());
-                    } else {
-                        item.set_block_ref(get_bordered_block().title(title));
-                    }
+        for (col, item) in items.iter_mut().enumerate() {
+            if let Some(item) = item {
+                item.set_style_ref(Style::default());
+
+                let was_selected_by_user = question
+                    .player_answer
+                    .iter()
+                    .any(|choice| choice.contains(&item.get_uuid()));
+
+                debug!("was_selected_by_user {row} {col}: {was_selected_by_user}");
+
+                let answers_count = match question.stats.get(&item.get_uuid()) {
+                    Some(count) => count.players_answered_count,
+                    None => 0,
+                };
+
+                let title = Title::from(answers_count.to_string())
+                    .alignment(Alignment::Right)
+                    .position(Position::Top);
+
+                if was_selected_by_user {
+                    item.set_block_ref(
+                        get_bordered_block()
+                            .border_type(BorderType::Double)
+                            .title(title),
+                    );
+                    item.set_style_ref(Style::default().bold());
+                } else {
+                    item.set_block_ref(get_bordered_block().title(title));
                 }
-                None => {}
             }
         }
     }
@@ -267,5 +498,8 @@ pub fn answers(frame: &mut Frame, question: &QuestionEnded, theme: Theme, quiz_n
         .correct_item_style(Style::default().bg(Color::Green))
         .block(Block::default());
 
-    frame.render_widget(choice_selector, layout[3]);
+    frame.render_widget(
+        choice_selector,
+        choices_area(question.question.kind, layout[3]),
+    );
 }
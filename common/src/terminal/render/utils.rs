@@ -68,8 +68,12 @@ pub fn simple_message(frame: &mut Frame, title: &str, message: &str, quiz_name:
     );
 }
 
-pub fn welcome(frame: &mut Frame, quiz_name: &str) {
-    let lines = ["Welcome", "to", "Clihoot!"];
+/// Renders the welcome screen; the `pin` of the game, if any, is shown instead of the greeting
+pub fn welcome(frame: &mut Frame, pin: Option<&str>, quiz_name: &str) {
+    let lines = match pin {
+        Some(pin) => ["Clihoot!", "PIN:", pin],
+        None => ["Welcome", "to", "Clihoot!"],
+    };
     ascii_art(
         frame,
         &lines,
@@ -83,6 +87,16 @@ pub fn end_game(frame: &mut Frame, quiz_name: &str) {
     ascii_art(frame, &lines, "Press CTRL C to close", quiz_name);
 }
 
+pub fn lobby_locked(frame: &mut Frame, quiz_name: &str) {
+    let lines = ["Lobby", "is", "locked"];
+    ascii_art(
+        frame,
+        &lines,
+        "Ask your teacher to unlock it, then press ENTER to try again",
+        quiz_name,
+    );
+}
+
 pub fn error(frame: &mut Frame, message: &str, quiz_name: &str) {
     simple_message(frame, "Error", message, quiz_name);
 }
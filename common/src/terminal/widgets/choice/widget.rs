@@ -212,11 +212,11 @@ impl<'a> StatefulWidget for Selector<'a> {
                 }
 
                 let block = if self.current_item_block.is_some() && current {
-                    self.current_item_block.clone().take()
+                    self.current_item_block.clone()
                 } else if self.selected_item_block.is_some() && selected {
-                    self.selected_item_block.clone().take()
+                    self.selected_item_block.clone()
                 } else if self.correct_item_block.is_some() && correct {
-                    self.correct_item_block.clone().take()
+                    self.correct_item_block.clone()
                 } else {
                     None
                 };
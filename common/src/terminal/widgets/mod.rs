@@ -1 +1,2 @@
 pub mod choice;
+pub mod ordering;
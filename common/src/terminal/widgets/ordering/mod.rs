@@ -0,0 +1,5 @@
+mod state;
+mod widget;
+
+pub use state::OrderingState;
+pub use widget::OrderSelector;
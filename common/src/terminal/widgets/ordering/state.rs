@@ -0,0 +1,69 @@
+use uuid::Uuid;
+
+use crate::questions::ChoiceCensored;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+#[allow(clippy::module_name_repetitions)]
+pub struct OrderingState {
+    pub(super) order: Vec<Uuid>,
+    cursor: usize,
+    grabbed: bool,
+}
+
+impl OrderingState {
+    #[must_use]
+    pub fn new(items: &[ChoiceCensored]) -> Self {
+        Self {
+            order: items.iter().map(|item| item.id).collect(),
+            cursor: 0,
+            grabbed: false,
+        }
+    }
+
+    #[must_use]
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    // when an item is grabbed, moving the cursor moves the item as well
+    #[must_use]
+    pub fn is_grabbed(&self) -> bool {
+        self.grabbed
+    }
+
+    // the order of the items as arranged by the player
+    #[must_use]
+    pub fn order(&self) -> Vec<Uuid> {
+        self.order.clone()
+    }
+
+    pub fn toggle_grab(&mut self) {
+        self.grabbed = !self.grabbed;
+    }
+
+    // returns true if the cursor moved
+    pub fn move_up(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+
+        if self.grabbed {
+            self.order.swap(self.cursor, self.cursor - 1);
+        }
+        self.cursor -= 1;
+        true
+    }
+
+    // returns true if the cursor moved
+    pub fn move_down(&mut self) -> bool {
+        if self.cursor + 1 >= self.order.len() {
+            return false;
+        }
+
+        if self.grabbed {
+            self.order.swap(self.cursor, self.cursor + 1);
+        }
+        self.cursor += 1;
+        true
+    }
+}
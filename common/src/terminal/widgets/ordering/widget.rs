@@ -0,0 +1,115 @@
+use ratatui::layout::Rect;
+use ratatui::prelude::Buffer;
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, StatefulWidget, Widget, Wrap};
+
+use crate::constants::COLORS;
+use crate::questions::ChoiceCensored;
+use crate::terminal::widgets::ordering::OrderingState;
+
+#[derive(Default, Clone)]
+pub struct OrderSelector<'a> {
+    items: Vec<ChoiceCensored>,
+    block: Option<Block<'a>>,
+    current_item_style: Style,
+    grabbed_item_style: Style,
+    vertical_gap: u16,
+}
+
+impl<'a> OrderSelector<'a> {
+    #[must_use]
+    pub fn new(items: Vec<ChoiceCensored>) -> Self {
+        Self {
+            items,
+            block: None,
+            current_item_style: Style::default().italic(),
+            grabbed_item_style: Style::default().bold(),
+            vertical_gap: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    #[must_use]
+    pub fn current_item_style(mut self, style: Style) -> Self {
+        self.current_item_style = style;
+        self
+    }
+
+    #[must_use]
+    pub fn grabbed_item_style(mut self, style: Style) -> Self {
+        self.grabbed_item_style = style;
+        self
+    }
+
+    #[must_use]
+    pub fn vertical_gap(mut self, gap: u16) -> Self {
+        self.vertical_gap = gap;
+        self
+    }
+}
+
+impl<'a> StatefulWidget for OrderSelector<'a> {
+    type State = OrderingState;
+
+    // when rendering the widget, make sure that the OrderingState was created
+    // from the same items that are used for the rendering
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let selector_area = match self.block.take() {
+            Some(b) => {
+                let inner_area = b.inner(area);
+                b.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+
+        let mut lines = Vec::new();
+        for (position, id) in state.order.iter().enumerate() {
+            // every item keeps its color, so that it can be followed when it moves
+            let Some((index, item)) = self.items.iter().enumerate().find(|(_, x)| x.id == *id)
+            else {
+                continue;
+            };
+
+            let mut style = Style::default().fg(COLORS[(index + 1) % COLORS.len()]);
+            if position == state.cursor() {
+                style = style.patch(self.current_item_style);
+                if state.is_grabbed() {
+                    style = style.patch(self.grabbed_item_style);
+                }
+            }
+
+            let marker = if position == state.cursor() && state.is_grabbed() {
+                "⇅ "
+            } else {
+                "  "
+            };
+
+            lines.push(Line::from(vec![
+                Span::raw(marker),
+                Span::styled(format!("{}. {}", position + 1, item.text), style),
+            ]));
+
+            for _ in 0..self.vertical_gap {
+                lines.push(Line::from(""));
+            }
+        }
+
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .render(selector_area, buf);
+    }
+}
+
+impl<'a> Widget for OrderSelector<'a> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = OrderingState::new(&self.items);
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
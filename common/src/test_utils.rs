@@ -36,6 +36,10 @@ pub fn compare_censored_questions(
         return false;
     }
 
+    if left.kind != right.kind {
+        return false;
+    }
+
     for (left_choice, right_choice) in left.choices.iter().zip(right.choices.iter()) {
         if left_choice.text != right_choice.text {
             return false;
@@ -66,6 +70,13 @@ fn compare_questions(left: &questions::Question, right: &questions::Question) ->
         return false;
     }
 
+    if left.kind != right.kind
+        || left.accepted_answers != right.accepted_answers
+        || left.numeric_answer != right.numeric_answer
+    {
+        return false;
+    }
+
     for (left_choice, right_choice) in left.choices.iter().zip(right.choices.iter()) {
         if left_choice.text != right_choice.text {
             return false;
@@ -109,5 +120,6 @@ pub fn no_code_question_fixture() -> questions::Question {
                 is_correct: false,
             },
         ],
+        ..Default::default()
     }
 }
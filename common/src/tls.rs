@@ -0,0 +1,38 @@
+use std::{fs::File, io::BufReader, path::Path, sync::Arc};
+
+use anyhow::{bail, Context};
+use rustls::{ClientConfig, RootCertStore};
+use tokio_tungstenite::Connector;
+
+/// Creates a TLS connector which trusts the certificates from the given PEM file
+/// in addition to the well-known certificate authorities.
+/// Without a file, `None` is returned and the well-known authorities are used for `wss://` addresses.
+/// # Errors
+/// - If the file cannot be read or it contains no valid certificate
+pub fn connector(ca_file: Option<&Path>) -> anyhow::Result<Option<Connector>> {
+    let Some(ca_file) = ca_file else {
+        return Ok(None);
+    };
+
+    let mut reader = BufReader::new(
+        File::open(ca_file).with_context(|| format!("Cannot open {}", ca_file.display()))?,
+    );
+
+    let mut roots = RootCertStore::empty();
+    roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+
+    let mut added = 0;
+    for cert in rustls_pemfile::certs(&mut reader) {
+        roots.add(cert?)?;
+        added += 1;
+    }
+    if added == 0 {
+        bail!("No certificate found in {}", ca_file.display());
+    }
+
+    let config = ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+    Ok(Some(Connector::Rustls(Arc::new(config))))
+}
@@ -0,0 +1,56 @@
+use common::analytics::{to_html, to_markdown};
+use common::messages::network::{Distractor, GameAnalytics, PlayerAnalytics, QuestionAnalytics};
+
+fn analytics_fixture() -> GameAnalytics {
+    GameAnalytics {
+        quiz_name: "Rust <basics>".to_string(),
+        questions: vec![
+            QuestionAnalytics {
+                question_index: 0,
+                text: "What does `a | b` do?".to_string(),
+                voided: false,
+                percent_correct: 50,
+                distractor: Some(Distractor {
+                    answer: "Nothing".to_string(),
+                    count: 3,
+                }),
+                median_answer_time_ms: Some(4250),
+            },
+            QuestionAnalytics {
+                question_index: 2,
+                text: "Is <T> generic?".to_string(),
+                voided: true,
+                percent_correct: 100,
+                distractor: None,
+                median_answer_time_ms: None,
+            },
+        ],
+        players: vec![PlayerAnalytics {
+            nickname: "Ferris".to_string(),
+            accuracy_percent: 0,
+            missed_questions: vec![0],
+        }],
+    }
+}
+
+#[test]
+fn test_markdown_export() {
+    let markdown = to_markdown(&analytics_fixture());
+
+    assert!(markdown.starts_with("# Rust <basics>\n"));
+    assert!(markdown.contains("| # | Question | Correct | Common wrong answer | Median time |\n"));
+    // pipes in the text would split the cell
+    assert!(markdown.contains("| 1 | What does `a \\| b` do? | 50 % | Nothing (3x) | 4.2 s |\n"));
+    assert!(markdown.contains("| 3 | Is <T> generic? (voided) | 100 % |  |  |\n"));
+    assert!(markdown.contains("| Ferris | 0 % | 1 |\n"));
+}
+
+#[test]
+fn test_html_export() {
+    let html = to_html(&analytics_fixture());
+
+    assert!(html.contains("<title>Rust &lt;basics&gt;</title>"));
+    assert!(html.contains("<td>Is &lt;T&gt; generic? (voided)</td>"));
+    assert!(html.contains("<td>Nothing (3x)</td>"));
+    assert!(html.contains("<tr><td>Ferris</td><td>0 %</td><td>1</td></tr>"));
+}
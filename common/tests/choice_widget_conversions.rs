@@ -1,6 +1,6 @@
 use uuid::Uuid;
 
-use common::questions::{Choice, ChoiceCensored, Question, QuestionCensored};
+use common::questions::{Choice, ChoiceCensored, Question, QuestionCensored, QuestionType};
 use common::terminal::widgets::choice::{Grid, Item};
 
 #[test]
@@ -43,6 +43,7 @@ fn test_choice_grid_from_censored_2_choices() {
     let text = "Test string".to_string();
 
     let question = QuestionCensored {
+        kind: QuestionType::Choice,
         text: "Why are we here?".to_string(),
         code_block: None,
         time_seconds: 42,
@@ -77,6 +78,7 @@ fn test_choice_grid_from_censored_3_choices() {
     let text = "Test string".to_string();
 
     let question = QuestionCensored {
+        kind: QuestionType::Choice,
         text: "Why are we here?".to_string(),
         code_block: None,
         time_seconds: 42,
@@ -119,6 +121,7 @@ fn test_choice_grid_from_censored_4_choices() {
     let text = "Test string".to_string();
 
     let question = QuestionCensored {
+        kind: QuestionType::Choice,
         text: "Why are we here?".to_string(),
         code_block: None,
         time_seconds: 42,
@@ -182,6 +185,7 @@ fn test_choice_grid_from_uncensored_2_choices() {
                 is_correct: false,
             },
         ],
+        ..Default::default()
     };
 
     let choice_grid: Grid = question.into();
@@ -223,6 +227,7 @@ fn test_choice_grid_from_uncensored_3_choices() {
                 is_correct: true,
             },
         ],
+        ..Default::default()
     };
 
     let choice_grid: Grid = question.into();
@@ -273,6 +278,7 @@ fn test_choice_grid_from_uncensored_4_choices() {
                 is_correct: false,
             },
         ],
+        ..Default::default()
     };
 
     let choice_grid: Grid = question.into();
@@ -0,0 +1,113 @@
+use std::path::PathBuf;
+
+use common::lint::{lint_files, Diagnostic, Severity};
+
+fn lint(file: &str) -> Vec<Diagnostic> {
+    lint_files(&[PathBuf::from("./tests/files/lint").join(file)])
+}
+
+fn summary(diagnostics: &[Diagnostic]) -> Vec<(Option<(usize, usize)>, Severity)> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| (diagnostic.location, diagnostic.severity))
+        .collect()
+}
+
+#[test]
+fn test_ok_file_has_no_diagnostics() {
+    assert_eq!(lint("ok.yaml"), Vec::new());
+}
+
+#[test]
+fn test_warnings_point_to_the_keys() {
+    let diagnostics = lint("warnings.yaml");
+
+    assert_eq!(
+        summary(&diagnostics),
+        vec![
+            (Some((5, 5)), Severity::Warning),  // duplicate choice
+            (Some((13, 5)), Severity::Warning), // single choice with two right answers
+            (Some((21, 5)), Severity::Warning), // too short to be read
+            (Some((27, 5)), Severity::Warning), // code too long
+        ]
+    );
+    assert!(diagnostics[0].message.contains("\"whale\""));
+}
+
+#[test]
+fn test_every_question_with_error_is_reported() {
+    let diagnostics = lint("errors.yaml");
+
+    assert_eq!(
+        summary(&diagnostics),
+        vec![
+            (Some((13, 7)), Severity::Error), // the language itself
+            (Some((20, 5)), Severity::Error), // no right choice
+            (Some((25, 5)), Severity::Error), // too many choices
+        ]
+    );
+    assert!(diagnostics[0]
+        .message
+        .starts_with("Question 2: code_block:"));
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "./tests/files/lint/errors.yaml:13:7: error: Question 2: code_block: Unknown syntax \"no_such_language\""
+    );
+}
+
+#[test]
+fn test_syntax_error_has_location() {
+    let diagnostics = lint("syntax.yaml");
+
+    assert_eq!(
+        summary(&diagnostics),
+        vec![(Some((4, 17)), Severity::Error)]
+    );
+}
+
+#[test]
+fn test_included_files_are_checked() {
+    let diagnostics = lint("includes.yaml");
+
+    let problems: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            (
+                diagnostic
+                    .file
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .unwrap_or_default(),
+                diagnostic.location,
+            )
+        })
+        .collect();
+    assert_eq!(
+        problems,
+        vec![
+            ("errors.yaml", Some((13, 7))),
+            ("errors.yaml", Some((20, 5))),
+            ("errors.yaml", Some((25, 5))),
+            ("err_cycle_b.yaml", Some((2, 5))),
+            ("includes.yaml", Some((4, 5))),
+        ]
+    );
+    assert!(diagnostics[3].message.contains("Cycle of includes"));
+}
+
+#[test]
+fn test_each_file_is_checked_once() {
+    let diagnostics = lint_files(&[
+        PathBuf::from("./tests/files/lint/errors.yaml"),
+        PathBuf::from("./tests/files/lint/includes.yaml"),
+    ]);
+
+    assert_eq!(diagnostics.len(), 5);
+}
+
+#[test]
+fn test_missing_file() {
+    let diagnostics = lint("no_such_file.yaml");
+
+    assert_eq!(summary(&diagnostics), vec![(None, Severity::Error)]);
+}
@@ -0,0 +1,63 @@
+use uuid::Uuid;
+
+use common::questions::ChoiceCensored;
+use common::terminal::widgets::ordering::OrderingState;
+
+fn items() -> Vec<ChoiceCensored> {
+    ["first", "second", "third"]
+        .iter()
+        .map(|text| ChoiceCensored {
+            id: Uuid::new_v4(),
+            text: (*text).to_string(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_ordering_state_keeps_order_of_items() {
+    let items = items();
+    let state = OrderingState::new(&items);
+
+    let wanted: Vec<_> = items.iter().map(|item| item.id).collect();
+    assert_eq!(state.order(), wanted);
+    assert_eq!(state.cursor(), 0);
+    assert!(!state.is_grabbed());
+}
+
+#[test]
+fn test_ordering_state_moves_cursor() {
+    let items = items();
+    let mut state = OrderingState::new(&items);
+
+    assert!(!state.move_up());
+    assert!(state.move_down());
+    assert!(state.move_down());
+    assert!(!state.move_down());
+    assert_eq!(state.cursor(), 2);
+
+    // moving the cursor alone does not change the order
+    let wanted: Vec<_> = items.iter().map(|item| item.id).collect();
+    assert_eq!(state.order(), wanted);
+}
+
+#[test]
+fn test_ordering_state_moves_grabbed_item() {
+    let items = items();
+    let mut state = OrderingState::new(&items);
+
+    // move the first item to the end
+    state.toggle_grab();
+    assert!(state.move_down());
+    assert!(state.move_down());
+    state.toggle_grab();
+
+    assert_eq!(state.order(), vec![items[1].id, items[2].id, items[0].id]);
+
+    // and the last one (now in the middle) to the top
+    assert!(state.move_up());
+    state.toggle_grab();
+    assert!(state.move_up());
+
+    assert_eq!(state.order(), vec![items[2].id, items[1].id, items[0].id]);
+    assert_eq!(state.cursor(), 0);
+}
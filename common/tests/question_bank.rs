@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use common::question_bank::{QuestionBank, Recipe};
+use common::questions::{Difficulty, Scoring};
+
+fn bank() -> QuestionBank {
+    QuestionBank::from_directory(Path::new("./tests/files/bank"))
+        .expect("Question bank should be OK")
+}
+
+fn recipe(file: &str) -> Recipe {
+    Recipe::from_file(&Path::new("./tests/files").join(file)).expect("Recipe should be OK")
+}
+
+#[test]
+fn test_bank_loads_all_question_files() {
+    let bank = bank();
+
+    // files are loaded in alphabetical order and other files are skipped
+    assert_eq!(bank.questions.len(), 6);
+    assert_eq!(bank.questions[0].text, "Which of these are smart pointers?");
+    assert_eq!(bank.questions[0].tags, vec!["rust", "memory"]);
+    assert_eq!(bank.questions[0].difficulty, Some(Difficulty::Hard));
+    assert_eq!(bank.questions[3].text, "Rust has a garbage collector.");
+}
+
+#[test]
+fn test_bank_error_names_file_and_question() {
+    let error = QuestionBank::from_directory(Path::new("./tests/files/bank_err"))
+        .expect_err("Question bank should not be OK");
+
+    let message = format!("{error:#}");
+    assert!(message.contains("broken.yaml"), "{message}");
+    assert!(message.contains("question 2"), "{message}");
+}
+
+#[test]
+fn test_missing_bank_directory() {
+    assert!(QuestionBank::from_directory(Path::new("./tests/files/no_such_bank")).is_err());
+}
+
+#[test]
+fn test_sample_follows_recipe() {
+    let recipe = recipe("recipe_ok.yaml");
+    let quiz = bank().sample(&recipe).expect("Sampling should succeed");
+
+    assert_eq!(quiz.quiz_name, "Rust memory");
+    assert_eq!(quiz.scoring, Scoring::Flat);
+    assert_eq!(quiz.len(), 3);
+    assert!(quiz[0].tags.contains(&"memory".to_owned()));
+    assert!(quiz[1].tags.contains(&"memory".to_owned()));
+    assert_eq!(quiz[2].difficulty, Some(Difficulty::Easy));
+
+    let texts: HashSet<_> = quiz.iter().map(|question| &question.text).collect();
+    assert_eq!(texts.len(), 3);
+}
+
+#[test]
+fn test_sample_with_seed_is_reproducible() {
+    let bank = bank();
+    let recipe = recipe("recipe_ok.yaml");
+
+    let first = bank.sample(&recipe).expect("Sampling should succeed");
+    let second = bank.sample(&recipe).expect("Sampling should succeed");
+
+    let texts = |quiz: &common::questions::QuestionSet| {
+        quiz.iter()
+            .map(|question| question.text.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(texts(&first), texts(&second));
+}
+
+#[test]
+fn test_sample_not_enough_questions() {
+    let error = bank()
+        .sample(&recipe("recipe_too_many.yaml"))
+        .expect_err("Sampling should fail");
+
+    assert!(error.to_string().contains("Pick 1"), "{error}");
+}
+
+#[test]
+fn test_recipe_without_picks() {
+    assert!(Recipe::from_file(Path::new("./tests/files/recipe_no_picks.yaml")).is_err());
+}
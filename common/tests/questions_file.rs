@@ -1,4 +1,5 @@
 use common::assert_questionset_eq;
+use common::messages::network::WrittenAnswer;
 use common::questions::{self};
 use common::test_utils::compare_question_sets;
 use common::test_utils::no_code_question_fixture;
@@ -68,6 +69,7 @@ fn test_ok_code() {
                 is_correct: true,
             },
         ],
+        ..Default::default()
     }]);
 
     assert_questionset_eq!(result, wanted);
@@ -149,3 +151,256 @@ fn test_quiz_name() {
 
     assert_eq!(result.quiz_name, "Very fancy quiz");
 }
+
+#[test]
+fn test_scoring() {
+    let result = questions::QuestionSet::from_file(Path::new("./tests/files/ok_scoring.yaml"))
+        .expect("Question file should be OK");
+
+    assert_eq!(result.scoring, questions::Scoring::NegativeMarking);
+
+    let result = questions::QuestionSet::from_file(Path::new("./tests/files/ok_quiz_name.yaml"))
+        .expect("Question file should be OK");
+
+    assert_eq!(result.scoring, questions::Scoring::Classic);
+}
+
+#[test]
+fn test_unknown_scoring() {
+    let result =
+        questions::QuestionSet::from_file(Path::new("./tests/files/err_unknown_scoring.yaml"));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_question_types() {
+    let result =
+        questions::QuestionSet::from_file(Path::new("./tests/files/ok_question_types.yaml"))
+            .expect("Question file should be OK");
+
+    let kinds: Vec<_> = result.iter().map(|question| question.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            questions::QuestionType::Text,
+            questions::QuestionType::Numeric,
+            questions::QuestionType::Numeric,
+            questions::QuestionType::TrueFalse,
+            questions::QuestionType::Choice,
+            questions::QuestionType::Ordering,
+        ]
+    );
+
+    assert_eq!(result[0].accepted_answers, vec!["Prague", "Praha"]);
+    assert!(result[0].choices.is_empty());
+
+    assert_eq!(
+        result[2].numeric_answer,
+        Some(questions::NumericAnswer {
+            value: 343.0,
+            tolerance: 5.0
+        })
+    );
+
+    // true/false questions are turned into two choices
+    let true_false = &result[3];
+    assert_eq!(true_false.is_true, None);
+    assert!(!true_false.is_multichoice);
+    let choices: Vec<_> = true_false
+        .choices
+        .iter()
+        .map(|choice| (choice.text.as_str(), choice.is_correct))
+        .collect();
+    assert_eq!(choices, vec![("True", false), ("False", true)]);
+
+    let ordering = &result[5];
+    assert_eq!(ordering.choices.len(), 4);
+    assert!(ordering.partial_credit);
+    assert_eq!(ordering.choices[0].text, "fn main() {");
+
+    let right_order: Vec<_> = ordering.choices.iter().map(|choice| choice.id).collect();
+    let mut wrong_order = right_order.clone();
+    wrong_order.swap(0, 3);
+    let no_choices = std::collections::HashSet::new();
+
+    assert!(ordering.is_answered_correctly(&no_choices, None, Some(&right_order)));
+    assert!(!ordering.is_answered_correctly(&no_choices, None, Some(&wrong_order)));
+    assert_eq!(ordering.correct_positions(&wrong_order), 2);
+}
+
+#[test]
+fn test_ordering_with_correct() {
+    let result = questions::QuestionSet::from_file(Path::new(
+        "./tests/files/err_ordering_with_correct.yaml",
+    ));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_ordering_single_item() {
+    let result =
+        questions::QuestionSet::from_file(Path::new("./tests/files/err_ordering_single_item.yaml"));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_text_without_answers() {
+    let result =
+        questions::QuestionSet::from_file(Path::new("./tests/files/err_text_without_answers.yaml"));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_numeric_with_choices() {
+    let result =
+        questions::QuestionSet::from_file(Path::new("./tests/files/err_numeric_with_choices.yaml"));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_true_false_without_answer() {
+    let result = questions::QuestionSet::from_file(Path::new(
+        "./tests/files/err_true_false_without_answer.yaml",
+    ));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_written_answers() {
+    let result =
+        questions::QuestionSet::from_file(Path::new("./tests/files/ok_question_types.yaml"))
+            .expect("Question file should be OK");
+
+    let text = |input: &str| {
+        WrittenAnswer::parse(questions::QuestionType::Text, input).expect("Answer should be OK")
+    };
+    let number = |input: &str| {
+        WrittenAnswer::parse(questions::QuestionType::Numeric, input).expect("Answer should be OK")
+    };
+
+    assert!(result[0].accepts(&text("  prague")));
+    assert!(result[0].accepts(&text("PRAHA ")));
+    assert!(!result[0].accepts(&text("Brno")));
+    assert!(!result[0].accepts(&number("8")));
+
+    assert!(result[1].accepts(&number("8")));
+    assert!(!result[1].accepts(&number("8.5")));
+    assert!(!result[1].accepts(&text("8")));
+
+    assert!(result[2].accepts(&number("340,5")));
+    assert!(result[2].accepts(&number("348")));
+    assert!(!result[2].accepts(&number("350")));
+
+    // a comma is either a decimal separator or separates groups of digits
+    assert_eq!(number("1,000"), WrittenAnswer::Number(1000.0));
+    assert_eq!(number("-1,234,567.5"), WrittenAnswer::Number(-1_234_567.5));
+    assert_eq!(number("0,125"), WrittenAnswer::Number(0.125));
+    assert_eq!(number("12,5"), WrittenAnswer::Number(12.5));
+    assert_eq!(
+        WrittenAnswer::parse(questions::QuestionType::Numeric, "1,5.5"),
+        None
+    );
+    assert_eq!(
+        WrittenAnswer::parse(questions::QuestionType::Numeric, "1,00,5"),
+        None
+    );
+
+    assert_eq!(
+        WrittenAnswer::parse(questions::QuestionType::Numeric, "eight"),
+        None
+    );
+    assert_eq!(
+        WrittenAnswer::parse(questions::QuestionType::Text, "   "),
+        None
+    );
+}
+
+#[test]
+fn test_ok_defaults() {
+    let result = questions::QuestionSet::from_file(Path::new("./tests/files/ok_defaults.yaml"))
+        .expect("Question file should be OK");
+
+    assert_eq!(result.len(), 3);
+    assert_eq!(result[0].time_seconds, 30);
+    assert!(result[0].is_multichoice);
+    // only choice questions can be multichoice
+    assert!(!result[1].is_multichoice);
+    // the questions can override the defaults
+    assert_eq!(result[2].time_seconds, 10);
+    assert!(!result[2].is_multichoice);
+    assert_eq!(
+        result[2]
+            .code_block
+            .as_ref()
+            .expect("Question should have code")
+            .language,
+        "rust"
+    );
+}
+
+#[test]
+fn test_err_defaults_language() {
+    let result =
+        questions::QuestionSet::from_file(Path::new("./tests/files/err_defaults_language.yaml"));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_ok_include() {
+    let result = questions::QuestionSet::from_file(Path::new("./tests/files/include/main.yaml"))
+        .expect("Question file should be OK");
+
+    assert_eq!(result.quiz_name, "Main");
+    let questions: Vec<_> = result
+        .iter()
+        .map(|question| (question.text.as_str(), question.time_seconds))
+        .collect();
+    // the defaults of a file are used only for its own questions
+    assert_eq!(
+        questions,
+        vec![
+            ("Shared question A", 20),
+            ("Shared question B", 25),
+            ("Single question", 40),
+            ("Own question", 15),
+        ]
+    );
+}
+
+#[test]
+fn test_err_include_cycle() {
+    let error =
+        questions::QuestionSet::from_file(Path::new("./tests/files/include/err_cycle_a.yaml"))
+            .expect_err("Question file should not be OK");
+
+    let message = format!("{error:#}");
+    assert!(message.contains("Cycle of includes"), "{message}");
+    assert!(message.contains("err_cycle_b.yaml"), "{message}");
+}
+
+#[test]
+fn test_err_include_no_match() {
+    let result =
+        questions::QuestionSet::from_file(Path::new("./tests/files/include/err_no_match.yaml"));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_err_include_names_file_and_question() {
+    let error =
+        questions::QuestionSet::from_file(Path::new("./tests/files/include/err_nested.yaml"))
+            .expect_err("Question file should not be OK");
+
+    let message = format!("{error:#}");
+    assert!(message.contains("included from"), "{message}");
+    assert!(message.contains("err_no_right.yaml"), "{message}");
+    assert!(message.contains("question 2"), "{message}");
+}
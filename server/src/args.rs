@@ -5,12 +5,36 @@ use clap_num::number_range;
 
 use log::info;
 
-use common::terminal::highlight::Theme;
+use common::{
+    constants::{
+        ANSWER_GRACE_PERIOD_MS, MAXIMAL_ANSWER_GRACE_PERIOD_MS, MAXIMAL_PIN_LENGTH,
+        MINIMAL_PIN_LENGTH,
+    },
+    questions::Scoring,
+    terminal::highlight::Theme,
+};
+
+use crate::lobby::ResultsFormat;
 
 fn valid_port(s: &str) -> Result<u16, String> {
     number_range(s, 1025u16, u16::MAX)
 }
 
+fn valid_answer_grace(s: &str) -> Result<u64, String> {
+    number_range(s, 0, MAXIMAL_ANSWER_GRACE_PERIOD_MS)
+}
+
+fn valid_pin(pin: &str) -> Result<String, String> {
+    if !(MINIMAL_PIN_LENGTH..=MAXIMAL_PIN_LENGTH).contains(&pin.len())
+        || !pin.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(format!(
+            "PIN must have {MINIMAL_PIN_LENGTH} to {MAXIMAL_PIN_LENGTH} digits"
+        ));
+    }
+    Ok(pin.to_string())
+}
+
 fn valid_questions_file(file: &str) -> Result<PathBuf, String> {
     // recursively try to find the file from the current directory up to the root
     let mut current_dir = std::env::current_dir().expect("Failed to get current directory");
@@ -35,6 +59,9 @@ fn valid_questions_file(file: &str) -> Result<PathBuf, String> {
     ))
 }
 
+/// Questions file used when none is given
+const DEFAULT_QUESTIONS_FILE: &str = "default_questions.yaml";
+
 #[derive(Parser)]
 #[clap(version = "1.0", author = "Robert Gemrot")]
 pub struct Args {
@@ -42,9 +69,17 @@ pub struct Args {
     #[clap(short, long, default_value="8080", value_parser=valid_port)]
     pub port: u16,
 
-    /// Where to load questions from
-    #[clap(short, long, value_parser=valid_questions_file, default_value = "default_questions.yaml")]
-    pub questions_file: PathBuf,
+    /// Where to load questions from (default: default_questions.yaml)
+    #[clap(short, long, value_parser=valid_questions_file, conflicts_with = "question_bank")]
+    pub questions_file: Option<PathBuf>,
+
+    /// Directory of question files to make the quiz from (instead of the questions file), using `--recipe`
+    #[clap(long, requires = "recipe")]
+    pub question_bank: Option<PathBuf>,
+
+    /// File describing which questions of the question bank make up the quiz
+    #[clap(long, requires = "question_bank")]
+    pub recipe: Option<PathBuf>,
 
     /// Where to write log messages to
     #[clap(short, long, default_value = "clihoot_server_logs.log")]
@@ -58,7 +93,72 @@ pub struct Args {
     #[clap(short = 'a', long, default_value = "false")]
     pub randomize_answers: bool,
 
+    /// How points are awarded; overrides the `scoring` set in the questions file
+    #[clap(short, long, value_enum)]
+    pub scoring: Option<Scoring>,
+
     /// Theme for syntax highlighting of code in questions
     #[clap(short('t'), long, default_value_t, value_enum)]
     pub syntax_theme: Theme,
+
+    /// Where to write the results of the game when it ends (or when the server is stopped)
+    #[clap(long)]
+    pub results_file: Option<PathBuf>,
+
+    /// Format of the results file
+    #[clap(long, default_value = "json", value_enum)]
+    pub results_format: ResultsFormat,
+
+    /// Where to save the state of the game after every phase change (nothing is saved if not given)
+    #[clap(long)]
+    pub snapshot_file: Option<PathBuf>,
+
+    /// Continue a game from the given snapshot instead of starting a new one
+    #[clap(long)]
+    pub resume: Option<PathBuf>,
+
+    /// Protect the game with a PIN the students have to enter to join; a random one is generated if none is given
+    #[clap(long, num_args = 0..=1, value_parser = valid_pin)]
+    pub pin: Option<Option<String>>,
+
+    /// Lock the lobby when the first question starts, so that no more players can join (the teacher can unlock it)
+    #[clap(long)]
+    pub lock_on_start: bool,
+
+    /// How many milliseconds after the deadline the answers are still accepted, as they take a while to arrive
+    #[clap(long, value_parser = valid_answer_grace, default_value_t = ANSWER_GRACE_PERIOD_MS)]
+    pub answer_grace_ms: u64,
+
+    /// Run without the teacher's terminal; the game is controlled by a remote teacher (see the `teacher` program)
+    #[clap(long)]
+    pub headless: bool,
+
+    /// Token the remote teachers log in with; a random one is generated (and printed) if none is given
+    #[clap(long)]
+    pub teacher_token: Option<String>,
+
+    /// Do not announce the game on the local network (students then have to know the address of the server)
+    #[clap(long)]
+    pub no_discovery: bool,
+
+    /// PEM file with the TLS certificate chain; with `--tls-key`, clients must connect using wss://
+    #[clap(long, requires = "tls_key")]
+    pub tls_cert: Option<PathBuf>,
+
+    /// PEM file with the private key of the TLS certificate
+    #[clap(long, requires = "tls_cert")]
+    pub tls_key: Option<PathBuf>,
+}
+
+impl Args {
+    /// Returns the questions file to load the quiz from; the default one is only looked for
+    /// when it is needed, that is when the quiz is neither made from a question bank nor resumed
+    /// # Errors
+    /// - when no questions file is given and the default one does not exist
+    pub fn questions_file(&self) -> anyhow::Result<PathBuf> {
+        match &self.questions_file {
+            Some(path) => Ok(path.clone()),
+            None => valid_questions_file(DEFAULT_QUESTIONS_FILE).map_err(anyhow::Error::msg),
+        }
+    }
 }
@@ -0,0 +1,42 @@
+use std::{path::PathBuf, process::ExitCode};
+
+use clap::Parser;
+
+use common::lint::{lint_files, Severity};
+
+/// Checks quiz files (and the files they include) without starting a game.
+/// Exits with 0 when the files are fine, 1 when there are errors (or warnings with `--deny-warnings`)
+/// and 2 when the arguments are wrong.
+#[derive(Parser)]
+#[clap(version = "1.0")]
+struct Args {
+    /// Quiz files to check
+    #[clap(required = true)]
+    files: Vec<PathBuf>,
+
+    /// Fail also when there are only warnings
+    #[clap(short = 'D', long)]
+    deny_warnings: bool,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let diagnostics = lint_files(&args.files);
+    for diagnostic in &diagnostics {
+        println!("{diagnostic}");
+    }
+
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .count();
+    let warnings = diagnostics.len() - errors;
+    println!("{errors} error(s), {warnings} warning(s)");
+
+    if errors > 0 || (args.deny_warnings && warnings > 0) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
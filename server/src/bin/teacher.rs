@@ -0,0 +1,71 @@
+use std::{fs::File, path::PathBuf};
+
+use clap::Parser;
+use simplelog::{
+    ColorChoice, CombinedLogger, Config, LevelFilter, TermLogger, TerminalMode, WriteLogger,
+};
+
+use common::{terminal::highlight::Theme, tls};
+use server::teacher::run_remote_teacher;
+
+fn addr_parser(arg: &str) -> Result<String, String> {
+    // plain websocket is the default, encrypted one has to be asked for
+    let addr = if arg.contains("://") {
+        arg.to_string()
+    } else {
+        format!("ws://{arg}")
+    };
+
+    if !(addr.starts_with("ws://") || addr.starts_with("wss://")) {
+        return Err("Only ws:// and wss:// addresses are supported".to_string());
+    }
+
+    Ok(addr)
+}
+
+/// Controls a clihoot server running on another computer (e.g. one started with `--headless`)
+#[derive(Parser)]
+#[clap(version = "1.0")]
+struct Args {
+    /// Url of the clihoot server; use wss://<host>:[port] if the server uses TLS
+    #[clap(short, long, default_value = "localhost:8080", value_parser = addr_parser)]
+    addr: String,
+
+    /// Token printed by the server when it started
+    #[clap(long)]
+    token: String,
+
+    /// PEM file with the certificate authority to trust for wss:// addresses (e.g. a self-signed certificate)
+    #[clap(long)]
+    ca_file: Option<PathBuf>,
+
+    /// Where to write log messages to
+    #[clap(short, long, default_value = "clihoot_teacher_logs.log")]
+    log_file: PathBuf,
+
+    /// Theme for syntax highlighting of code in questions
+    #[clap(short('t'), long, default_value_t, value_enum)]
+    syntax_theme: Theme,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    CombinedLogger::init(vec![
+        WriteLogger::new(
+            LevelFilter::Debug,
+            Config::default(),
+            File::create(args.log_file)?,
+        ),
+        TermLogger::new(
+            LevelFilter::Error,
+            Config::default(),
+            TerminalMode::Mixed,
+            ColorChoice::Auto,
+        ),
+    ])?;
+
+    let tls_connector = tls::connector(args.ca_file.as_deref())?;
+
+    run_remote_teacher(&args.addr, tls_connector, args.token, args.syntax_theme)
+}
@@ -0,0 +1,167 @@
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    path::Path,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use anyhow::{bail, Context as _};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, ReadBuf},
+    net::TcpStream,
+    time::timeout,
+};
+use tokio_rustls::{rustls::ServerConfig, server::TlsStream, TlsAcceptor};
+
+const MAXIMAL_REQUEST_HEAD_LENGTH: usize = 8 * 1024;
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+
+enum Stream {
+    Plain(TcpStream),
+    Tls(Box<TlsStream<TcpStream>>),
+}
+
+/// A connection accepted by the server, either plain or encrypted with TLS.
+/// Bytes read while looking at the request head are handed out again before the rest of the stream,
+/// so that the websocket handshake can still read the whole request.
+pub struct Connection {
+    stream: Stream,
+    read_ahead: Vec<u8>,
+    read_ahead_position: usize,
+}
+
+impl Connection {
+    /// Wraps the accepted `socket`, doing the TLS handshake first if the server has a certificate.
+    /// # Errors
+    /// - If the TLS handshake fails or does not finish in time
+    pub async fn accept(socket: TcpStream, tls: Option<&TlsAcceptor>) -> anyhow::Result<Self> {
+        let stream = match tls {
+            Some(acceptor) => Stream::Tls(Box::new(
+                timeout(HANDSHAKE_TIMEOUT, acceptor.accept(socket))
+                    .await
+                    .context("TLS handshake timed out")??,
+            )),
+            None => Stream::Plain(socket),
+        };
+
+        Ok(Self {
+            stream,
+            read_ahead: Vec::new(),
+            read_ahead_position: 0,
+        })
+    }
+
+    /// Reads the head of the HTTP request (the request line and the headers).
+    /// The head stays in the connection and is read again by whoever reads the connection next.
+    /// # Errors
+    /// - If the connection is closed or the head does not arrive in time
+    /// - If the head is too long
+    pub async fn read_request_head(&mut self) -> anyhow::Result<String> {
+        let head_length = timeout(HANDSHAKE_TIMEOUT, async {
+            let mut buffer = [0; 1024];
+            loop {
+                if let Some(end) = self.read_ahead.windows(4).position(|w| w == b"\r\n\r\n") {
+                    return Ok(end + 4);
+                }
+
+                if self.read_ahead.len() >= MAXIMAL_REQUEST_HEAD_LENGTH {
+                    bail!("Request head is too long");
+                }
+
+                let read = match &mut self.stream {
+                    Stream::Plain(stream) => stream.read(&mut buffer).await?,
+                    Stream::Tls(stream) => stream.read(&mut buffer).await?,
+                };
+                if read == 0 {
+                    bail!("Connection closed before the request was received");
+                }
+
+                self.read_ahead.extend_from_slice(&buffer[..read]);
+            }
+        })
+        .await
+        .context("Request head did not arrive in time")??;
+
+        Ok(String::from_utf8_lossy(&self.read_ahead[..head_length]).into_owned())
+    }
+}
+
+impl AsyncRead for Connection {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+
+        let read_ahead = &this.read_ahead[this.read_ahead_position..];
+        if !read_ahead.is_empty() {
+            let length = read_ahead.len().min(buf.remaining());
+            buf.put_slice(&read_ahead[..length]);
+            this.read_ahead_position += length;
+            return Poll::Ready(Ok(()));
+        }
+
+        match &mut this.stream {
+            Stream::Plain(stream) => Pin::new(stream).poll_read(cx, buf),
+            Stream::Tls(stream) => Pin::new(stream.as_mut()).poll_read(cx, buf),
+        }
+    }
+}
+
+impl AsyncWrite for Connection {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match &mut self.get_mut().stream {
+            Stream::Plain(stream) => Pin::new(stream).poll_write(cx, buf),
+            Stream::Tls(stream) => Pin::new(stream.as_mut()).poll_write(cx, buf),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match &mut self.get_mut().stream {
+            Stream::Plain(stream) => Pin::new(stream).poll_flush(cx),
+            Stream::Tls(stream) => Pin::new(stream.as_mut()).poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match &mut self.get_mut().stream {
+            Stream::Plain(stream) => Pin::new(stream).poll_shutdown(cx),
+            Stream::Tls(stream) => Pin::new(stream.as_mut()).poll_shutdown(cx),
+        }
+    }
+}
+
+/// Loads the certificate chain and the private key from PEM files and creates a TLS acceptor from them.
+/// # Errors
+/// - If the files cannot be read
+/// - If there is no certificate or private key in the files, or the key does not match the certificate
+pub fn load_tls_acceptor(cert_file: &Path, key_file: &Path) -> anyhow::Result<TlsAcceptor> {
+    let mut cert_reader = BufReader::new(
+        File::open(cert_file).with_context(|| format!("Cannot open {}", cert_file.display()))?,
+    );
+    let certs = rustls_pemfile::certs(&mut cert_reader).collect::<Result<Vec<_>, _>>()?;
+    if certs.is_empty() {
+        bail!("No certificate found in {}", cert_file.display());
+    }
+
+    let mut key_reader = BufReader::new(
+        File::open(key_file).with_context(|| format!("Cannot open {}", key_file.display()))?,
+    );
+    let Some(key) = rustls_pemfile::private_key(&mut key_reader)? else {
+        bail!("No private key found in {}", key_file.display());
+    };
+
+    let config = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)?;
+
+    Ok(TlsAcceptor::from(Arc::new(config)))
+}
@@ -0,0 +1,38 @@
+use std::{net::Ipv4Addr, time::Duration};
+
+use actix::Addr;
+use common::constants::{DISCOVERY_INTERVAL_MS, DISCOVERY_PORT};
+use log::debug;
+use tokio::{net::UdpSocket, time::interval};
+
+use crate::{messages::lobby::GetAnnouncement, Lobby};
+
+/// Broadcasts the state of the game to the local network until the lobby stops,
+/// so that students do not have to type the address of the server.
+/// # Errors
+/// - If the UDP socket cannot be created
+pub async fn announce(lobby: Addr<Lobby>, port: u16, tls: bool) -> anyhow::Result<()> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 0)).await?;
+    socket.set_broadcast(true)?;
+
+    let mut interval = interval(Duration::from_millis(DISCOVERY_INTERVAL_MS));
+
+    loop {
+        interval.tick().await;
+
+        let Ok(announcement) = lobby.send(GetAnnouncement { port, tls }).await else {
+            return Ok(());
+        };
+
+        // the network may not allow broadcasts at the moment, students can still type the address
+        if let Err(e) = socket
+            .send_to(
+                &serde_json::to_vec(&announcement)?,
+                (Ipv4Addr::BROADCAST, DISCOVERY_PORT),
+            )
+            .await
+        {
+            debug!("Failed to announce the game: {e}");
+        }
+    }
+}
@@ -1,7 +1,10 @@
 mod args;
+pub mod connection;
+pub mod discovery;
 pub mod lobby;
 pub mod messages;
 pub mod teacher;
+pub mod web;
 pub mod websocket;
 
 pub use args::*;
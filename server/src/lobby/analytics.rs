@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use common::{
+    messages::network::{Distractor, GameAnalytics, PlayerAnalytics, QuestionAnalytics},
+    questions::QuestionType,
+};
+use itertools::Itertools;
+use uuid::Uuid;
+
+use super::Lobby;
+
+/// Share of `part` in `total`, in whole percents
+fn percent(part: usize, total: usize) -> usize {
+    if total == 0 {
+        return 0;
+    }
+
+    part * 100 / total
+}
+
+fn median(mut values: Vec<i64>) -> Option<i64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    values.sort_unstable();
+    let middle = values.len() / 2;
+
+    Some(if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) / 2
+    } else {
+        values[middle]
+    })
+}
+
+impl Lobby {
+    fn get_question_analytics(&self, index: usize, players: &[Uuid]) -> QuestionAnalytics {
+        let question = &self.questions[index];
+        let answers = players
+            .iter()
+            .map(|uuid| self.get_answer_report(index, uuid))
+            .collect_vec();
+
+        // the wrong choices the players picked, or the wrong answers they wrote
+        let mut wrong_answers: HashMap<String, usize> = HashMap::new();
+        for answer in answers.iter().filter(|answer| !answer.correct) {
+            let wrong = match question.kind {
+                QuestionType::Ordering => vec![],
+                kind if kind.is_written() => answer.chosen_answers.clone(),
+                _ => question
+                    .choices
+                    .iter()
+                    .filter(|choice| {
+                        !choice.is_correct && answer.chosen_answers.contains(&choice.text)
+                    })
+                    .map(|choice| choice.text.clone())
+                    .collect(),
+            };
+
+            for wrong in wrong {
+                *wrong_answers.entry(wrong).or_default() += 1;
+            }
+        }
+
+        QuestionAnalytics {
+            question_index: index,
+            text: question.text.clone(),
+            voided: self.voided_questions.contains(&index),
+            percent_correct: percent(
+                answers.iter().filter(|answer| answer.correct).count(),
+                answers.len(),
+            ),
+            // ties are broken by the text, so that the summary does not change from run to run
+            distractor: wrong_answers
+                .into_iter()
+                .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then_with(|| b.cmp(a)))
+                .map(|(answer, count)| Distractor { answer, count }),
+            median_answer_time_ms: median(
+                answers
+                    .iter()
+                    .filter(|answer| answer.answer_order.is_some())
+                    .filter_map(|answer| answer.latency_ms)
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Sums up how the players did in the questions asked so far, and which questions were the hardest for them
+    #[must_use]
+    pub fn get_game_analytics(&self) -> GameAnalytics {
+        // players who dropped out or were kicked are counted as well
+        let report = self.get_report();
+        let players = report
+            .players
+            .iter()
+            .map(|player| player.uuid)
+            .collect_vec();
+
+        let asked = (0..self.questions.len())
+            .filter(|index| self.is_asked(*index))
+            .collect_vec();
+
+        // voided questions do not count against anyone
+        let graded = asked
+            .iter()
+            .copied()
+            .filter(|index| !self.voided_questions.contains(index))
+            .collect_vec();
+
+        GameAnalytics {
+            quiz_name: report.quiz_name.clone(),
+            questions: asked
+                .iter()
+                .map(|index| self.get_question_analytics(*index, &players))
+                .collect(),
+            players: report
+                .players
+                .iter()
+                .map(|player| {
+                    let missed_questions = graded
+                        .iter()
+                        .copied()
+                        .filter(|index| !self.get_answer_report(*index, &player.uuid).correct)
+                        .collect_vec();
+
+                    PlayerAnalytics {
+                        nickname: player.nickname.clone(),
+                        accuracy_percent: percent(
+                            graded.len() - missed_questions.len(),
+                            graded.len(),
+                        ),
+                        missed_questions,
+                    }
+                })
+                .sorted_by(|a, b| a.nickname.cmp(&b.nickname))
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use actix::{Context, Handler};
+use anyhow::bail;
+use common::{
+    constants::MAXIMAL_ADDED_TIME_SECS,
+    messages::{network::QuestionTimeUpdate, ServerNetworkMessage},
+};
+use log::debug;
+
+use crate::{
+    lobby::{Lobby, Phase},
+    messages::lobby::AddTime,
+};
+
+impl Handler<AddTime> for Lobby {
+    type Result = anyhow::Result<()>;
+
+    fn handle(&mut self, msg: AddTime, ctx: &mut Context<Self>) -> Self::Result {
+        debug!("Received AddTime message in Lobby; adding {}s", msg.seconds);
+
+        let Phase::ActiveQuestion(index) = self.phase else {
+            bail!("Time can only be added to an active question");
+        };
+
+        if !(1..=MAXIMAL_ADDED_TIME_SECS).contains(&msg.seconds) {
+            bail!(
+                "Between 1 and {MAXIMAL_ADDED_TIME_SECS} seconds can be added at once, not {}",
+                msg.seconds
+            );
+        }
+
+        // only this run of the question gets longer, the quiz stays as it is
+        *self.extra_time.entry(index).or_default() += msg.seconds;
+        let time_seconds = self.get_question(index).time_seconds;
+
+        // a paused question gets its timer back on resume
+        if self.paused_at.is_none() {
+            self.cancel_question_timer(ctx);
+            self.start_question_timer(ctx, index);
+        }
+
+        let message = ServerNetworkMessage::QuestionTimeUpdate(QuestionTimeUpdate {
+            question_index: index,
+            time_seconds,
+            deadline: self.get_deadline(index),
+        });
+        self.send_to_all(&message);
+        self.send_to_teachers(&message);
+        self.save_snapshot();
+
+        Ok(())
+    }
+}
@@ -1,14 +1,20 @@
 use crate::{
     lobby::{
-        point_calculator::calculate_points,
+        point_calculator::{calculate_points, ScoringContext},
         state::{Lobby, Phase, PlayerQuestionRecord},
     },
     messages::lobby::EndQuestion,
 };
+use std::collections::HashSet;
+
 use actix::{prelude::Handler, AsyncContext};
 use anyhow::{bail, Ok};
-use chrono::Utc;
-use common::messages::network::AnswerSelected;
+use chrono::{Duration, Utc};
+use common::{
+    constants::MAXIMAL_WRITTEN_ANSWER_LENGTH,
+    messages::network::{AnswerSelected, WrittenAnswer},
+    questions::QuestionType,
+};
 use log::debug;
 
 impl Handler<AnswerSelected> for Lobby {
@@ -39,6 +45,11 @@ impl Handler<AnswerSelected> for Lobby {
             return Ok(());
         }
 
+        if self.paused_at.is_some() {
+            debug!("Player {id} answered while the question is paused -- ignoring.");
+            return Ok(());
+        }
+
         if self
             .results
             .entry(msg.question_index)
@@ -48,31 +59,68 @@ impl Handler<AnswerSelected> for Lobby {
             bail!("Player {id} already answered this question");
         }
 
+        let question = &self.questions[msg.question_index];
+
         // If player selected more than one answer, but the question is not multichoice, return error
-        if msg.answers.len() > 1 && !self.questions[msg.question_index].is_multichoice {
+        if msg.answers.len() > 1 && !question.is_multichoice {
             bail!("Player {id} selected more than one answer, but the question is not multichoice");
         }
 
+        // The answer has to be of the kind the question expects
+        let fits = match (question.kind, &msg.written_answer, &msg.order) {
+            (kind, None, None) if kind.has_choices() => true,
+            (QuestionType::Text, Some(WrittenAnswer::Text(text)), None) => {
+                msg.answers.is_empty() && text.chars().count() <= MAXIMAL_WRITTEN_ANSWER_LENGTH
+            }
+            (QuestionType::Numeric, Some(WrittenAnswer::Number(number)), None) => {
+                msg.answers.is_empty() && number.is_finite()
+            }
+            // the order has to contain every item exactly once
+            (QuestionType::Ordering, None, Some(order)) => {
+                msg.answers.is_empty()
+                    && order.len() == question.choices.len()
+                    && order.iter().collect::<HashSet<_>>()
+                        == question.choices.iter().map(|choice| &choice.id).collect()
+            }
+            _ => false,
+        };
+
+        if !fits {
+            bail!("Player {id} sent an answer which does not fit the question");
+        }
+
         let answer_order = self.results.entry(msg.question_index).or_default().len();
         debug!("Player {id} answered as {answer_order}th player");
 
-        let points = calculate_points(
-            id,
+        let now = Utc::now();
+        let elapsed = self
+            .question_start_times
+            .get(&msg.question_index)
+            .map_or_else(Duration::zero, |started| now - *started);
+
+        let points = calculate_points(&ScoringContext {
+            player: id,
             answer_order,
-            self.joined_players.len(),
-            msg.question_index,
-            &msg,
-            &self.questions,
-            &self.results,
-        )?;
+            total_players: self.joined_players.len(),
+            question_index: msg.question_index,
+            answers: &msg.answers,
+            written_answer: msg.written_answer.as_ref(),
+            order: msg.order.as_deref(),
+            elapsed,
+            extra_seconds: self.get_extra_seconds(msg.question_index),
+            questions: &self.questions,
+            results: &self.results,
+        })?;
         debug!("Player {id} got {points} points");
 
         self.results.entry(msg.question_index).or_default().insert(
             id,
             PlayerQuestionRecord {
                 answer_order: answer_order + 1,
-                timestamp: Utc::now(),
+                timestamp: now,
                 selected_answers: msg.answers,
+                written_answer: msg.written_answer,
+                order: msg.order,
                 points_awarded: points,
             },
         );
@@ -1,4 +1,5 @@
 use actix::{Context, Handler};
+use chrono::Utc;
 
 use crate::{messages::websocket::DisconnectFromLobby, Lobby};
 use log::info;
@@ -7,11 +8,27 @@ use log::info;
 impl Handler<DisconnectFromLobby> for Lobby {
     type Result = ();
 
-    fn handle(&mut self, msg: DisconnectFromLobby, _: &mut Context<Self>) {
-        if self.joined_players.remove(&msg.player_id).is_some() {
-            info!("{} disconnected", msg.player_id);
-
-            let _ = self.send_players_update(Some(&msg.player_id));
+    fn handle(&mut self, msg: DisconnectFromLobby, ctx: &mut Context<Self>) {
+        // the player may have already reconnected using another websocket
+        if !self
+            .joined_players
+            .get(&msg.player_id)
+            .is_some_and(|player| player.addr == msg.addr)
+        {
+            return;
         }
+
+        let Some(player) = self.joined_players.remove(&msg.player_id) else {
+            return;
+        };
+
+        info!("{} disconnected", msg.player_id);
+
+        // keep the player around for a while, so that they can reconnect
+        let player = player.disconnect(Utc::now());
+        self.forget_player_later(ctx, &player);
+        self.disconnected_players.insert(msg.player_id, player);
+
+        let _ = self.send_players_update(Some(&msg.player_id));
     }
 }
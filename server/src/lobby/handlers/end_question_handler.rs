@@ -10,7 +10,7 @@ use crate::{
 impl Handler<EndQuestion> for Lobby {
     type Result = anyhow::Result<()>;
 
-    fn handle(&mut self, msg: EndQuestion, _: &mut Context<Self>) -> Self::Result {
+    fn handle(&mut self, msg: EndQuestion, ctx: &mut Context<Self>) -> Self::Result {
         debug!("Received EndQuestion message in Lobby; ending question");
 
         // check that it's the correct phase
@@ -24,8 +24,16 @@ impl Handler<EndQuestion> for Lobby {
             bail!("Received EndQuestion in Lobby, but the question index does not match the current question; ignoring");
         }
 
+        // the question may have ended sooner than its time was up, or while it was paused
+        self.cancel_question_timer(ctx);
+        self.paused_at = None;
+
+        // the players who did not answer a re-opened question again keep their previous answers
+        self.restore_reopened_results(question);
+
         // set the phase
         self.phase = Phase::AfterQuestion(question);
+        self.save_snapshot();
 
         debug!("sending send_question_ended to all");
 
@@ -0,0 +1,19 @@
+use actix::{Context, Handler};
+use common::{constants::PROTOCOL_VERSION, messages::discovery::Announcement};
+
+use crate::{messages::lobby::GetAnnouncement, Lobby};
+
+impl Handler<GetAnnouncement> for Lobby {
+    type Result = Announcement;
+
+    fn handle(&mut self, msg: GetAnnouncement, _: &mut Context<Self>) -> Self::Result {
+        Announcement {
+            protocol_version: PROTOCOL_VERSION,
+            quiz_name: self.questions.quiz_name.clone(),
+            port: msg.port,
+            tls: msg.tls,
+            locked: self.locked,
+            pin_required: self.pin.is_some(),
+        }
+    }
+}
@@ -9,6 +9,7 @@ impl Handler<HardStop> for Lobby {
 
     fn handle(&mut self, _msg: HardStop, _: &mut Context<Self>) {
         debug!("Received HardStop message in Lobby; stopping server");
+        self.save_results();
         System::current().stop();
     }
 }
@@ -1,12 +1,12 @@
 use actix::Handler;
 use common::{
     constants::{LOBBY_LOCKED_MSG, NICKNAME_ALREADY_TAKEN_MSG, PLAYER_NOT_IN_WAITING_LIST_MSG},
-    messages::network::{CanJoin, JoinResponse},
+    messages::network::{CanJoin, GameSnapshot, JoinResponse},
 };
-use log::debug;
+use log::{debug, warn};
 
 use crate::{
-    lobby::{JoinedPlayer, Lobby},
+    lobby::{JoinedPlayer, Lobby, Phase, RosterEntry},
     messages::client::JoinRequest,
 };
 
@@ -24,6 +24,8 @@ impl Handler<JoinRequest> for Lobby {
             quiz_name: self.questions.quiz_name.clone(),
             can_join: CanJoin::No(String::new()),
             players: self.get_players(),
+            snapshot: GameSnapshot::WaitingForGame,
+            reconnect_secret: None,
         };
 
         if self.locked {
@@ -41,10 +43,13 @@ impl Handler<JoinRequest> for Lobby {
             };
         }
 
+        // players who may still reconnect keep their nickname
         if self
             .joined_players
             .values()
-            .any(|x| x.nickname == msg.player_data.nickname)
+            .map(|x| &x.nickname)
+            .chain(self.disconnected_players.values().map(|x| &x.nickname))
+            .any(|nickname| *nickname == msg.player_data.nickname)
         {
             return JoinResponse {
                 can_join: CanJoin::No(NICKNAME_ALREADY_TAKEN_MSG.to_owned()),
@@ -53,16 +58,24 @@ impl Handler<JoinRequest> for Lobby {
         }
 
         self.waiting_players.retain(|&x| x != id);
-        self.joined_players.insert(
-            id,
-            JoinedPlayer {
-                addr: msg.addr,
-                color: msg.player_data.color,
-                nickname: msg.player_data.nickname,
-                uuid: msg.player_data.uuid,
-                joined_at: chrono::Utc::now(),
-            },
-        );
+        let reconnect_secret = Self::generate_secret();
+        let player = JoinedPlayer {
+            addr: msg.addr,
+            color: msg.player_data.color,
+            nickname: msg.player_data.nickname,
+            uuid: msg.player_data.uuid,
+            joined_at: chrono::Utc::now(),
+            joined_late: self.phase != Phase::WaitingForPlayers,
+            reconnect_secret: reconnect_secret.clone(),
+        };
+        self.roster.insert(id, RosterEntry::from(&player));
+        self.joined_players.insert(id, player);
+
+        // a late player is shown the current question (with the time they have left) or the leaderboard
+        let snapshot = self.get_snapshot(&id).unwrap_or_else(|e| {
+            warn!("Could not create a snapshot for {id}: {e}");
+            GameSnapshot::WaitingForGame
+        });
 
         // do NOT send update to the player that just joined
         let _ = self.send_players_update(Some(&id));
@@ -70,6 +83,8 @@ impl Handler<JoinRequest> for Lobby {
         JoinResponse {
             can_join: CanJoin::Yes,
             players: self.get_players(),
+            snapshot,
+            reconnect_secret: Some(reconnect_secret),
             ..result
         }
     }
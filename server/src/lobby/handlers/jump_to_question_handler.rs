@@ -0,0 +1,33 @@
+use actix::{Context, Handler};
+use log::debug;
+
+use crate::{
+    lobby::Lobby,
+    messages::lobby::{JumpToQuestion, ReopenQuestion},
+};
+
+impl Handler<JumpToQuestion> for Lobby {
+    type Result = anyhow::Result<()>;
+
+    fn handle(&mut self, msg: JumpToQuestion, ctx: &mut Context<Self>) -> Self::Result {
+        debug!(
+            "Received JumpToQuestion message in Lobby; jumping to question {}",
+            msg.index
+        );
+
+        if self.is_asked(msg.index) {
+            return self.handle(
+                ReopenQuestion {
+                    index: msg.index,
+                    discard_results: false,
+                },
+                ctx,
+            );
+        }
+
+        self.check_can_go_to_question(msg.index)?;
+        self.start_question(ctx, msg.index);
+
+        Ok(())
+    }
+}
@@ -1,11 +1,22 @@
+mod add_time_handler;
 pub mod answer_selected_handler;
 mod disconnect_from_lobby_handler;
 mod end_question_handler;
+mod get_announcement_handler;
 mod hard_stop_handler;
 mod join_request_handler;
+mod jump_to_question_handler;
 mod kick_player_handler;
+mod pause_question_handler;
+mod reconnect_request_handler;
 mod register_teacher_handler;
+mod regrade_question_handler;
+mod reopen_question_handler;
+mod resume_question_handler;
 mod set_lock_message_handler;
 mod start_question_handler;
 pub mod switch_to_leaderboard_handler;
+mod teacher_command_handler;
+mod teacher_login_request_handler;
 mod try_join_request_handler;
+mod void_question_handler;
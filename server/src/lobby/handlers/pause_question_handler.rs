@@ -0,0 +1,33 @@
+use actix::{Context, Handler};
+use anyhow::bail;
+use chrono::Utc;
+use log::debug;
+
+use crate::{
+    lobby::{Lobby, Phase},
+    messages::lobby::PauseQuestion,
+};
+
+impl Handler<PauseQuestion> for Lobby {
+    type Result = anyhow::Result<()>;
+
+    fn handle(&mut self, _msg: PauseQuestion, ctx: &mut Context<Self>) -> Self::Result {
+        debug!("Received PauseQuestion message in Lobby; pausing question");
+
+        let Phase::ActiveQuestion(index) = self.phase else {
+            bail!("Only an active question can be paused");
+        };
+
+        if self.paused_at.is_some() {
+            bail!("The question is already paused");
+        }
+
+        self.cancel_question_timer(ctx);
+        self.paused_at = Some(Utc::now());
+
+        self.send_pause_update(index);
+        self.save_snapshot();
+
+        Ok(())
+    }
+}
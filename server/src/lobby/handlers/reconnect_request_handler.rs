@@ -0,0 +1,102 @@
+use actix::{Context, Handler};
+use common::{
+    constants::{PLAYER_CANNOT_RECONNECT_MSG, TOO_MANY_ATTEMPTS_MSG},
+    messages::network::{CanJoin, GameSnapshot, PlayerData, ReconnectResponse},
+};
+use log::{debug, info, warn};
+
+use crate::{
+    lobby::JoinedPlayer,
+    messages::{client::ReconnectRequest, websocket::HardStop},
+    Lobby,
+};
+
+impl Handler<ReconnectRequest> for Lobby {
+    type Result = ReconnectResponse;
+
+    fn handle(&mut self, msg: ReconnectRequest, _: &mut Context<Self>) -> Self::Result {
+        debug!(
+            "Received ReconnectRequest message: {:?} from {:?}",
+            msg.player_uuid, msg.addr
+        );
+
+        let id = msg.player_uuid;
+        let response = ReconnectResponse {
+            uuid: id,
+            can_reconnect: CanJoin::No(PLAYER_CANNOT_RECONNECT_MSG.to_owned()),
+            quiz_name: self.questions.quiz_name.clone(),
+            player: None,
+            players: self.get_players(),
+            snapshot: GameSnapshot::WaitingForGame,
+        };
+
+        if self.is_locked_out(msg.who.ip()) {
+            return ReconnectResponse {
+                can_reconnect: CanJoin::No(TOO_MANY_ATTEMPTS_MSG.to_owned()),
+                ..response
+            };
+        }
+
+        // the uuids of the players are known to everyone, the secret only to the player
+        let secret = self
+            .disconnected_players
+            .get(&id)
+            .map(|player| &player.reconnect_secret)
+            .or_else(|| {
+                self.joined_players
+                    .get(&id)
+                    .map(|player| &player.reconnect_secret)
+            });
+        if !secret.is_some_and(|secret| Self::secrets_match(secret, &msg.secret)) {
+            let count = self.record_failed_attempt(msg.who.ip());
+            warn!(
+                "Rejected reconnect of {id} from {} ({count} times)",
+                msg.who
+            );
+            return response;
+        }
+
+        // reattach the player to the new websocket, keeping everything else
+        let player = if let Some(disconnected) = self.disconnected_players.remove(&id) {
+            disconnected.reconnect(msg.addr)
+        } else if let Some(old) = self.joined_players.remove(&id) {
+            // the old websocket has not noticed the connection dropped yet -- hang it up
+            old.addr.do_send(HardStop);
+            JoinedPlayer {
+                addr: msg.addr,
+                ..old
+            }
+        } else {
+            return response;
+        };
+
+        let player_data = PlayerData {
+            uuid: player.uuid,
+            nickname: player.nickname.clone(),
+            color: player.color,
+        };
+        self.joined_players.insert(id, player);
+
+        let snapshot = match self.get_snapshot(&id) {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                debug!("Could not create a snapshot for {id}: {e}");
+                self.joined_players.remove(&id);
+                return response;
+            }
+        };
+
+        info!("{id} reconnected");
+
+        // do NOT send update to the player that just reconnected
+        let _ = self.send_players_update(Some(&id));
+
+        ReconnectResponse {
+            can_reconnect: CanJoin::Yes,
+            player: Some(player_data),
+            players: self.get_players(),
+            snapshot,
+            ..response
+        }
+    }
+}
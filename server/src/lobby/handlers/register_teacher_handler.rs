@@ -2,16 +2,21 @@ use actix::{Context, Handler};
 
 use crate::{messages::lobby::RegisterTeacher, Lobby};
 
-use log::debug;
+use log::{debug, warn};
 
 impl Handler<RegisterTeacher> for Lobby {
     type Result = ();
 
     fn handle(&mut self, msg: RegisterTeacher, _: &mut Context<Self>) -> Self::Result {
         debug!("Received RegisterTeacherMessage in Lobby; unlocking lobby");
-        self.teacher = Some(msg.teacher);
+        self.teacher = Some(msg.teacher.clone());
 
         // only now actually start the server (i.e. allow players to join)
         self.locked = false;
+
+        // a resumed game does not start from the beginning
+        if let Err(e) = self.send_state_to_teacher(&msg.teacher.recipient()) {
+            warn!("Could not send the state of the game to the teacher: {e}");
+        }
     }
 }
@@ -0,0 +1,49 @@
+use actix::{Context, Handler};
+use anyhow::bail;
+use log::{debug, info};
+
+use crate::{
+    lobby::Lobby,
+    messages::lobby::{RegradeQuestion, SwitchToLeaderboard},
+};
+
+impl Handler<RegradeQuestion> for Lobby {
+    type Result = anyhow::Result<()>;
+
+    fn handle(&mut self, msg: RegradeQuestion, ctx: &mut Context<Self>) -> Self::Result {
+        debug!("Received RegradeQuestion message in Lobby; grading question again");
+        let RegradeQuestion {
+            index,
+            correct_choices,
+        } = msg;
+
+        self.check_can_regrade(index)?;
+
+        let question = &mut self.questions[index];
+        if !question.kind.has_choices() {
+            bail!("Only the correct choices can be changed, but question {index} has none");
+        }
+
+        if correct_choices.is_empty() {
+            bail!("At least one choice has to be correct; void the question instead");
+        }
+
+        if !correct_choices
+            .iter()
+            .all(|id| question.choices.iter().any(|choice| choice.id == *id))
+        {
+            bail!("The correct choices do not belong to question {index}");
+        }
+
+        for choice in &mut question.choices {
+            choice.is_correct = correct_choices.contains(&choice.id);
+        }
+
+        info!("Grading question {index} again with new correct choices");
+        self.voided_questions.remove(&index);
+        self.regrade_results(index)?;
+
+        // the players see the changed scores right away
+        self.handle(SwitchToLeaderboard, ctx)
+    }
+}
@@ -0,0 +1,37 @@
+use actix::{Context, Handler};
+use anyhow::bail;
+use log::{debug, info};
+
+use crate::{lobby::Lobby, messages::lobby::ReopenQuestion};
+
+impl Handler<ReopenQuestion> for Lobby {
+    type Result = anyhow::Result<()>;
+
+    fn handle(&mut self, msg: ReopenQuestion, ctx: &mut Context<Self>) -> Self::Result {
+        debug!("Received ReopenQuestion message in Lobby; re-opening question");
+        let ReopenQuestion {
+            index,
+            discard_results,
+        } = msg;
+
+        self.check_can_go_to_question(index)?;
+
+        if !self.is_asked(index) {
+            bail!("Question {index} has not been asked yet, so it cannot be re-opened");
+        }
+
+        // the players answer anew; the previous answers count only for those who do not
+        let previous = self.results.remove(&index).unwrap_or_default();
+        if discard_results {
+            info!("Discarding the results of question {index}");
+        } else {
+            self.reopened_results.insert(index, previous);
+        }
+
+        // the question is answered anew, so it counts again
+        self.voided_questions.remove(&index);
+        self.start_question(ctx, index);
+
+        Ok(())
+    }
+}
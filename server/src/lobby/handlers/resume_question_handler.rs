@@ -0,0 +1,36 @@
+use actix::{Context, Handler};
+use anyhow::bail;
+use chrono::Utc;
+use log::debug;
+
+use crate::{
+    lobby::{Lobby, Phase},
+    messages::lobby::ResumeQuestion,
+};
+
+impl Handler<ResumeQuestion> for Lobby {
+    type Result = anyhow::Result<()>;
+
+    fn handle(&mut self, _msg: ResumeQuestion, ctx: &mut Context<Self>) -> Self::Result {
+        debug!("Received ResumeQuestion message in Lobby; resuming question");
+
+        let Phase::ActiveQuestion(index) = self.phase else {
+            bail!("Only an active question can be resumed");
+        };
+
+        let Some(paused_at) = self.paused_at.take() else {
+            bail!("The question is not paused");
+        };
+
+        // the pause does not count into the time of the question
+        if let Some(started) = self.question_start_times.get_mut(&index) {
+            *started += Utc::now() - paused_at;
+        }
+
+        self.start_question_timer(ctx, index);
+        self.send_pause_update(index);
+        self.save_snapshot();
+
+        Ok(())
+    }
+}
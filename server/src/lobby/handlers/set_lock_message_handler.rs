@@ -12,6 +12,6 @@ impl Handler<SetLockMessage> for Lobby {
             "Received SetLockMessage in Lobby; setting `locked` to `{}`",
             msg.locked
         );
-        self.locked = msg.locked;
+        self.set_locked(msg.locked);
     }
 }
@@ -1,5 +1,6 @@
 use actix::{Context, Handler};
 use anyhow::bail;
+use common::messages::ServerNetworkMessage;
 use log::debug;
 
 use crate::{
@@ -27,6 +28,16 @@ impl Handler<SwitchToLeaderboard> for Lobby {
             Phase::ShowingLeaderboard(index)
         };
 
+        self.save_snapshot();
+        if is_final {
+            self.save_results();
+
+            // the teacher gets the summary of the game after the final leaderboard
+            self.send_to_teachers(&ServerNetworkMessage::GameAnalytics(
+                self.get_game_analytics(),
+            ));
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,66 @@
+use actix::{Context, Handler};
+use common::messages::network::TeacherCommand;
+use log::debug;
+
+use crate::{
+    messages::lobby::{
+        AddTime, EndQuestion, JumpToQuestion, KickPlayer, PauseQuestion, RegradeQuestion,
+        ReopenQuestion, ResumeQuestion, SetLockMessage, StartQuestion, SwitchToLeaderboard,
+        VoidQuestion,
+    },
+    Lobby,
+};
+
+impl Handler<TeacherCommand> for Lobby {
+    type Result = anyhow::Result<()>;
+
+    /// Commands of the teacher do the same, whether they come from the server's terminal or a remote teacher
+    fn handle(&mut self, msg: TeacherCommand, ctx: &mut Context<Self>) -> Self::Result {
+        debug!("Received TeacherCommand in Lobby: {msg:?}");
+
+        match msg {
+            TeacherCommand::StartQuestion => self.handle(StartQuestion, ctx),
+            TeacherCommand::EndQuestion { index } => self.handle(EndQuestion { index }, ctx),
+            TeacherCommand::SwitchToLeaderboard => self.handle(SwitchToLeaderboard, ctx),
+            TeacherCommand::KickPlayer {
+                player_uuid,
+                reason,
+            } => self.handle(
+                KickPlayer {
+                    player_uuid,
+                    reason,
+                },
+                ctx,
+            ),
+            TeacherCommand::PauseQuestion => self.handle(PauseQuestion, ctx),
+            TeacherCommand::ResumeQuestion => self.handle(ResumeQuestion, ctx),
+            TeacherCommand::AddTime { seconds } => self.handle(AddTime { seconds }, ctx),
+            TeacherCommand::ReopenQuestion {
+                index,
+                discard_results,
+            } => self.handle(
+                ReopenQuestion {
+                    index,
+                    discard_results,
+                },
+                ctx,
+            ),
+            TeacherCommand::JumpToQuestion { index } => self.handle(JumpToQuestion { index }, ctx),
+            TeacherCommand::VoidQuestion { index } => self.handle(VoidQuestion { index }, ctx),
+            TeacherCommand::RegradeQuestion {
+                index,
+                correct_choices,
+            } => self.handle(
+                RegradeQuestion {
+                    index,
+                    correct_choices,
+                },
+                ctx,
+            ),
+            TeacherCommand::SetLock { locked } => {
+                self.handle(SetLockMessage { locked }, ctx);
+                Ok(())
+            }
+        }
+    }
+}
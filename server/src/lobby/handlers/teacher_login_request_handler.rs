@@ -0,0 +1,77 @@
+use actix::{Context, Handler};
+use chrono::Utc;
+use common::{
+    constants::{
+        INCOMPATIBLE_CLIENT_MSG, MINIMAL_PROTOCOL_VERSION, PROTOCOL_VERSION, TOO_MANY_ATTEMPTS_MSG,
+        WRONG_TEACHER_TOKEN_MSG,
+    },
+    messages::network::{CanJoin, TeacherLoginResponse},
+};
+use log::{debug, info, warn};
+
+use crate::{messages::client::TeacherLoginRequest, Lobby};
+
+impl Handler<TeacherLoginRequest> for Lobby {
+    type Result = TeacherLoginResponse;
+
+    fn handle(&mut self, msg: TeacherLoginRequest, _: &mut Context<Self>) -> Self::Result {
+        debug!("Received TeacherLoginRequest message in Lobby");
+        let TeacherLoginRequest { request, addr, who } = msg;
+
+        let response = TeacherLoginResponse {
+            can_login: CanJoin::No(String::new()),
+            quiz_name: self.questions.quiz_name.clone(),
+            pin: None,
+            protocol_version: PROTOCOL_VERSION,
+            server_time: Some(Utc::now()),
+        };
+
+        if !(MINIMAL_PROTOCOL_VERSION..=PROTOCOL_VERSION).contains(&request.protocol_version) {
+            let version = request.protocol_version;
+            warn!("Rejecting a teacher speaking protocol version {version}");
+
+            return TeacherLoginResponse {
+                can_login: CanJoin::No(format!(
+                    "{INCOMPATIBLE_CLIENT_MSG}: it speaks protocol version {version}, \
+                     but the server needs {MINIMAL_PROTOCOL_VERSION} to {PROTOCOL_VERSION}"
+                )),
+                ..response
+            };
+        }
+
+        if self.is_locked_out(who.ip()) {
+            return TeacherLoginResponse {
+                can_login: CanJoin::No(TOO_MANY_ATTEMPTS_MSG.to_owned()),
+                ..response
+            };
+        }
+
+        if !Self::secrets_match(&self.teacher_token, &request.token) {
+            let count = self.record_failed_attempt(who.ip());
+            warn!("Rejecting a teacher with a wrong token from {who} ({count} times)");
+            return TeacherLoginResponse {
+                can_login: CanJoin::No(WRONG_TEACHER_TOKEN_MSG.to_owned()),
+                ..response
+            };
+        }
+
+        // without a teacher on the server, the game starts with the first remote one
+        self.remote_teachers.retain(actix::Addr::connected);
+        if self.teacher.is_none() && self.remote_teachers.is_empty() {
+            self.locked = false;
+        }
+
+        info!("Remote teacher logged in");
+        self.remote_teachers.push(addr.clone());
+
+        if let Err(e) = self.send_state_to_teacher(&addr.recipient()) {
+            warn!("Could not send the state of the game to the teacher: {e}");
+        }
+
+        TeacherLoginResponse {
+            can_login: CanJoin::Yes,
+            pin: self.pin.clone(),
+            ..response
+        }
+    }
+}
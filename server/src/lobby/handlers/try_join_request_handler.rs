@@ -1,23 +1,45 @@
 use actix::{Context, Handler};
+use chrono::Utc;
 use common::{
-    constants::LOBBY_LOCKED_MSG,
-    messages::network::{CanJoin, TryJoinRequest, TryJoinResponse},
+    constants::{
+        INCOMPATIBLE_CLIENT_MSG, LOBBY_LOCKED_MSG, MINIMAL_PROTOCOL_VERSION,
+        PLAYER_ALREADY_JOINED_MSG, PROTOCOL_VERSION, SUPPORTED_FEATURES,
+    },
+    messages::network::{CanJoin, TryJoinResponse},
 };
-use log::debug;
+use log::{debug, warn};
 
-use crate::Lobby;
+use crate::{messages::client::TryJoinRequest, Lobby};
 
 impl Handler<TryJoinRequest> for Lobby {
     type Result = TryJoinResponse;
 
     fn handle(&mut self, msg: TryJoinRequest, _: &mut Context<Self>) -> Self::Result {
         debug!("Received TryJoinRequest message in Lobby; trying to join");
+        let TryJoinRequest { request: msg, who } = msg;
+
         let response = TryJoinResponse {
             uuid: msg.uuid,
             can_join: CanJoin::No(String::new()),
             quiz_name: self.questions.quiz_name.clone(),
+            protocol_version: PROTOCOL_VERSION,
+            features: SUPPORTED_FEATURES.to_vec(),
+            server_time: Some(Utc::now()),
         };
 
+        if !(MINIMAL_PROTOCOL_VERSION..=PROTOCOL_VERSION).contains(&msg.protocol_version) {
+            let version = msg.protocol_version;
+            warn!("Rejecting a client speaking protocol version {version}");
+
+            return TryJoinResponse {
+                can_join: CanJoin::No(format!(
+                    "{INCOMPATIBLE_CLIENT_MSG}: it speaks protocol version {version}, \
+                     but the server needs {MINIMAL_PROTOCOL_VERSION} to {PROTOCOL_VERSION}"
+                )),
+                ..response
+            };
+        }
+
         if self.locked {
             return TryJoinResponse {
                 can_join: CanJoin::No(LOBBY_LOCKED_MSG.to_owned()),
@@ -25,6 +47,21 @@ impl Handler<TryJoinRequest> for Lobby {
             };
         }
 
+        // the request may be repeated with another PIN, but not after joining
+        if self.joined_players.contains_key(&msg.uuid) {
+            return TryJoinResponse {
+                can_join: CanJoin::No(PLAYER_ALREADY_JOINED_MSG.to_owned()),
+                ..response
+            };
+        }
+
+        if let Err(reason) = self.check_pin(msg.pin.as_deref(), who.ip()) {
+            return TryJoinResponse {
+                can_join: CanJoin::No(reason.to_string()),
+                ..response
+            };
+        }
+
         self.waiting_players.insert(msg.uuid);
 
         TryJoinResponse {
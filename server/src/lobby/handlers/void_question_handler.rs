@@ -0,0 +1,25 @@
+use actix::{Context, Handler};
+use log::{debug, info};
+
+use crate::{
+    lobby::Lobby,
+    messages::lobby::{SwitchToLeaderboard, VoidQuestion},
+};
+
+impl Handler<VoidQuestion> for Lobby {
+    type Result = anyhow::Result<()>;
+
+    fn handle(&mut self, msg: VoidQuestion, ctx: &mut Context<Self>) -> Self::Result {
+        debug!("Received VoidQuestion message in Lobby; voiding question");
+        let VoidQuestion { index } = msg;
+
+        self.check_can_regrade(index)?;
+
+        info!("Voiding question {index}, nobody gets points for it");
+        self.voided_questions.insert(index);
+        self.regrade_results(index)?;
+
+        // the players see the changed scores right away
+        self.handle(SwitchToLeaderboard, ctx)
+    }
+}
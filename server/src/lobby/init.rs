@@ -1,26 +1,30 @@
 use actix::{Actor, Addr};
 
-use common::questions::QuestionSet;
-use log::info;
-use tokio::net::TcpListener;
+use log::{info, warn};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_rustls::TlsAcceptor;
 
 use std::{net::SocketAddr, sync::mpsc::Sender};
 
 use super::Lobby;
-use crate::websocket::Websocket;
+use crate::{connection::Connection, discovery::announce, web, websocket::Websocket};
 
-/// Starts the server and send the address of the lobby through the given channel.
+/// Starts the server with the given `lobby` and send the address of the lobby through the given channel.
+/// If `tls` is given, all connections are encrypted with it.
+/// If `discoverable` is set, the game is announced on the local network.
 /// # Errors
 /// - If the tokio runtime cannot be created
 /// - If the server cannot be started
 pub fn run_server(
     tx: Sender<Addr<Lobby>>,
-    questions: QuestionSet,
+    lobby: Lobby,
     addr: SocketAddr,
+    tls: Option<TlsAcceptor>,
+    discoverable: bool,
 ) -> anyhow::Result<()> {
     let system = actix::System::new();
 
-    system.block_on(init(tx, questions, addr))?;
+    system.block_on(init(tx, lobby, addr, tls, discoverable))?;
 
     system.run()?;
 
@@ -30,15 +34,27 @@ pub fn run_server(
 #[allow(clippy::unused_async)]
 async fn init(
     tx: Sender<Addr<Lobby>>,
-    questions: QuestionSet,
+    lobby: Lobby,
     addr: SocketAddr,
+    tls: Option<TlsAcceptor>,
+    discoverable: bool,
 ) -> anyhow::Result<()> {
     // spawn an actor for managing the lobby
-    let lobby_actor = Lobby::new(questions).start();
+    let lobby_actor = lobby.start();
+
+    if discoverable {
+        let lobby = lobby_actor.clone();
+        let uses_tls = tls.is_some();
+        tokio::task::spawn_local(async move {
+            if let Err(e) = announce(lobby, addr.port(), uses_tls).await {
+                warn!("Cannot announce the game on the local network: {e}");
+            }
+        });
+    }
 
     // spawn task for accepting connections
     let _connection_acceptor =
-        tokio::task::spawn_local(accept_connections(addr, lobby_actor.clone()));
+        tokio::task::spawn_local(accept_connections(addr, lobby_actor.clone(), tls));
 
     // send the address of the lobby to the main thread
     let _ = tx.send(lobby_actor.clone());
@@ -46,7 +62,11 @@ async fn init(
     Ok(())
 }
 
-async fn accept_connections(addr: SocketAddr, lobby: Addr<Lobby>) -> anyhow::Result<()> {
+async fn accept_connections(
+    addr: SocketAddr,
+    lobby: Addr<Lobby>,
+    tls: Option<TlsAcceptor>,
+) -> anyhow::Result<()> {
     // create a TCP socket listener
 
     let listener = TcpListener::bind(addr).await?;
@@ -59,8 +79,36 @@ async fn accept_connections(addr: SocketAddr, lobby: Addr<Lobby>) -> anyhow::Res
 
         info!("Accepted connection from: {who:?}");
 
-        // spawn a actor for managing the connection
-        let ws = Websocket::new(lobby.clone(), socket, who).await?;
-        let _ = ws.start();
+        let lobby = lobby.clone();
+        let tls = tls.clone();
+
+        // a slow or broken client must not block accepting others
+        tokio::task::spawn_local(async move {
+            if let Err(e) = handle_connection(socket, who, lobby, tls).await {
+                warn!("Connection from {who:?} failed: {e}");
+            }
+        });
+    }
+}
+
+/// Serves the browser client to plain HTTP requests and hands websocket upgrades over to a new actor
+async fn handle_connection(
+    socket: TcpStream,
+    who: SocketAddr,
+    lobby: Addr<Lobby>,
+    tls: Option<TlsAcceptor>,
+) -> anyhow::Result<()> {
+    let mut connection = Connection::accept(socket, tls.as_ref()).await?;
+    let head = connection.read_request_head().await?;
+
+    if !web::is_websocket_upgrade(&head) {
+        info!("Serving the web client to: {who:?}");
+        return web::serve(connection, &head).await;
     }
+
+    // spawn a actor for managing the connection
+    let ws = Websocket::new(lobby, connection, who).await?;
+    let _ = ws.start();
+
+    Ok(())
 }
@@ -1,23 +1,44 @@
-use actix::prelude::{Actor, Context};
+use actix::{
+    prelude::{Actor, Context},
+    AsyncContext,
+};
 use anyhow::{bail, Ok};
 use common::{
+    constants::{
+        ANSWER_GRACE_PERIOD_MS, LOCKOUT_SECS, MAXIMAL_FAILED_ATTEMPTS, PIN_REQUIRED_MSG,
+        RECONNECT_GRACE_PERIOD_SECS, TOO_MANY_PIN_ATTEMPTS_MSG, WRONG_PIN_MSG,
+    },
     messages::{
         network::{
-            ChoiceStats, NextQuestion, PlayerData, PlayersUpdate, QuestionEnded, QuestionUpdate,
-            ShowLeaderboard,
+            AnswerDistribution, ChoiceStats, GameSnapshot, LockUpdate, NextQuestion, PauseUpdate,
+            PlayerData, PlayersUpdate, QuestionEnded, QuestionList, QuestionListItem,
+            QuestionUpdate, ShowLeaderboard,
         },
         ServerNetworkMessage,
     },
-    questions::{QuestionCensored, QuestionSet},
+    questions::{Question, QuestionCensored, QuestionSet, QuestionType},
 };
 
-use log::debug;
+use chrono::{DateTime, Utc};
+use log::{debug, info};
 use rand::prelude::*;
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    net::IpAddr,
+    time::Duration,
+};
 use uuid::Uuid;
 
-use super::state::{Lobby, Phase};
+use crate::messages::lobby::EndQuestion;
+
+use super::{
+    point_calculator::{calculate_points, total_points, ScoringContext},
+    state::{DisconnectedPlayer, FailedAttempts, Lobby, Phase, PlayerQuestionRecord},
+    ResultsFormat,
+};
+
+const PIN_LENGTH: usize = 6;
 
 impl Lobby {
     #[must_use]
@@ -29,20 +50,126 @@ impl Lobby {
 
         if questions.randomize_answers {
             let mut rng = rand::thread_rng();
+            // the items of ordering questions are listed in the right order, so keep them
             for question in &mut questions.questions {
-                question.choices.shuffle(&mut rng);
+                if question.kind != QuestionType::Ordering {
+                    question.choices.shuffle(&mut rng);
+                }
             }
         }
 
         Lobby {
             teacher: None,
+            remote_teachers: Vec::new(),
+            teacher_token: Self::generate_secret(),
             phase: Phase::default(),
             locked: true,
+            lock_on_start: false,
+            pin: None,
+            failed_attempts: HashMap::new(),
             joined_players: HashMap::new(),
+            roster: HashMap::new(),
             questions,
             waiting_players: HashSet::new(),
             results: HashMap::new(),
+            reopened_results: HashMap::new(),
+            voided_questions: HashSet::new(),
+            disconnected_players: HashMap::new(),
+            question_start_times: HashMap::new(),
+            question_timer: None,
+            paused_at: None,
+            extra_time: HashMap::new(),
+            answer_grace: Duration::from_millis(ANSWER_GRACE_PERIOD_MS),
+            results_file: None,
+            results_format: ResultsFormat::default(),
+            snapshot_file: None,
+        }
+    }
+
+    /// Generates a random secret, like the token of the teachers
+    #[must_use]
+    pub fn generate_secret() -> String {
+        Uuid::new_v4().simple().to_string()
+    }
+
+    /// Compares a secret with the expected one in time that does not depend on where they differ,
+    /// so that it cannot be guessed character by character; an empty secret never matches
+    #[must_use]
+    pub fn secrets_match(expected: &str, given: &str) -> bool {
+        let (expected, given) = (expected.as_bytes(), given.as_bytes());
+
+        !expected.is_empty()
+            && expected.len() == given.len()
+            && expected
+                .iter()
+                .zip(given)
+                .fold(0, |difference, (a, b)| difference | (a ^ b))
+                == 0
+    }
+
+    /// Generates a random numeric PIN for the game
+    #[must_use]
+    pub fn generate_pin() -> String {
+        let mut rng = rand::thread_rng();
+        (0..PIN_LENGTH)
+            .map(|_| char::from(b'0' + rng.gen_range(0..10)))
+            .collect()
+    }
+
+    /// Returns whether the address `ip` sent too many wrong PINs or secrets lately
+    /// and has to wait before trying again
+    pub fn is_locked_out(&mut self, ip: IpAddr) -> bool {
+        let lockout = chrono::Duration::seconds(LOCKOUT_SECS);
+
+        let Some(failures) = self.failed_attempts.get(&ip) else {
+            return false;
+        };
+        if Utc::now() - failures.last_failure >= lockout {
+            self.failed_attempts.remove(&ip);
+            return false;
         }
+
+        failures.count >= MAXIMAL_FAILED_ATTEMPTS
+    }
+
+    /// Counts a wrong PIN or secret sent from the address `ip`; returns how many there were lately
+    pub fn record_failed_attempt(&mut self, ip: IpAddr) -> u32 {
+        let now = Utc::now();
+        let failures = self.failed_attempts.entry(ip).or_insert(FailedAttempts {
+            count: 0,
+            last_failure: now,
+        });
+        failures.count += 1;
+        failures.last_failure = now;
+
+        failures.count
+    }
+
+    /// Checks the PIN a player entered from the address `ip`.
+    /// After too many wrong PINs, the address has to wait before trying again.
+    /// # Errors
+    /// - if the PIN is missing or wrong, or the address is waiting; the error is the reason for the player
+    pub fn check_pin(&mut self, pin: Option<&str>, ip: IpAddr) -> anyhow::Result<()> {
+        let Some(expected) = self.pin.clone() else {
+            return Ok(());
+        };
+
+        if self.is_locked_out(ip) {
+            bail!(TOO_MANY_PIN_ATTEMPTS_MSG);
+        }
+
+        let Some(pin) = pin else {
+            bail!(PIN_REQUIRED_MSG);
+        };
+
+        if pin.trim() != expected {
+            let count = self.record_failed_attempt(ip);
+            info!("Wrong PIN entered from {ip} ({count} times)");
+            bail!(WRONG_PIN_MSG);
+        }
+
+        self.failed_attempts.remove(&ip);
+        Ok(())
     }
 
     #[must_use]
@@ -61,6 +188,19 @@ impl Lobby {
             .collect()
     }
 
+    /// Returns the players who joined after the game started, in the order of `get_players`
+    #[must_use]
+    pub fn get_late_joiners(&self) -> Vec<Uuid> {
+        use itertools::Itertools;
+
+        self.joined_players
+            .values()
+            .filter(|player| player.joined_late)
+            .sorted_by_key(|player| player.joined_at)
+            .map(|player| player.uuid)
+            .collect()
+    }
+
     /// Returns the index of the next question, or an error if there is no next question
     /// # Errors
     /// - when the game has ended
@@ -70,29 +210,156 @@ impl Lobby {
             return Err(anyhow::anyhow!("Can't show next question"));
         }
 
-        match self.phase {
-            Phase::WaitingForPlayers => Ok(0),
+        let next = match self.phase {
+            Phase::WaitingForPlayers => self.get_question_after(None),
             Phase::ActiveQuestion(index)
             | Phase::AfterQuestion(index)
-            | Phase::ShowingLeaderboard(index) => Ok(index + 1),
-            Phase::GameEnded => Err(anyhow::anyhow!(
-                "Cannot call next_question when game has ended"
-            )),
-        }
+            | Phase::ShowingLeaderboard(index) => self.get_question_after(Some(index)),
+            Phase::GameEnded => {
+                bail!("Cannot call next_question when game has ended")
+            }
+        };
+
+        next.ok_or_else(|| anyhow::anyhow!("There is no question left to ask"))
     }
 
     #[must_use]
     pub fn can_show_next_question(&self) -> bool {
         match self.phase {
             Phase::WaitingForPlayers => true,
-            Phase::ShowingLeaderboard(index) => {
-                // only show next question if we are not at the last question
-                index < self.questions.len() - 1
-            }
+            // only show next question if there is one left after the current one
+            Phase::ShowingLeaderboard(index) => self.get_question_after(Some(index)).is_some(),
             _ => false,
         }
     }
 
+    /// Whether the question with `index` has been started, at least once
+    #[must_use]
+    pub fn is_asked(&self, index: usize) -> bool {
+        self.question_start_times.contains_key(&index)
+    }
+
+    /// The first question after the one with `index` (or the first question at all) which has not been asked yet.
+    /// The game goes on from there when the teacher went back to an earlier question or jumped ahead.
+    #[must_use]
+    pub fn get_question_after(&self, index: Option<usize>) -> Option<usize> {
+        let first = index.map_or(0, |index| index + 1);
+
+        (first..self.questions.len()).find(|index| !self.is_asked(*index))
+    }
+
+    /// Checks that the teacher can go to the question with `index` now, that is between the questions
+    pub(super) fn check_can_go_to_question(&self, index: usize) -> anyhow::Result<()> {
+        if index >= self.questions.len() {
+            bail!("There is no question with index {index}");
+        }
+
+        match self.phase {
+            Phase::WaitingForPlayers | Phase::AfterQuestion(_) | Phase::ShowingLeaderboard(_) => {
+                Ok(())
+            }
+            Phase::ActiveQuestion(_) => bail!("The active question has to end first"),
+            Phase::GameEnded => bail!("The game has already ended"),
+        }
+    }
+
+    /// Starts the question with `index`: sends it to everyone and starts its timer
+    pub(super) fn start_question(&mut self, ctx: &mut Context<Self>, index: usize) {
+        // latecomers are not let in once the game is under way, if the teacher wants so
+        if self.phase == Phase::WaitingForPlayers && self.lock_on_start {
+            self.set_locked(true);
+        }
+
+        self.phase = Phase::ActiveQuestion(index);
+        self.question_start_times.insert(index, Utc::now());
+        // a question started again gets only its own time
+        self.extra_time.remove(&index);
+
+        self.send_question(index);
+        self.send_to_teachers(&ServerNetworkMessage::QuestionList(
+            self.get_question_list(),
+        ));
+        self.save_snapshot();
+
+        // the timer can be cancelled when the question is paused or ends sooner
+        self.start_question_timer(ctx, index);
+    }
+
+    /// Gives the players who did not answer the re-opened question with `index` again their previous answers
+    pub(super) fn restore_reopened_results(&mut self, index: usize) {
+        let Some(previous) = self.reopened_results.remove(&index) else {
+            return;
+        };
+
+        let results = self.results.entry(index).or_default();
+        for (player, record) in previous {
+            results.entry(player).or_insert(record);
+        }
+    }
+
+    /// Checks that the question with `index` has just ended, as only then the teacher can change its grading
+    pub(super) fn check_can_regrade(&self, index: usize) -> anyhow::Result<()> {
+        if self.phase != Phase::AfterQuestion(index) {
+            bail!("Only the question which has just ended can be graded again");
+        }
+
+        Ok(())
+    }
+
+    /// Computes the points for all answers to the question with `index` again,
+    /// after the teacher voided it or changed its correct choices
+    pub(super) fn regrade_results(&mut self, index: usize) -> anyhow::Result<()> {
+        let voided = self.voided_questions.contains(&index);
+        let started = self.question_start_times.get(&index).copied();
+        let records = self.results.get(&index).cloned().unwrap_or_default();
+
+        let mut points = HashMap::new();
+        for (player, record) in &records {
+            let awarded = if voided {
+                0
+            } else {
+                calculate_points(&ScoringContext {
+                    player: *player,
+                    answer_order: record.answer_order.saturating_sub(1),
+                    total_players: self.joined_players.len(),
+                    question_index: index,
+                    answers: &record.selected_answers,
+                    written_answer: record.written_answer.as_ref(),
+                    order: record.order.as_deref(),
+                    elapsed: started
+                        .map_or_else(chrono::Duration::zero, |started| record.timestamp - started),
+                    extra_seconds: self.get_extra_seconds(index),
+                    questions: &self.questions,
+                    results: &self.results,
+                })?
+            };
+            debug!("Player {player} now has {awarded} points for question {index}");
+
+            points.insert(*player, awarded);
+        }
+
+        for (player, record) in self.results.entry(index).or_default() {
+            record.points_awarded = points[player];
+        }
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn get_question_list(&self) -> QuestionList {
+        QuestionList {
+            questions: self
+                .questions
+                .iter()
+                .enumerate()
+                .map(|(index, question)| QuestionListItem {
+                    text: question.text.clone(),
+                    asked: self.is_asked(index),
+                })
+                .collect(),
+        }
+    }
+
     fn get_question_stats(&self, index: usize) -> anyhow::Result<HashMap<Uuid, ChoiceStats>> {
         let mut stats: HashMap<Uuid, ChoiceStats> =
             HashMap::with_capacity(self.questions[index].choices.len());
@@ -128,77 +395,89 @@ impl Lobby {
         Ok(stats)
     }
 
-    fn get_player_answer(&self, index: usize, player_id: &Uuid) -> Option<HashSet<Uuid>> {
+    fn get_player_record(&self, index: usize, player_id: &Uuid) -> Option<&PlayerQuestionRecord> {
         self.results
             .get(&index)
             .and_then(|results| results.get(player_id))
+    }
+
+    fn get_player_answer(&self, index: usize, player_id: &Uuid) -> Option<HashSet<Uuid>> {
+        self.get_player_record(index, player_id)
             .map(|record| record.selected_answers.clone())
     }
 
-    pub fn send_question_ended(&self, index: usize) -> anyhow::Result<()> {
-        let stats = self.get_question_stats(index)?;
+    pub(super) fn get_question_ended(
+        &self,
+        index: usize,
+        player_id: Option<&Uuid>,
+    ) -> anyhow::Result<QuestionEnded> {
+        Ok(QuestionEnded {
+            stats: self.get_question_stats(index)?,
+            player_answer: player_id.and_then(|id| self.get_player_answer(index, id)),
+            player_written_answer: player_id
+                .and_then(|id| self.get_player_record(index, id))
+                .and_then(|record| record.written_answer.clone()),
+            player_order: player_id
+                .and_then(|id| self.get_player_record(index, id))
+                .and_then(|record| record.order.clone()),
+            question_index: index,
+            question: self.get_question(index),
+        })
+    }
 
-        for (player_id, socket_recipient) in &self.joined_players {
+    pub fn send_question_ended(&self, index: usize) -> anyhow::Result<()> {
+        for player_id in self.joined_players.keys() {
             debug!("Sending QuestionEnded to player {player_id}");
-            socket_recipient.do_send(ServerNetworkMessage::QuestionEnded(QuestionEnded {
-                stats: stats.clone(),
-                player_answer: self.get_player_answer(index, player_id),
-                question_index: index,
-                question: self.questions[index].clone(),
-            }));
+            let message = self.get_question_ended(index, Some(player_id))?;
+            self.joined_players[player_id].do_send(ServerNetworkMessage::QuestionEnded(message));
         }
 
-        // and also to the teacher
-        let Some(ref teacher) = self.teacher else {
-            anyhow::bail!("Cannot send to teacher, Teacher is null");
-        };
-
-        teacher.do_send(QuestionEnded {
-            stats: stats.clone(),
-            player_answer: None,
-            question_index: index,
-            question: self.questions[index].clone(),
-        });
+        // and also to the teacher, who can see what everyone answered
+        self.send_to_teachers(&ServerNetworkMessage::QuestionEnded(
+            self.get_question_ended(index, None)?,
+        ));
+        self.send_to_teachers(&ServerNetworkMessage::AnswerDetails(
+            self.get_answer_details(index),
+        ));
         Ok(())
     }
 
-    pub fn send_leaderboard(&self, index: usize) -> anyhow::Result<bool> {
+    pub(super) fn get_leaderboard(&self, index: usize) -> ShowLeaderboard {
         use itertools::Itertools;
-        let is_final = index == self.questions.len() - 1;
 
-        let message = ShowLeaderboard {
-            was_final_round: is_final,
+        ShowLeaderboard {
+            was_final_round: self.get_question_after(Some(index)).is_none(),
             players: self
                 .get_players()
                 .into_iter()
                 .map(|player| {
-                    // here, sum up the scores for each question so far
-
-                    let score = (0..=index)
-                        .map(|question_index| {
-                            self.results
-                                .get(&question_index)
-                                .and_then(|results| results.get(&player.uuid))
-                                .map_or(0, |record| record.points_awarded)
-                        })
-                        .sum();
+                    // here, sum up the scores for each question asked so far -- in whatever order it was,
+                    // a re-opened question has a single result and a discarded one none
+                    let score = total_points(
+                        self.results
+                            .values()
+                            .filter_map(|results| results.get(&player.uuid))
+                            .map(|record| record.points_awarded),
+                    );
 
                     (player, score)
                 })
                 // sort by score descending
                 .sorted_by_key(|(_, score)| std::cmp::Reverse(*score))
                 .collect(),
-        };
+        }
+    }
+
+    pub fn send_leaderboard(&self, index: usize) -> anyhow::Result<bool> {
+        let message = self.get_leaderboard(index);
+        let is_final = message.was_final_round;
 
         // send it to all students
-        self.send_to_all(&ServerNetworkMessage::ShowLeaderboard(message.clone()));
+        let message = ServerNetworkMessage::ShowLeaderboard(message);
+        self.send_to_all(&message);
 
         // and also to the teacher
-        let Some(ref teacher) = self.teacher else {
-            anyhow::bail!("Cannot send to teacher, Teacher is null");
-        };
-
-        teacher.do_send(message);
+        self.send_to_teachers(&message);
 
         Ok(is_final)
     }
@@ -216,42 +495,202 @@ impl Lobby {
         };
 
         // send it to all students
-        self.send_to_all(&ServerNetworkMessage::QuestionUpdate(message.clone()));
+        let message = ServerNetworkMessage::QuestionUpdate(message);
+        self.send_to_all(&message);
 
         // and also to the teacher
-        let Some(ref teacher) = self.teacher else {
-            anyhow::bail!("Cannot send to teacher, Teacher is null");
-        };
+        self.send_to_teachers(&message);
+
+        // only the teachers may see what the players answered before the question ends
+        self.send_answer_distribution(index)
+    }
+
+    pub(super) fn get_answer_distribution(
+        &self,
+        index: usize,
+    ) -> anyhow::Result<AnswerDistribution> {
+        Ok(AnswerDistribution {
+            question_index: index,
+            stats: self.get_question_stats(index)?,
+        })
+    }
 
-        teacher.do_send(message);
+    pub fn send_answer_distribution(&self, index: usize) -> anyhow::Result<()> {
+        self.send_to_teachers(&ServerNetworkMessage::AnswerDistribution(
+            self.get_answer_distribution(index)?,
+        ));
         Ok(())
     }
 
-    pub fn send_question(&self, index: usize) -> anyhow::Result<usize> {
-        let question = self.questions[index].clone();
+    /// Items of ordering questions are shuffled differently on every call,
+    /// so that every player gets them in their own order
+    /// Seconds the teacher added to the question with `index` while it was running
+    pub(super) fn get_extra_seconds(&self, index: usize) -> usize {
+        self.extra_time.get(&index).copied().unwrap_or(0)
+    }
 
-        let answer_time = question.time_seconds;
-        let reading_time = question.get_reading_time_estimate();
+    /// The question with `index` with the time the teacher added to it
+    pub(super) fn get_question(&self, index: usize) -> Question {
+        let mut question = self.questions[index].clone();
+        question.time_seconds += self.get_extra_seconds(index);
 
-        // construct a message object
-        let message = NextQuestion {
+        question
+    }
+
+    pub(super) fn get_next_question(&self, index: usize) -> NextQuestion {
+        let mut question = self.get_question(index);
+
+        if question.kind == QuestionType::Ordering {
+            let mut rng = rand::thread_rng();
+            let right_order = question.choices.clone();
+            // the right order must not be given away (the question has at least two items)
+            while question.choices == right_order {
+                question.choices.shuffle(&mut rng);
+            }
+        }
+
+        NextQuestion {
             question_index: index,
             questions_count: self.questions.len(),
             show_choices_after: question.get_reading_time_estimate(),
             question: QuestionCensored::from(question),
-        };
+            choices_visible_at: self.get_choices_visible_at(index),
+            deadline: self.get_deadline(index),
+        }
+    }
 
-        // send it to all students
-        self.send_to_all(&ServerNetworkMessage::NextQuestion(message.clone()));
+    pub fn send_question(&self, index: usize) {
+        let message = self.get_next_question(index);
+
+        // send it to all students, each gets their own copy (see `get_next_question`)
+        for player in self.joined_players.values() {
+            player.do_send(ServerNetworkMessage::NextQuestion(
+                self.get_next_question(index),
+            ));
+        }
 
         // and also to the teacher
-        let Some(ref teacher) = self.teacher else {
-            anyhow::bail!("Cannot send to teacher, Teacher is null");
+        self.send_to_teachers(&ServerNetworkMessage::NextQuestion(message));
+    }
+
+    /// How long the question with `index` has been running, not counting the pauses
+    pub(super) fn get_elapsed(&self, index: usize) -> chrono::Duration {
+        let now = self.paused_at.unwrap_or_else(Utc::now);
+
+        self.question_start_times
+            .get(&index)
+            .map_or_else(chrono::Duration::zero, |started| now - *started)
+    }
+
+    pub(super) fn get_elapsed_seconds(&self, index: usize) -> usize {
+        usize::try_from(self.get_elapsed(index).num_seconds()).unwrap_or(0)
+    }
+
+    /// When the choices of the question with `index` appear, on the clock of the server;
+    /// not known while the question is paused, as it depends on how long the pause takes
+    pub(super) fn get_choices_visible_at(&self, index: usize) -> Option<DateTime<Utc>> {
+        if self.paused_at.is_some() {
+            return None;
+        }
+
+        let reading = self.questions[index].get_reading_time_estimate();
+        let started = self.question_start_times.get(&index)?;
+
+        Some(*started + chrono::Duration::seconds(reading.try_into().unwrap_or(0)))
+    }
+
+    /// When the time of the question with `index` is up, on the clock of the server (see `get_choices_visible_at`)
+    pub(super) fn get_deadline(&self, index: usize) -> Option<DateTime<Utc>> {
+        let time_seconds = self.get_question(index).time_seconds;
+
+        self.get_choices_visible_at(index).map(|visible| {
+            visible + chrono::Duration::seconds(time_seconds.try_into().unwrap_or(0))
+        })
+    }
+
+    /// Ends the question with `index` when the rest of its time is up
+    pub(super) fn start_question_timer(&mut self, ctx: &mut Context<Self>, index: usize) {
+        let question = self.get_question(index);
+        let duration = Duration::from_secs(
+            (question.get_reading_time_estimate() + question.time_seconds)
+                .try_into()
+                .unwrap_or(0),
+        );
+        // the answers sent right before the deadline are still on their way
+        let remaining = duration
+            .saturating_sub(self.get_elapsed(index).to_std().unwrap_or_default())
+            + self.answer_grace;
+
+        debug!("Question {index} ends in {remaining:?}");
+        self.question_timer = Some(ctx.notify_later(EndQuestion { index }, remaining));
+    }
+
+    /// Stops the timer of the active question, so that it does not end the question
+    pub(super) fn cancel_question_timer(&mut self, ctx: &mut Context<Self>) {
+        if let Some(timer) = self.question_timer.take() {
+            ctx.cancel_future(timer);
+        }
+    }
+
+    /// Tells everyone whether the question with `index` is paused, along with its elapsed time
+    pub(super) fn send_pause_update(&self, index: usize) {
+        let message = ServerNetworkMessage::PauseUpdate(PauseUpdate {
+            question_index: index,
+            paused: self.paused_at.is_some(),
+            elapsed_seconds: self.get_elapsed_seconds(index),
+            deadline: self.get_deadline(index),
+        });
+
+        self.send_to_all(&message);
+        self.send_to_teachers(&message);
+    }
+
+    /// Returns everything the player with `player_id` needs to see the current state of the game
+    /// # Errors
+    /// - when the stats of the current question cannot be calculated
+    pub fn get_snapshot(&self, player_id: &Uuid) -> anyhow::Result<GameSnapshot> {
+        let snapshot = match self.phase {
+            Phase::WaitingForPlayers => GameSnapshot::WaitingForGame,
+            Phase::ActiveQuestion(index) => GameSnapshot::Question {
+                question: self.get_next_question(index),
+                players_answered_count: self.results.get(&index).map_or(0, HashMap::len),
+                player_answer: self.get_player_answer(index, player_id),
+                elapsed_seconds: self.get_elapsed_seconds(index),
+                paused: self.paused_at.is_some(),
+            },
+            Phase::AfterQuestion(index) => {
+                GameSnapshot::QuestionEnded(self.get_question_ended(index, Some(player_id))?)
+            }
+            Phase::ShowingLeaderboard(index) => {
+                GameSnapshot::Leaderboard(self.get_leaderboard(index))
+            }
+            Phase::GameEnded => {
+                GameSnapshot::Leaderboard(self.get_leaderboard(self.questions.len() - 1))
+            }
         };
 
-        teacher.do_send(message);
+        Ok(snapshot)
+    }
 
-        Ok(reading_time + answer_time)
+    /// Forgets the `player` if they do not reconnect within the grace period
+    pub(super) fn forget_player_later(&self, ctx: &mut Context<Self>, player: &DisconnectedPlayer) {
+        let id = player.uuid;
+        let disconnected_at = player.disconnected_at;
+
+        ctx.run_later(
+            Duration::from_secs(RECONNECT_GRACE_PERIOD_SECS),
+            move |lobby, _| {
+                // only forget the player if they did not reconnect (and drop again) in the meantime
+                if lobby
+                    .disconnected_players
+                    .get(&id)
+                    .is_some_and(|player| player.disconnected_at == disconnected_at)
+                {
+                    info!("{id} did not reconnect in time");
+                    lobby.disconnected_players.remove(&id);
+                }
+            },
+        );
     }
 
     /// Sends the `message` to all joined players
@@ -270,12 +709,34 @@ impl Lobby {
         }
     }
 
+    /// Locks or unlocks the lobby and shows the new state to the teachers
+    pub fn set_locked(&mut self, locked: bool) {
+        self.locked = locked;
+        self.send_to_teachers(&ServerNetworkMessage::LockUpdate(LockUpdate { locked }));
+    }
+
+    /// Sends the `message` to the teacher on the server and to the remote teachers
+    pub fn send_to_teachers(&self, message: &ServerNetworkMessage) {
+        if let Some(teacher) = &self.teacher {
+            teacher.do_send(message.clone());
+        }
+
+        for teacher in self
+            .remote_teachers
+            .iter()
+            .filter(|teacher| teacher.connected())
+        {
+            teacher.do_send(message.clone());
+        }
+    }
+
     /// Sends the `PlayersUpdate` to all currently joined players. Should be invoked
     /// whenever the list of players changes.
     /// If `except` is not None, the message will not be sent to the player with this id.
     pub fn send_players_update(&self, except: Option<&Uuid>) -> anyhow::Result<()> {
         let message = PlayersUpdate {
             players: self.get_players(),
+            late_joiners: self.get_late_joiners(),
         };
 
         // (maybe) NICE TO HAVE: delay sending of the update by 100 ms, and
@@ -287,11 +748,7 @@ impl Lobby {
         }
 
         // also send to teacher
-        let Some(ref teacher) = self.teacher else {
-            anyhow::bail!("Cannot send to teacher, Teacher is null");
-        };
-
-        teacher.do_send(message);
+        self.send_to_teachers(&ServerNetworkMessage::PlayersUpdate(message));
         Ok(())
     }
 }
@@ -299,8 +756,9 @@ impl Lobby {
 impl Actor for Lobby {
     type Context = Context<Self>;
 
-    fn started(&mut self, _ctx: &mut Self::Context) {
+    fn started(&mut self, ctx: &mut Self::Context) {
         debug!("Lobby started");
+        self.resume_timers(ctx);
     }
 
     fn stopping(&mut self, _ctx: &mut Self::Context) -> actix::prelude::Running {
@@ -1,10 +1,15 @@
+mod analytics;
 mod handlers;
 mod init;
 mod lobby_impl;
 mod point_calculator;
+mod report;
+mod snapshot;
 mod state;
 
 pub use handlers::*;
 pub use init::*;
 pub use point_calculator::*;
+pub use report::*;
+pub use snapshot::*;
 pub use state::*;
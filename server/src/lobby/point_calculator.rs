@@ -1,166 +1,577 @@
 use std::collections::HashSet;
 
 use anyhow::anyhow;
-use common::{messages::network::AnswerSelected, questions::QuestionSet};
+use chrono::Duration;
+use common::{
+    messages::network::WrittenAnswer,
+    questions::{Question, QuestionSet, QuestionType, Scoring},
+};
 use log::debug;
 use uuid::Uuid;
 
 use super::state::QuestionRecords;
 
-pub fn calculate_points(
-    player: Uuid,
-    answer_order: usize,
-    total_players: usize,
-    question: usize,
-    answers: &AnswerSelected,
-    questions: &QuestionSet,
-    _results: &QuestionRecords,
-) -> anyhow::Result<usize> {
-    // find the question
-    let question = questions
-        .get(question)
-        .ok_or(anyhow!("Question not found"))?;
-
-    // find the correct answers
-    let correct_answers = question
-        .choices
-        .iter()
-        .filter(|choice| choice.is_correct)
-        .map(|choice| choice.id)
-        .collect::<HashSet<_>>();
-    debug!("Question has {} correct answers", correct_answers.len());
-
-    let num_correct = answers.answers.intersection(&correct_answers).count();
-
-    let num_wrong = answers.answers.len() - num_correct;
-
-    debug!("Player {player} got {num_correct} correct and {num_wrong} wrong");
-
-    debug!("Total players: {total_players}, answer order: {answer_order}");
-
-    // Correct modifier
-    let modifier = total_players - answer_order + 10; // magic constant
-    debug!("Modifier: {modifier}");
-
-    let final_points = modifier * usize::saturating_sub(num_correct * 10, num_wrong * 5);
+/// Everything a scoring strategy may need to know about a single answer
+pub struct ScoringContext<'a> {
+    pub player: Uuid,
+    /// How many players answered the question before this one
+    pub answer_order: usize,
+    pub total_players: usize,
+    pub question_index: usize,
+    pub answers: &'a HashSet<Uuid>,
+    /// Answer to a text or numeric question
+    pub written_answer: Option<&'a WrittenAnswer>,
+    /// Answer to an ordering question
+    pub order: Option<&'a [Uuid]>,
+    /// Time between the start of the question and the moment the answer arrived
+    pub elapsed: Duration,
+    /// Seconds the teacher added to the time of the question
+    pub extra_seconds: usize,
+    pub questions: &'a QuestionSet,
+    /// Answers of all players to all the questions so far (without this answer)
+    pub results: &'a QuestionRecords,
+}
+
+impl ScoringContext<'_> {
+    fn question(&self) -> anyhow::Result<&Question> {
+        self.questions
+            .get(self.question_index)
+            .ok_or(anyhow!("Question not found"))
+    }
+
+    /// Returns the number of correct and wrong choices the player selected.
+    /// A written answer counts as a single choice, every item of an ordering question
+    /// as one choice -- either if it is in the right position (partial credit),
+    /// or only if all of them are.
+    fn count_choices(&self) -> anyhow::Result<(usize, usize)> {
+        let question = self.question()?;
+
+        if question.kind == QuestionType::Ordering {
+            let Some(order) = self.order else {
+                return Ok((0, 0));
+            };
+
+            let correct = question.correct_positions(order);
+            debug!("Player {} has {correct} items in place", self.player);
+
+            if question.partial_credit || correct == question.choices.len() {
+                return Ok((correct, 0));
+            }
+            return Ok((0, 0));
+        }
+
+        if !question.kind.has_choices() {
+            return Ok(match self.written_answer {
+                Some(answer) if question.accepts(answer) => (1, 0),
+                Some(_) => (0, 1),
+                None => (0, 0),
+            });
+        }
+
+        let correct_answers = question.correct_choices();
+        debug!("Question has {} correct answers", correct_answers.len());
+
+        let num_correct = self.answers.intersection(&correct_answers).count();
+        let num_wrong = self.answers.len() - num_correct;
+        debug!(
+            "Player {} got {num_correct} correct and {num_wrong} wrong",
+            self.player
+        );
+
+        Ok((num_correct, num_wrong))
+    }
+}
+
+/// A way of turning an answer into points
+pub trait ScoringStrategy {
+    /// Computes the points awarded for the answer described by `context`,
+    /// which may be negative to take away points for a wrong answer
+    /// # Errors
+    /// If the answered question does not exist
+    fn points(&self, context: &ScoringContext) -> anyhow::Result<i64>;
+}
+
+/// The original scoring: 10 points for every correct choice, -5 for every wrong one,
+/// multiplied by a modifier which rewards players who answer before the others
+pub struct ClassicScoring;
+
+impl ScoringStrategy for ClassicScoring {
+    fn points(&self, context: &ScoringContext) -> anyhow::Result<i64> {
+        let (num_correct, num_wrong) = context.count_choices()?;
+
+        debug!(
+            "Total players: {}, answer order: {}",
+            context.total_players, context.answer_order
+        );
+
+        // Correct modifier
+        let modifier = context.total_players - context.answer_order + 10; // magic constant
+        debug!("Modifier: {modifier}");
+
+        Ok(i64::try_from(
+            modifier * usize::saturating_sub(num_correct * 10, num_wrong * 5),
+        )?)
+    }
+}
+
+/// Points shrink linearly with the time it took the player to answer -- an answer given
+/// right when the choices appear gets the full score, an answer given at the deadline half of it
+pub struct TimeDecayScoring;
+
+/// Maximal points for a single correct choice in [`TimeDecayScoring`]
+const TIME_DECAY_POINTS_PER_CHOICE: usize = 200;
+
+impl ScoringStrategy for TimeDecayScoring {
+    fn points(&self, context: &ScoringContext) -> anyhow::Result<i64> {
+        let question = context.question()?;
+        let (num_correct, num_wrong) = context.count_choices()?;
+
+        let base = usize::saturating_sub(
+            num_correct * TIME_DECAY_POINTS_PER_CHOICE,
+            num_wrong * TIME_DECAY_POINTS_PER_CHOICE / 2,
+        );
+
+        // the player cannot answer while reading the question, so do not count that time
+        let reading_ms = question.get_reading_time_estimate() * 1000;
+        let limit_ms = ((question.time_seconds + context.extra_seconds) * 1000).max(1);
+        let answering_ms = usize::try_from(context.elapsed.num_milliseconds())
+            .unwrap_or(0)
+            .saturating_sub(reading_ms)
+            .min(limit_ms);
+        debug!("Player {} answered after {answering_ms} ms", context.player);
+
+        // linearly from 100 % to 50 % of the base
+        Ok(i64::try_from(base - base * answering_ms / limit_ms / 2)?)
+    }
+}
+
+/// One point if exactly the correct choices were selected (or the written answer is right),
+/// nothing otherwise
+pub struct FlatScoring;
+
+impl ScoringStrategy for FlatScoring {
+    fn points(&self, context: &ScoringContext) -> anyhow::Result<i64> {
+        let question = context.question()?;
+
+        Ok(i64::from(question.is_answered_correctly(
+            context.answers,
+            context.written_answer,
+            context.order,
+        )))
+    }
+}
+
+/// Classic points with a bonus of 10 % for every previous question (up to 5)
+/// that the player answered fully correctly in a row
+pub struct StreakScoring;
+
+/// Maximal number of previous questions counted into the streak
+const MAX_STREAK: usize = 5;
+
+impl ScoringStrategy for StreakScoring {
+    fn points(&self, context: &ScoringContext) -> anyhow::Result<i64> {
+        let base = ClassicScoring.points(context)?;
+
+        let streak = (0..context.question_index)
+            .rev()
+            .take_while(|index| {
+                let Some(question) = context.questions.get(*index) else {
+                    return false;
+                };
+                context
+                    .results
+                    .get(index)
+                    .and_then(|records| records.get(&context.player))
+                    .is_some_and(|record| {
+                        question.is_answered_correctly(
+                            &record.selected_answers,
+                            record.written_answer.as_ref(),
+                            record.order.as_deref(),
+                        )
+                    })
+            })
+            .take(MAX_STREAK)
+            .count();
+        let streak = i64::try_from(streak)?;
+        debug!("Player {} has a streak of {streak}", context.player);
+
+        Ok(base + base * streak / 10)
+    }
+}
+
+/// 10 points for every correct choice, -10 for every wrong one.
+/// A wrong answer takes away the points gained with the previous questions
+/// (see [`total_points`]).
+pub struct NegativeMarkingScoring;
+
+impl ScoringStrategy for NegativeMarkingScoring {
+    fn points(&self, context: &ScoringContext) -> anyhow::Result<i64> {
+        let (num_correct, num_wrong) = context.count_choices()?;
+
+        Ok(i64::try_from(num_correct * 10)? - i64::try_from(num_wrong * 10)?)
+    }
+}
+
+/// Returns the strategy implementing the given scoring
+#[must_use]
+pub fn scoring_strategy(scoring: Scoring) -> &'static dyn ScoringStrategy {
+    match scoring {
+        Scoring::Classic => &ClassicScoring,
+        Scoring::TimeDecay => &TimeDecayScoring,
+        Scoring::Flat => &FlatScoring,
+        Scoring::Streak => &StreakScoring,
+        Scoring::NegativeMarking => &NegativeMarkingScoring,
+    }
+}
+
+/// Computes the points for an answer using the scoring selected for the quiz
+/// # Errors
+/// If the answered question does not exist
+pub fn calculate_points(context: &ScoringContext) -> anyhow::Result<i64> {
+    let final_points = scoring_strategy(context.questions.scoring).points(context)?;
     debug!("Final points: {final_points}");
 
     Ok(final_points)
 }
 
+/// Sums up the points a player got for the answers; the score shown to the players
+/// is never negative, so only the total is clamped at zero
+pub fn total_points(points: impl IntoIterator<Item = i64>) -> usize {
+    usize::try_from(points.into_iter().sum::<i64>()).unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::{HashMap, HashSet};
 
     use super::*;
+    use crate::lobby::state::PlayerQuestionRecord;
+    use chrono::Utc;
     use common::constants::DEFAULT_QUIZ_NAME;
     use common::questions::QuestionSet;
-    use common::questions::{Choice, Question};
+    use common::questions::{Choice, NumericAnswer, Question};
+
+    fn sample_questions(scoring: Scoring) -> QuestionSet {
+        let question = Question {
+            text: "What is the answer to life, the universe, and everything?".to_string(),
+            choices: vec![
+                Choice {
+                    id: Uuid::new_v4(),
+                    text: "42".to_string(),
+                    is_correct: true,
+                },
+                Choice {
+                    id: Uuid::new_v4(),
+                    text: "43".to_string(),
+                    is_correct: true,
+                },
+                Choice {
+                    id: Uuid::new_v4(),
+                    text: "44".to_string(),
+                    is_correct: false,
+                },
+                Choice {
+                    id: Uuid::new_v4(),
+                    text: "45".to_string(),
+                    is_correct: false,
+                },
+            ],
+            code_block: None,
+            time_seconds: 10,
+            is_multichoice: true,
+            ..Default::default()
+        };
+
+        QuestionSet {
+            questions: vec![question.clone(), question.clone(), question],
+            randomize_answers: false,
+            randomize_questions: false,
+            quiz_name: DEFAULT_QUIZ_NAME.to_owned(),
+            scoring,
+        }
+    }
+
+    fn choice_ids(questions: &QuestionSet, question: usize, choices: &[usize]) -> HashSet<Uuid> {
+        choices
+            .iter()
+            .map(|choice| questions[question].choices[*choice].id)
+            .collect()
+    }
+
+    fn context<'a>(
+        player: Uuid,
+        question_index: usize,
+        answers: &'a HashSet<Uuid>,
+        questions: &'a QuestionSet,
+        results: &'a QuestionRecords,
+    ) -> ScoringContext<'a> {
+        ScoringContext {
+            player,
+            answer_order: 1,
+            total_players: 4,
+            question_index,
+            answers,
+            written_answer: None,
+            order: None,
+            elapsed: Duration::zero(),
+            extra_seconds: 0,
+            questions,
+            results,
+        }
+    }
 
     #[test]
     fn test_calculate_points() -> anyhow::Result<()> {
         let player_id = Uuid::new_v4();
-        let answer_order = 1;
-        let total_players = 4;
-        let question_index = 0;
-
-        let choice_1 = Choice {
-            id: Uuid::new_v4(),
-            text: "42".to_string(),
-            is_correct: true,
-        };
+        let questions = sample_questions(Scoring::Classic);
+        let results = HashMap::new();
 
-        let choice_2 = Choice {
-            id: Uuid::new_v4(),
-            text: "43".to_string(),
-            is_correct: true,
-        };
+        let answers = choice_ids(&questions, 0, &[1, 0]);
+        let points = calculate_points(&context(player_id, 0, &answers, &questions, &results))?;
+        assert!(points > 200);
 
-        let choice_3 = Choice {
-            id: Uuid::new_v4(),
-            text: "44".to_string(),
-            is_correct: false,
-        };
+        let answers = choice_ids(&questions, 0, &[0, 2]);
+        let points = calculate_points(&context(player_id, 0, &answers, &questions, &results))?;
+        assert!(points > 0);
 
-        let choice_4 = Choice {
-            id: Uuid::new_v4(),
-            text: "45".to_string(),
-            is_correct: false,
-        };
+        let answers = choice_ids(&questions, 0, &[3, 2]);
+        let points = calculate_points(&context(player_id, 0, &answers, &questions, &results))?;
+        assert_eq!(points, 0);
 
-        let questions = QuestionSet {
-            questions: vec![Question {
-                text: "What is the answer to life, the universe, and everything?".to_string(),
-                choices: vec![
-                    choice_1.clone(),
-                    choice_2.clone(),
-                    choice_3.clone(),
-                    choice_4.clone(),
-                ],
-                code_block: None,
-                time_seconds: 10,
-                is_multichoice: true,
-            }],
-            randomize_answers: false,
-            randomize_questions: false,
-            quiz_name: DEFAULT_QUIZ_NAME.to_owned(),
-        };
+        Ok(())
+    }
 
-        let answers = AnswerSelected {
-            answers: HashSet::from([choice_2.id, choice_1.id]),
-            player_uuid: player_id,
-            question_index,
+    #[test]
+    fn test_calculate_points_unknown_question() {
+        let questions = sample_questions(Scoring::Classic);
+        let results = HashMap::new();
+        let answers = HashSet::new();
+
+        let points = calculate_points(&context(Uuid::new_v4(), 42, &answers, &questions, &results));
+        assert!(points.is_err());
+    }
+
+    #[test]
+    fn test_time_decay_scoring() -> anyhow::Result<()> {
+        let player_id = Uuid::new_v4();
+        let questions = sample_questions(Scoring::TimeDecay);
+        let results = HashMap::new();
+        let answers = choice_ids(&questions, 0, &[0, 1]);
+        let reading_time = questions[0].get_reading_time_estimate();
+
+        let mut ctx = context(player_id, 0, &answers, &questions, &results);
+        let mut points_at = |seconds: usize| {
+            ctx.elapsed = Duration::seconds(i64::try_from(reading_time + seconds).unwrap_or(0));
+            calculate_points(&ctx)
         };
 
+        let immediately = points_at(0)?;
+        let halfway = points_at(5)?;
+        let at_deadline = points_at(10)?;
+        let after_deadline = points_at(20)?;
+
+        assert_eq!(immediately, 400);
+        assert_eq!(halfway, 300);
+        assert_eq!(at_deadline, 200);
+        assert_eq!(after_deadline, 200);
+
+        let wrong = choice_ids(&questions, 0, &[2, 3]);
+        let points = calculate_points(&context(player_id, 0, &wrong, &questions, &results))?;
+        assert_eq!(points, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flat_scoring() -> anyhow::Result<()> {
+        let player_id = Uuid::new_v4();
+        let questions = sample_questions(Scoring::Flat);
         let results = HashMap::new();
 
-        let points = calculate_points(
-            player_id,
-            answer_order,
-            total_players,
-            question_index,
-            &answers,
-            &questions,
-            &results,
-        )?;
+        let answers = choice_ids(&questions, 0, &[0, 1]);
+        let points = calculate_points(&context(player_id, 0, &answers, &questions, &results))?;
+        assert_eq!(points, 1);
 
-        assert!(points > 200);
+        let answers = choice_ids(&questions, 0, &[0]);
+        let points = calculate_points(&context(player_id, 0, &answers, &questions, &results))?;
+        assert_eq!(points, 0);
 
-        let answers = AnswerSelected {
-            answers: HashSet::from([choice_1.id, choice_3.id]),
-            player_uuid: player_id,
-            question_index,
+        let answers = choice_ids(&questions, 0, &[0, 1, 2]);
+        let points = calculate_points(&context(player_id, 0, &answers, &questions, &results))?;
+        assert_eq!(points, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_streak_scoring() -> anyhow::Result<()> {
+        let player_id = Uuid::new_v4();
+        let questions = sample_questions(Scoring::Streak);
+        let answers = choice_ids(&questions, 2, &[0, 1]);
+
+        let record = |question: usize, choices: &[usize]| {
+            HashMap::from([(
+                player_id,
+                PlayerQuestionRecord {
+                    answer_order: 1,
+                    timestamp: Utc::now(),
+                    selected_answers: choice_ids(&questions, question, choices),
+                    written_answer: None,
+                    order: None,
+                    points_awarded: 0,
+                },
+            )])
         };
 
-        let points = calculate_points(
+        let classic = ClassicScoring.points(&context(
             player_id,
-            answer_order,
-            total_players,
-            question_index,
+            2,
             &answers,
             &questions,
-            &results,
-        )?;
+            &HashMap::new(),
+        ))?;
 
-        assert!(points > 0);
+        // no previous answers, no bonus
+        let results = HashMap::new();
+        let points = calculate_points(&context(player_id, 2, &answers, &questions, &results))?;
+        assert_eq!(points, classic);
 
-        let answers = AnswerSelected {
-            answers: HashSet::from([choice_4.id, choice_3.id]),
-            player_uuid: player_id,
-            question_index,
+        // two fully correct answers in a row
+        let results = HashMap::from([(0, record(0, &[0, 1])), (1, record(1, &[1, 0]))]);
+        let points = calculate_points(&context(player_id, 2, &answers, &questions, &results))?;
+        assert_eq!(points, classic + classic * 2 / 10);
+
+        // the streak is broken by the last answer
+        let results = HashMap::from([(0, record(0, &[0, 1])), (1, record(1, &[0]))]);
+        let points = calculate_points(&context(player_id, 2, &answers, &questions, &results))?;
+        assert_eq!(points, classic);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_negative_marking_scoring() -> anyhow::Result<()> {
+        let player_id = Uuid::new_v4();
+        let questions = sample_questions(Scoring::NegativeMarking);
+        let results = HashMap::new();
+
+        let answers = choice_ids(&questions, 0, &[0, 1]);
+        let points = calculate_points(&context(player_id, 0, &answers, &questions, &results))?;
+        assert_eq!(points, 20);
+
+        let answers = choice_ids(&questions, 0, &[0, 1, 2]);
+        let points = calculate_points(&context(player_id, 0, &answers, &questions, &results))?;
+        assert_eq!(points, 10);
+
+        let answers = choice_ids(&questions, 0, &[0, 2, 3]);
+        let points = calculate_points(&context(player_id, 0, &answers, &questions, &results))?;
+        assert_eq!(points, -10);
+
+        // a wrong answer lowers the score gained with the previous ones
+        assert_eq!(total_points([20, 10]), 30);
+        assert_eq!(total_points([20, 10, -10]), 20);
+
+        // but the total is never negative
+        assert_eq!(total_points([10, -20]), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_written_answers() -> anyhow::Result<()> {
+        let player_id = Uuid::new_v4();
+        let mut questions = sample_questions(Scoring::Flat);
+        questions.questions = vec![
+            Question {
+                kind: QuestionType::Text,
+                text: "What is the capital of the Czech Republic?".to_string(),
+                time_seconds: 10,
+                accepted_answers: vec!["Prague".to_string(), "Praha".to_string()],
+                ..Default::default()
+            },
+            Question {
+                kind: QuestionType::Numeric,
+                text: "What is the speed of sound in the air in m/s?".to_string(),
+                time_seconds: 10,
+                numeric_answer: Some(NumericAnswer {
+                    value: 343.0,
+                    tolerance: 5.0,
+                }),
+                ..Default::default()
+            },
+        ];
+        let results = HashMap::new();
+        let no_choices = HashSet::new();
+
+        let points_for = |question_index: usize, answer: WrittenAnswer| {
+            let mut ctx = context(player_id, question_index, &no_choices, &questions, &results);
+            ctx.written_answer = Some(&answer);
+            calculate_points(&ctx)
         };
 
-        let points = calculate_points(
-            player_id,
-            answer_order,
-            total_players,
-            question_index,
-            &answers,
-            &questions,
-            &results,
-        )?;
+        assert_eq!(
+            points_for(0, WrittenAnswer::Text("  praGUE ".to_string()))?,
+            1
+        );
+        assert_eq!(points_for(0, WrittenAnswer::Text("Praha".to_string()))?, 1);
+        assert_eq!(points_for(0, WrittenAnswer::Text("Brno".to_string()))?, 0);
+        assert_eq!(points_for(1, WrittenAnswer::Number(340.5))?, 1);
+        assert_eq!(points_for(1, WrittenAnswer::Number(350.0))?, 0);
+        assert_eq!(points_for(1, WrittenAnswer::Text("343".to_string()))?, 0);
 
+        // no answer, no points
+        let points = calculate_points(&context(player_id, 0, &no_choices, &questions, &results))?;
         assert_eq!(points, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ordering_scoring() -> anyhow::Result<()> {
+        let player_id = Uuid::new_v4();
+        let mut questions = sample_questions(Scoring::Classic);
+        for question in questions.iter_mut() {
+            question.kind = QuestionType::Ordering;
+            question.is_multichoice = false;
+            for choice in &mut question.choices {
+                choice.is_correct = false;
+            }
+        }
+        questions[1].partial_credit = true;
+
+        let results = HashMap::new();
+        let no_choices = HashSet::new();
+        let ids: Vec<_> = questions[0]
+            .choices
+            .iter()
+            .map(|choice| choice.id)
+            .collect();
+        let right_order = ids.clone();
+        let two_swapped = vec![ids[1], ids[0], ids[2], ids[3]];
+
+        let points_for = |question_index: usize, order: &[Uuid]| {
+            let mut ctx = context(player_id, question_index, &no_choices, &questions, &results);
+            ctx.order = Some(order);
+            calculate_points(&ctx)
+        };
+
+        // all or nothing
+        assert!(points_for(0, &right_order)? > 0);
+        assert_eq!(points_for(0, &two_swapped)?, 0);
+
+        // partial credit for the two items in the right position
+        let full = points_for(1, &right_order)?;
+        let partial = points_for(1, &two_swapped)?;
+        assert_eq!(partial * 2, full);
+
+        // the flat scoring only counts fully correct orders
+        questions.scoring = Scoring::Flat;
+        let mut ctx = context(player_id, 1, &no_choices, &questions, &results);
+        ctx.order = Some(&two_swapped);
+        assert_eq!(calculate_points(&ctx)?, 0);
+        ctx.order = Some(&right_order);
+        assert_eq!(calculate_points(&ctx)?, 1);
+
         Ok(())
     }
 }
@@ -0,0 +1,266 @@
+use std::{fs::File, path::Path};
+
+use anyhow::Context;
+use clap::ValueEnum;
+use common::messages::network::{AnswerDetails, PlayerAnswerDetail, PlayerData};
+use itertools::Itertools;
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::{point_calculator::total_points, Lobby, Phase};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ResultsFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+/// How a single player did in a single question
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AnswerReport {
+    pub question_index: usize,
+    pub question: String,
+    pub chosen_answers: Vec<String>,
+    pub correct: bool,
+    pub voided: bool, // the teacher voided the question, so it gives no points
+    pub answer_order: Option<usize>, // None if the player did not answer
+    pub latency_ms: Option<i64>, // time from the start of the question
+    pub points: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlayerReport {
+    pub rank: usize,
+    pub nickname: String,
+    pub uuid: Uuid,
+    pub joined_late: bool, // the questions before the player joined are graded with zero points
+    pub total_points: usize,
+    pub answers: Vec<AnswerReport>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GameReport {
+    pub quiz_name: String,
+    pub game_ended: bool,
+    pub players: Vec<PlayerReport>,
+}
+
+/// One line of the CSV report
+#[derive(Debug, Serialize)]
+struct ReportRow<'a> {
+    rank: usize,
+    nickname: &'a str,
+    uuid: Uuid,
+    joined_late: bool,
+    question_index: usize,
+    question: &'a str,
+    chosen_answers: String,
+    correct: bool,
+    voided: bool,
+    answer_order: Option<usize>,
+    latency_ms: Option<i64>,
+    points: i64,
+    total_points: usize,
+}
+
+impl Lobby {
+    pub(super) fn get_answer_report(&self, index: usize, player_id: &Uuid) -> AnswerReport {
+        let question = &self.questions[index];
+        let voided = self.voided_questions.contains(&index);
+        let record = self
+            .results
+            .get(&index)
+            .and_then(|results| results.get(player_id));
+
+        let Some(record) = record else {
+            return AnswerReport {
+                question_index: index,
+                question: question.text.clone(),
+                chosen_answers: vec![],
+                correct: false,
+                voided,
+                answer_order: None,
+                latency_ms: None,
+                points: 0,
+            };
+        };
+
+        AnswerReport {
+            question_index: index,
+            question: question.text.clone(),
+            chosen_answers: question
+                .choices
+                .iter()
+                .filter(|choice| record.selected_answers.contains(&choice.id))
+                .map(|choice| choice.text.clone())
+                .chain(record.written_answer.iter().map(ToString::to_string))
+                .chain(record.order.iter().flatten().filter_map(|id| {
+                    question
+                        .choices
+                        .iter()
+                        .find(|choice| choice.id == *id)
+                        .map(|choice| choice.text.clone())
+                }))
+                .collect(),
+            correct: question.is_answered_correctly(
+                &record.selected_answers,
+                record.written_answer.as_ref(),
+                record.order.as_deref(),
+            ),
+            voided,
+            answer_order: Some(record.answer_order),
+            latency_ms: self
+                .question_start_times
+                .get(&index)
+                .map(|started| (record.timestamp - *started).num_milliseconds()),
+            points: record.points_awarded,
+        }
+    }
+
+    /// Collects what each of the players who took part in the game answered to the question with `index`
+    #[must_use]
+    pub fn get_answer_details(&self, index: usize) -> AnswerDetails {
+        // players who dropped out or were kicked are listed as well
+        let players = self
+            .roster
+            .values()
+            .map(|player| {
+                let answer = self.get_answer_report(index, &player.uuid);
+
+                PlayerAnswerDetail {
+                    player: PlayerData {
+                        uuid: player.uuid,
+                        nickname: player.nickname.clone(),
+                        color: player.color,
+                    },
+                    chosen_answers: answer.chosen_answers,
+                    correct: answer.correct,
+                    answer_order: answer.answer_order,
+                    response_time_ms: answer.latency_ms,
+                    points: answer.points,
+                }
+            })
+            // the players who did not answer come last
+            .sorted_by(|a, b| {
+                (a.answer_order.is_none(), a.answer_order, &a.player.nickname).cmp(&(
+                    b.answer_order.is_none(),
+                    b.answer_order,
+                    &b.player.nickname,
+                ))
+            })
+            .collect();
+
+        AnswerDetails {
+            question_index: index,
+            players,
+        }
+    }
+
+    /// Collects the results of all players who took part in the game so far
+    #[must_use]
+    pub fn get_report(&self) -> GameReport {
+        // the teacher may have skipped some of the questions, or gone back to an earlier one
+        let asked = (0..self.questions.len())
+            .filter(|index| self.is_asked(*index))
+            .collect_vec();
+
+        // players who dropped out or were kicked are graded as well
+        let players = self
+            .roster
+            .values()
+            .sorted_by_key(|player| player.joined_at)
+            .map(|player| {
+                let answers: Vec<_> = asked
+                    .iter()
+                    .map(|index| self.get_answer_report(*index, &player.uuid))
+                    .collect();
+
+                PlayerReport {
+                    rank: 0, // filled in below
+                    nickname: player.nickname.clone(),
+                    uuid: player.uuid,
+                    joined_late: player.joined_late,
+                    total_points: total_points(answers.iter().map(|answer| answer.points)),
+                    answers,
+                }
+            })
+            .sorted_by_key(|player| std::cmp::Reverse(player.total_points))
+            .collect_vec();
+
+        // players with the same score share the rank
+        let totals = players
+            .iter()
+            .map(|player| player.total_points)
+            .collect_vec();
+        let players = players
+            .into_iter()
+            .map(|player| PlayerReport {
+                rank: totals
+                    .iter()
+                    .position(|total| *total == player.total_points)
+                    .unwrap_or(0)
+                    + 1,
+                ..player
+            })
+            .collect();
+
+        GameReport {
+            quiz_name: self.questions.quiz_name.clone(),
+            game_ended: self.phase == Phase::GameEnded,
+            players,
+        }
+    }
+
+    /// Writes the report of the game into `path` in the given `format`
+    /// # Errors
+    /// - when the file cannot be created or written to
+    pub fn write_report(&self, path: &Path, format: ResultsFormat) -> anyhow::Result<()> {
+        let report = self.get_report();
+        let file = File::create(path)
+            .context(format!("Cannot create results file \"{}\"", path.display()))?;
+
+        match format {
+            ResultsFormat::Json => serde_json::to_writer_pretty(file, &report)?,
+            ResultsFormat::Csv => {
+                let mut writer = csv::Writer::from_writer(file);
+
+                for player in &report.players {
+                    for answer in &player.answers {
+                        writer.serialize(ReportRow {
+                            rank: player.rank,
+                            nickname: &player.nickname,
+                            uuid: player.uuid,
+                            joined_late: player.joined_late,
+                            question_index: answer.question_index,
+                            question: &answer.question,
+                            chosen_answers: answer.chosen_answers.join("; "),
+                            correct: answer.correct,
+                            voided: answer.voided,
+                            answer_order: answer.answer_order,
+                            latency_ms: answer.latency_ms,
+                            points: answer.points,
+                            total_points: player.total_points,
+                        })?;
+                    }
+                }
+
+                writer.flush()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the results report, if the teacher asked for one
+    pub fn save_results(&self) {
+        let Some(path) = &self.results_file else {
+            return;
+        };
+
+        match self.write_report(path, self.results_format) {
+            Ok(()) => log::info!("Results written to \"{}\"", path.display()),
+            Err(e) => log::error!("Failed to write results: {e:#}"),
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+};
+
+use actix::{Context, Recipient};
+use anyhow::Context as _;
+use chrono::{DateTime, Utc};
+use common::{
+    messages::{
+        network::{LockUpdate, PauseUpdate, PlayersUpdate},
+        ServerNetworkMessage,
+    },
+    questions::QuestionSet,
+};
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+
+use super::{DisconnectedPlayer, Lobby, Phase, QuestionRecords, RosterEntry};
+
+/// Everything needed to continue the game after the server restarts
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LobbySnapshot {
+    pub phase: Phase,
+    pub questions: QuestionSet, // in the already shuffled order
+    pub results: QuestionRecords,
+    #[serde(default)]
+    pub reopened_results: QuestionRecords,
+    #[serde(default)]
+    pub voided_questions: HashSet<usize>,
+    pub players: Vec<DisconnectedPlayer>,
+    #[serde(default)]
+    pub roster: Vec<RosterEntry>, // also the players who left the game
+    pub question_start_times: HashMap<usize, DateTime<Utc>>,
+    #[serde(default)]
+    pub paused_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub extra_time: HashMap<usize, usize>,
+}
+
+impl Lobby {
+    /// Restores the lobby from the snapshot in `path`. All players have to reconnect.
+    /// # Errors
+    /// - when the file cannot be read or parsed
+    pub fn from_snapshot(path: &Path) -> anyhow::Result<Self> {
+        let content = fs::read_to_string(path)
+            .context(format!("Cannot read snapshot file \"{}\"", path.display()))?;
+        let snapshot: LobbySnapshot = serde_json::from_str(&content)
+            .context(format!("Invalid snapshot file \"{}\"", path.display()))?;
+
+        let mut lobby = Lobby::new(snapshot.questions); // randomization flags are not stored
+        lobby.phase = snapshot.phase;
+        lobby.results = snapshot.results;
+        lobby.reopened_results = snapshot.reopened_results;
+        lobby.voided_questions = snapshot.voided_questions;
+        lobby.question_start_times = snapshot.question_start_times;
+        lobby.paused_at = snapshot.paused_at;
+        lobby.extra_time = snapshot.extra_time;
+
+        // snapshots without the roster know only the players who may still reconnect
+        lobby.roster = snapshot
+            .roster
+            .into_iter()
+            .chain(snapshot.players.iter().map(RosterEntry::from))
+            .map(|player| (player.uuid, player))
+            .collect();
+
+        // the grace period for reconnecting starts now
+        let now = Utc::now();
+        lobby.disconnected_players = snapshot
+            .players
+            .into_iter()
+            .map(|player| {
+                (
+                    player.uuid,
+                    DisconnectedPlayer {
+                        disconnected_at: now,
+                        ..player
+                    },
+                )
+            })
+            .collect();
+
+        Ok(lobby)
+    }
+
+    fn get_lobby_snapshot(&self) -> LobbySnapshot {
+        let now = Utc::now();
+
+        LobbySnapshot {
+            phase: self.phase.clone(),
+            questions: self.questions.clone(),
+            results: self.results.clone(),
+            reopened_results: self.reopened_results.clone(),
+            voided_questions: self.voided_questions.clone(),
+            players: self
+                .joined_players
+                .values()
+                .map(|player| player.clone().disconnect(now))
+                .chain(self.disconnected_players.values().cloned())
+                .collect(),
+            roster: self.roster.values().cloned().collect(),
+            question_start_times: self.question_start_times.clone(),
+            paused_at: self.paused_at,
+            extra_time: self.extra_time.clone(),
+        }
+    }
+
+    /// Writes the snapshot of the lobby into `path`
+    /// # Errors
+    /// - when the file cannot be written to
+    pub fn write_snapshot(&self, path: &Path) -> anyhow::Result<()> {
+        let content = serde_json::to_string(&self.get_lobby_snapshot())?;
+
+        // write to a temporary file first, so that a crash never leaves a half-written snapshot
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, content).context(format!(
+            "Cannot write snapshot file \"{}\"",
+            tmp_path.display()
+        ))?;
+        fs::rename(&tmp_path, path)
+            .context(format!("Cannot write snapshot file \"{}\"", path.display()))?;
+
+        Ok(())
+    }
+
+    /// Writes the snapshot of the lobby, if the server was started with a snapshot file
+    pub fn save_snapshot(&self) {
+        let Some(path) = &self.snapshot_file else {
+            return;
+        };
+
+        if let Err(e) = self.write_snapshot(path) {
+            error!("Failed to write snapshot: {e:#}");
+        }
+    }
+
+    /// Restarts the timers which were running when the snapshot was taken
+    pub(super) fn resume_timers(&mut self, ctx: &mut Context<Self>) {
+        for player in self.disconnected_players.values() {
+            self.forget_player_later(ctx, player);
+        }
+
+        let Phase::ActiveQuestion(index) = self.phase else {
+            return;
+        };
+
+        // a paused question waits for the teacher to resume it
+        if self.paused_at.is_some() {
+            info!("Question {index} stays paused");
+            return;
+        }
+
+        let elapsed = self.get_elapsed_seconds(index);
+        info!("Resuming question {index}, {elapsed} seconds elapsed");
+        self.start_question_timer(ctx, index);
+    }
+
+    /// Shows a teacher who has just come the current state of the game (which may be a resumed one)
+    pub(super) fn send_state_to_teacher(
+        &self,
+        teacher: &Recipient<ServerNetworkMessage>,
+    ) -> anyhow::Result<()> {
+        teacher.do_send(ServerNetworkMessage::PlayersUpdate(PlayersUpdate {
+            players: self.get_players(),
+            late_joiners: self.get_late_joiners(),
+        }));
+        teacher.do_send(ServerNetworkMessage::LockUpdate(LockUpdate {
+            locked: self.locked,
+        }));
+        teacher.do_send(ServerNetworkMessage::QuestionList(self.get_question_list()));
+
+        match self.phase {
+            Phase::WaitingForPlayers => {}
+            Phase::ActiveQuestion(index) => {
+                teacher.do_send(ServerNetworkMessage::NextQuestion(
+                    self.get_next_question(index),
+                ));
+                // the question may have been running (or paused) for a while
+                teacher.do_send(ServerNetworkMessage::PauseUpdate(PauseUpdate {
+                    question_index: index,
+                    paused: self.paused_at.is_some(),
+                    elapsed_seconds: self.get_elapsed_seconds(index),
+                    deadline: self.get_deadline(index),
+                }));
+                teacher.do_send(ServerNetworkMessage::AnswerDistribution(
+                    self.get_answer_distribution(index)?,
+                ));
+            }
+            Phase::AfterQuestion(index) => {
+                // the teacher only accepts the answers after seeing the question
+                teacher.do_send(ServerNetworkMessage::NextQuestion(
+                    self.get_next_question(index),
+                ));
+                teacher.do_send(ServerNetworkMessage::QuestionEnded(
+                    self.get_question_ended(index, None)?,
+                ));
+                teacher.do_send(ServerNetworkMessage::AnswerDetails(
+                    self.get_answer_details(index),
+                ));
+            }
+            Phase::ShowingLeaderboard(index) => {
+                teacher.do_send(ServerNetworkMessage::ShowLeaderboard(
+                    self.get_leaderboard(index),
+                ));
+            }
+            Phase::GameEnded => {
+                teacher.do_send(ServerNetworkMessage::ShowLeaderboard(
+                    self.get_leaderboard(self.questions.len() - 1),
+                ));
+                teacher.do_send(ServerNetworkMessage::GameAnalytics(
+                    self.get_game_analytics(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,22 +1,29 @@
+use crate::lobby::ResultsFormat;
 use crate::websocket::Websocket;
 use crate::Teacher;
 use actix::Actor;
 use actix::Addr;
+use actix::SpawnHandle;
 
 use actix::dev::MessageResponse;
 use actix::dev::OneshotSender;
 use actix::Message;
 use chrono::DateTime;
 use chrono::Utc;
+use common::messages::network::WrittenAnswer;
 use common::questions::QuestionSet;
 use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
 
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::net::IpAddr;
 use std::ops::Deref;
+use std::path::PathBuf;
+use std::time::Duration;
 use uuid::Uuid;
 
-#[derive(Default, PartialEq, Clone, Debug)]
+#[derive(Default, PartialEq, Clone, Debug, Serialize, Deserialize)]
 
 pub enum Phase {
     #[default]
@@ -27,12 +34,16 @@ pub enum Phase {
     GameEnded,
 }
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 pub struct PlayerQuestionRecord {
     pub answer_order: usize,
     pub timestamp: DateTime<Utc>,
     pub selected_answers: HashSet<Uuid>,
-    pub points_awarded: usize,
+    #[serde(default)]
+    pub written_answer: Option<WrittenAnswer>,
+    #[serde(default)]
+    pub order: Option<Vec<Uuid>>,
+    pub points_awarded: i64, // negative if the scoring takes away points for a wrong answer
 }
 
 /// Uuid of the player -> record of a single question
@@ -48,6 +59,8 @@ pub struct JoinedPlayer {
     pub color: Color,
     pub addr: Addr<Websocket>,
     pub joined_at: DateTime<Utc>,
+    pub joined_late: bool, // after the game started, so the questions before count as zero
+    pub reconnect_secret: String,
 }
 
 impl Deref for JoinedPlayer {
@@ -58,29 +71,169 @@ impl Deref for JoinedPlayer {
     }
 }
 
+impl JoinedPlayer {
+    #[must_use]
+    pub fn disconnect(self, disconnected_at: DateTime<Utc>) -> DisconnectedPlayer {
+        DisconnectedPlayer {
+            uuid: self.uuid,
+            nickname: self.nickname,
+            color: self.color,
+            joined_at: self.joined_at,
+            joined_late: self.joined_late,
+            reconnect_secret: self.reconnect_secret,
+            disconnected_at,
+        }
+    }
+}
+
+/// A player without a websocket, who may still reconnect
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct DisconnectedPlayer {
+    pub uuid: Uuid,
+    pub nickname: String,
+    pub color: Color,
+    pub joined_at: DateTime<Utc>,
+    #[serde(default)]
+    pub joined_late: bool,
+    #[serde(default)]
+    pub reconnect_secret: String, // players from snapshots without it cannot reconnect
+    pub disconnected_at: DateTime<Utc>,
+}
+
+impl DisconnectedPlayer {
+    #[must_use]
+    pub fn reconnect(self, addr: Addr<Websocket>) -> JoinedPlayer {
+        JoinedPlayer {
+            uuid: self.uuid,
+            nickname: self.nickname,
+            color: self.color,
+            addr,
+            joined_at: self.joined_at,
+            joined_late: self.joined_late,
+            reconnect_secret: self.reconnect_secret,
+        }
+    }
+}
+
+/// A player who joined the game at some point; kept after they are kicked or do not come back,
+/// so that their answers still make it into the results
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct RosterEntry {
+    pub uuid: Uuid,
+    pub nickname: String,
+    pub color: Color,
+    pub joined_at: DateTime<Utc>,
+    pub joined_late: bool,
+}
+
+impl From<&JoinedPlayer> for RosterEntry {
+    fn from(player: &JoinedPlayer) -> Self {
+        Self {
+            uuid: player.uuid,
+            nickname: player.nickname.clone(),
+            color: player.color,
+            joined_at: player.joined_at,
+            joined_late: player.joined_late,
+        }
+    }
+}
+
+impl From<&DisconnectedPlayer> for RosterEntry {
+    fn from(player: &DisconnectedPlayer) -> Self {
+        Self {
+            uuid: player.uuid,
+            nickname: player.nickname.clone(),
+            color: player.color,
+            joined_at: player.joined_at,
+            joined_late: player.joined_late,
+        }
+    }
+}
+
+/// Wrong PINs or secrets recently sent from one address
+#[derive(PartialEq, Clone, Debug)]
+pub struct FailedAttempts {
+    pub count: u32,
+    pub last_failure: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone)]
 pub struct Lobby {
     /// An address to the teacher actor
     pub teacher: Option<Addr<Teacher>>,
 
+    /// Connections of the teachers controlling the game remotely
+    pub remote_teachers: Vec<Addr<Websocket>>,
+
+    /// Secret the remote teachers log in with
+    pub teacher_token: String,
+
     /// Phase of the game  
     pub phase: Phase,
 
     /// Whether new players can join
     pub locked: bool,
 
+    /// Whether to lock the lobby when the first question starts
+    pub lock_on_start: bool,
+
+    /// PIN the players have to enter to join, if the game is protected by one
+    pub pin: Option<String>,
+
+    /// Wrong PINs, reconnect secrets and teacher tokens by the address they came from;
+    /// keyed by the IP, as every connection comes from a new port
+    pub failed_attempts: HashMap<IpAddr, FailedAttempts>,
+
     /// References to all the connected clients
     pub joined_players: HashMap<Uuid, JoinedPlayer>,
 
+    /// Every player who has ever joined the game
+    pub roster: HashMap<Uuid, RosterEntry>,
+
     /// Incremental results of the game
     /// * `results[question_index][player_uuid] = PlayerQuestionRecord`
     pub results: QuestionRecords,
 
+    /// Answers from before the questions were re-opened; the players who do not answer again keep them
+    pub reopened_results: QuestionRecords,
+
+    /// Questions which the teacher voided, so that nobody gets points for them
+    pub voided_questions: HashSet<usize>,
+
     /// All questions to be asked
     pub questions: QuestionSet,
 
     /// Players who have sent a TryJoinRequest, but have not joined yet
     pub waiting_players: HashSet<Uuid>,
+
+    /// Players who lost their connection and are within the reconnect grace period
+    pub disconnected_players: HashMap<Uuid, DisconnectedPlayer>,
+
+    /// When each of the questions was sent out; moved forward by the length of every pause
+    /// * `question_start_times[question_index] = timestamp`
+    pub question_start_times: HashMap<usize, DateTime<Utc>>,
+
+    /// Ends the active question when its time is up; cancelled when it ends sooner or is paused
+    pub question_timer: Option<SpawnHandle>,
+
+    /// When the active question was paused, if it is
+    pub paused_at: Option<DateTime<Utc>>,
+
+    /// Seconds the teacher added to the questions while they were running; the quiz itself is left as it is
+    /// * `extra_time[question_index] = seconds`
+    pub extra_time: HashMap<usize, usize>,
+
+    /// How long after the deadline the answers are still accepted, as they take a while to arrive
+    pub answer_grace: Duration,
+
+    /// Where to write the results report when the game ends
+    pub results_file: Option<PathBuf>,
+
+    /// Format of the results report
+    pub results_format: ResultsFormat,
+
+    /// Where to write the snapshot of the game after every phase change
+    pub snapshot_file: Option<PathBuf>,
 }
 
 impl<A, M> MessageResponse<A, M> for Lobby
@@ -1,12 +1,19 @@
 use std::{
     sync::mpsc::{self},
     thread,
+    time::Duration,
 };
 
 use anyhow::bail;
 use clap::Parser;
-use common::questions::QuestionSet;
-use server::{lobby::run_server, teacher::run_teacher, Args};
+use common::{
+    question_bank::{QuestionBank, Recipe},
+    questions::QuestionSet,
+};
+use server::{
+    connection::load_tls_acceptor, lobby::run_server, messages::lobby, teacher::run_teacher, Args,
+    Lobby,
+};
 
 use simplelog::{
     ColorChoice, CombinedLogger, Config, LevelFilter, TermLogger, TerminalMode, WriteLogger,
@@ -21,7 +28,7 @@ fn main() -> anyhow::Result<()> {
         WriteLogger::new(
             LevelFilter::Debug,
             Config::default(),
-            File::create(args.log_file)?,
+            File::create(&args.log_file)?,
         ),
         TermLogger::new(
             LevelFilter::Error,
@@ -31,28 +38,71 @@ fn main() -> anyhow::Result<()> {
         ),
     ])?;
 
-    let path = std::path::Path::new(&args.questions_file);
-    let mut questions = QuestionSet::from_file(path)?;
+    let mut lobby = if let Some(snapshot) = &args.resume {
+        Lobby::from_snapshot(snapshot)?
+    } else {
+        let mut questions = if let (Some(bank), Some(recipe)) = (&args.question_bank, &args.recipe)
+        {
+            QuestionBank::from_directory(bank)?.sample(&Recipe::from_file(recipe)?)?
+        } else {
+            QuestionSet::from_file(&args.questions_file()?)?
+        };
 
-    questions.randomize_answers = args.randomize_answers;
-    questions.randomize_questions = args.randomize_questions;
+        questions.randomize_answers = args.randomize_answers;
+        questions.randomize_questions = args.randomize_questions;
+        if let Some(scoring) = args.scoring {
+            questions.scoring = scoring;
+        }
+
+        Lobby::new(questions)
+    };
+    lobby.results_file = args.results_file;
+    lobby.results_format = args.results_format;
+    lobby.snapshot_file = args.snapshot_file;
+    lobby.lock_on_start = args.lock_on_start;
+    lobby.answer_grace = Duration::from_millis(args.answer_grace_ms);
+    lobby.pin = args.pin.map(|pin| pin.unwrap_or_else(Lobby::generate_pin));
+    if let Some(token) = args.teacher_token {
+        lobby.teacher_token = token;
+    }
+
+    // the terminal of the teacher takes over the screen, so this is seen after it quits (or right away when headless)
+    println!("Remote teacher token: {}", lobby.teacher_token);
+    if let (true, Some(pin)) = (args.headless, &lobby.pin) {
+        println!("PIN of the game: {pin}");
+    }
 
     // construct address on which the server will listen
     let addr = format!("0.0.0.0:{}", args.port).parse()?;
 
+    let tls = match (&args.tls_cert, &args.tls_key) {
+        (Some(cert), Some(key)) => Some(load_tls_acceptor(cert, key)?),
+        _ => None,
+    };
+
     // create oneshot channel, so that spawned server can send us its address
     let (tx_server, rx_server) = mpsc::channel();
     let (tx_teacher, _rx_teacher) = mpsc::channel();
 
-    let quiz_name = questions.quiz_name.clone();
+    let quiz_name = lobby.questions.quiz_name.clone();
+    let pin = lobby.pin.clone();
+    let discoverable = !args.no_discovery;
 
     let server_thread = thread::spawn(move || {
-        run_server(tx_server, questions, addr).expect("Failed to run server");
+        run_server(tx_server, lobby, addr, tls, discoverable).expect("Failed to run server");
     });
 
     let teacher_thread = thread::spawn(move || {
         let server = rx_server.recv().expect("Failed to receive server address");
-        run_teacher(server, tx_teacher, &quiz_name, args.syntax_theme)
+
+        if args.headless {
+            // without the teacher's terminal, the server is stopped with Ctrl+C
+            wait_for_ctrl_c().expect("Failed to wait for Ctrl+C");
+            server.do_send(lobby::HardStop);
+            return;
+        }
+
+        run_teacher(server, tx_teacher, &quiz_name, pin, args.syntax_theme)
             .expect("Failed to run teacher");
     });
 
@@ -67,3 +117,12 @@ fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+fn wait_for_ctrl_c() -> anyhow::Result<()> {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()?
+        .block_on(tokio::signal::ctrl_c())?;
+
+    Ok(())
+}
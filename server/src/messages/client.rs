@@ -1,11 +1,38 @@
+use std::net::SocketAddr;
+
 use actix::{Addr, Message};
-use common::messages::network::PlayerData;
+use common::messages::network::{self, PlayerData};
+use uuid::Uuid;
 
 use crate::websocket::Websocket;
 
+#[derive(Debug, Message)]
+#[rtype(result = "common::messages::network::TryJoinResponse")]
+pub struct TryJoinRequest {
+    pub request: network::TryJoinRequest,
+    pub who: SocketAddr, // wrong PINs are counted per address
+}
+
 #[derive(Debug, Message)]
 #[rtype(result = "common::messages::network::JoinResponse")]
 pub struct JoinRequest {
     pub player_data: PlayerData,
     pub addr: Addr<Websocket>,
 }
+
+#[derive(Debug, Message)]
+#[rtype(result = "common::messages::network::ReconnectResponse")]
+pub struct ReconnectRequest {
+    pub player_uuid: Uuid,
+    pub secret: String,
+    pub addr: Addr<Websocket>,
+    pub who: SocketAddr, // wrong secrets are counted per address
+}
+
+#[derive(Debug, Message)]
+#[rtype(result = "common::messages::network::TeacherLoginResponse")]
+pub struct TeacherLoginRequest {
+    pub request: network::TeacherLoginRequest,
+    pub addr: Addr<Websocket>, // receives the updates of the game once the teacher is logged in
+    pub who: SocketAddr,       // wrong tokens are counted per address
+}
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use actix::{prelude::Message, Addr};
 use uuid::Uuid;
 
@@ -41,3 +43,60 @@ pub struct KickPlayer {
 #[derive(Debug, Clone, Message)]
 #[rtype(result = "anyhow::Result<()>")]
 pub struct SwitchToLeaderboard;
+
+/// Stops the timer of the active question until it is resumed
+#[derive(Debug, Clone, Message)]
+#[rtype(result = "anyhow::Result<()>")]
+pub struct PauseQuestion;
+
+/// Restarts the timer of the paused question with the time it had left
+#[derive(Debug, Clone, Message)]
+#[rtype(result = "anyhow::Result<()>")]
+pub struct ResumeQuestion;
+
+/// Asks a question which was already asked once more. Unless its results are discarded,
+/// the players who do not answer it again keep their previous answers.
+#[derive(Debug, Clone, Message)]
+#[rtype(result = "anyhow::Result<()>")]
+pub struct ReopenQuestion {
+    pub index: usize,
+    pub discard_results: bool,
+}
+
+/// Starts the question with `index` instead of the next one; a question already asked is re-opened
+#[derive(Debug, Clone, Message)]
+#[rtype(result = "anyhow::Result<()>")]
+pub struct JumpToQuestion {
+    pub index: usize,
+}
+
+/// Takes away the points of everyone for the question with `index`, which has just ended
+#[derive(Debug, Clone, Message)]
+#[rtype(result = "anyhow::Result<()>")]
+pub struct VoidQuestion {
+    pub index: usize,
+}
+
+/// Marks `correct_choices` as the correct ones of the question with `index`, which has just ended,
+/// and grades the answers to it again
+#[derive(Debug, Clone, Message)]
+#[rtype(result = "anyhow::Result<()>")]
+pub struct RegradeQuestion {
+    pub index: usize,
+    pub correct_choices: HashSet<Uuid>,
+}
+
+/// Gives the active question more time to be answered
+#[derive(Debug, Clone, Message)]
+#[rtype(result = "anyhow::Result<()>")]
+pub struct AddTime {
+    pub seconds: usize,
+}
+
+/// Asks the lobby for the announcement of the game on the local network
+#[derive(Debug, Clone, Message)]
+#[rtype(result = "common::messages::discovery::Announcement")]
+pub struct GetAnnouncement {
+    pub port: u16,
+    pub tls: bool,
+}
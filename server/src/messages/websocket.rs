@@ -1,6 +1,8 @@
-use actix::prelude::Message;
+use actix::{prelude::Message, Addr};
 use uuid::Uuid;
 
+use crate::websocket::Websocket;
+
 #[derive(Message)]
 #[rtype(result = "()")]
 pub struct GracefulStop {
@@ -16,4 +18,5 @@ pub struct HardStop;
 #[rtype(result = "()")]
 pub struct DisconnectFromLobby {
     pub player_id: Uuid,
+    pub addr: Addr<Websocket>,
 }
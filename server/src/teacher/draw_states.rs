@@ -1,11 +1,41 @@
-use common::terminal::render;
-use ratatui::Frame;
+use std::collections::HashMap;
+
+use common::{
+    analytics,
+    constants::COLORS,
+    messages::network::{ChoiceStats, PlayerAnswerDetail, QuestionListItem, ShowLeaderboard},
+    questions::{Choice, ChoiceCensored},
+    terminal::render,
+};
+use ratatui::{
+    prelude::*,
+    widgets::{ListState, Paragraph, Row, Table, TableState},
+};
+use uuid::Uuid;
+
+use crate::teacher::states::{AnalyticsState, RegradeState};
+
+/// Longer choices are cut in the chart of the answers
+const MAXIMAL_BAR_LABEL_LENGTH: usize = 30;
 
 pub fn render_teacher_help(frame: &mut Frame) {
     let help_text = [
         ("ENTER", "Move to the next state"),
         ("CTRL C", "Exit the game"),
         ("x", "Kick a player"),
+        ("l", "Lock or unlock the lobby"),
+        ("p", "Pause or resume the question"),
+        ("t | T", "Add 15 | 30 seconds to the question"),
+        ("c", "Show or hide what the players answered so far"),
+        ("d", "Show or hide the answer of each player"),
+        (
+            "TAB",
+            "Switch between the questions and the players in the summary",
+        ),
+        ("e | E", "Save the summary as Markdown | HTML"),
+        ("g", "Go to a question (or ask it again)"),
+        ("v", "Void the question, nobody gets points for it"),
+        ("r", "Change the correct choices of the question"),
         ("h", "Show this help"),
         ("↑↓ | ws", "Move up and down"),
     ];
@@ -21,3 +51,235 @@ pub fn render_skip_question_popup(frame: &mut Frame) {
     let message = "Are you sure you want to skip this question?\n Players who haven't answered will get 0 points";
     render::confirm(frame, message);
 }
+
+pub fn render_void_question_popup(frame: &mut Frame) {
+    let message = "Are you sure you want to void this question?\n Nobody will get points for it";
+    render::confirm(frame, message);
+}
+
+/// Lists the choices of the question being graded again, marking the ones which are correct
+pub fn render_regrade_picker(frame: &mut Frame, choices: &[Choice], regrade: &mut RegradeState) {
+    let items: Vec<_> = choices
+        .iter()
+        .map(|choice| {
+            let mark = if regrade.correct_choices.contains(&choice.id) {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+            format!("{mark} {}", choice.text)
+        })
+        .collect();
+
+    render::picker(
+        frame,
+        " Correct choices ",
+        " SPACE to mark as correct, ENTER to grade again, ESC to close ",
+        &items,
+        &mut regrade.list_state,
+    );
+}
+
+/// Shows how many players picked each of the choices of the running question
+pub fn render_answer_distribution(
+    frame: &mut Frame,
+    choices: &[ChoiceCensored],
+    stats: &HashMap<Uuid, ChoiceStats>,
+) {
+    let bars: Vec<_> = choices
+        .iter()
+        .enumerate()
+        .map(|(index, choice)| {
+            let label = choice.text.chars().take(MAXIMAL_BAR_LABEL_LENGTH).collect();
+            let count = stats
+                .get(&choice.id)
+                .map_or(0, |stats| stats.players_answered_count);
+
+            (
+                label,
+                u64::try_from(count).unwrap_or(u64::MAX),
+                COLORS[(index + 1) % COLORS.len()], // the same colors as the choices have
+            )
+        })
+        .collect();
+
+    render::bar_chart(frame, " Answers so far ", " Press c to hide ", &bars);
+}
+
+/// Lists what each of the players answered to the question, who was right and how fast
+pub fn render_answer_details(
+    frame: &mut Frame,
+    question_index: usize,
+    details: &[PlayerAnswerDetail],
+    table_state: &mut TableState,
+    quiz_name: &str,
+) {
+    let layout = render::list_layout(
+        frame,
+        vec![Constraint::Length(1), Constraint::Percentage(90)],
+        "Answers of the players (press d to go back):",
+        &format!(" Question {} ", question_index + 1),
+        quiz_name,
+    );
+
+    let rows: Vec<_> = details
+        .iter()
+        .map(|detail| {
+            let cells = match detail.answer_order {
+                Some(order) => vec![
+                    detail.player.nickname.clone(),
+                    detail.chosen_answers.join(", "),
+                    if detail.correct { "yes" } else { "no" }.to_string(),
+                    order.to_string(),
+                    detail.response_time_ms.map_or_else(String::new, |ms| {
+                        format!("{}.{} s", ms / 1000, ms % 1000 / 100)
+                    }),
+                    detail.points.to_string(),
+                ],
+                None => vec![
+                    detail.player.nickname.clone(),
+                    "did not answer".to_string(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    detail.points.to_string(),
+                ],
+            };
+
+            Row::new(cells).style(Style::default().fg(detail.player.color))
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Percentage(25),
+        Constraint::Percentage(35),
+        Constraint::Percentage(10),
+        Constraint::Percentage(10),
+        Constraint::Percentage(10),
+        Constraint::Percentage(10),
+    ];
+    let header = Row::new(["Player", "Answer", "Correct", "Order", "Time", "Points"]).underlined();
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(render::get_bordered_block())
+        .highlight_style(render::get_highlighted_style())
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(table, layout[1], table_state);
+}
+
+/// Shows the summary of the game: how hard the questions were, or how well each player did
+pub fn render_analytics(frame: &mut Frame, state: &mut AnalyticsState, quiz_name: &str) {
+    let (title, text) = if state.players_visible {
+        (" Summary: players ", "TAB for the questions")
+    } else {
+        (" Summary: questions ", "TAB for the players")
+    };
+    let text = state.status.clone().unwrap_or(format!(
+        "{text}, e | E to save as Markdown | HTML, ENTER to finish"
+    ));
+
+    let layout = render::list_layout(
+        frame,
+        vec![Constraint::Length(1), Constraint::Percentage(90)],
+        &text,
+        title,
+        quiz_name,
+    );
+
+    let table = if state.players_visible {
+        Table::new(
+            state
+                .analytics
+                .players
+                .iter()
+                .map(|player| Row::new(analytics::player_cells(player))),
+            [
+                Constraint::Percentage(30),
+                Constraint::Percentage(20),
+                Constraint::Percentage(50),
+            ],
+        )
+        .header(Row::new(analytics::PLAYER_HEADER).underlined())
+    } else {
+        Table::new(
+            state
+                .analytics
+                .questions
+                .iter()
+                .map(|question| Row::new(analytics::question_cells(question))),
+            [
+                Constraint::Percentage(5),
+                Constraint::Percentage(45),
+                Constraint::Percentage(10),
+                Constraint::Percentage(25),
+                Constraint::Percentage(15),
+            ],
+        )
+        .header(Row::new(analytics::QUESTION_HEADER).underlined())
+    };
+
+    let table = table
+        .block(render::get_bordered_block())
+        .highlight_style(render::get_highlighted_style())
+        .highlight_symbol(">> ");
+
+    frame.render_stateful_widget(table, layout[1], &mut state.table_state);
+}
+
+/// Lists the questions of the game, marking the ones which were already asked
+pub fn render_question_picker(
+    frame: &mut Frame,
+    questions: &[QuestionListItem],
+    list_state: &mut ListState,
+) {
+    let items: Vec<_> = questions
+        .iter()
+        .enumerate()
+        .map(|(index, question)| {
+            let asked = if question.asked { " (asked)" } else { "" };
+            format!("{}. {}{asked}", index + 1, question.text)
+        })
+        .collect();
+
+    render::picker(
+        frame,
+        " Go to question ",
+        " ENTER to go, d to ask again without the previous answers, ESC to close ",
+        &items,
+        list_state,
+    );
+}
+
+/// Shows in the top right corner whether new players can join
+pub fn render_lock_indicator(frame: &mut Frame, locked: bool) {
+    let (text, color) = if locked {
+        (" Lobby locked ", Color::Red)
+    } else {
+        (" Lobby open ", Color::Green)
+    };
+
+    let width = u16::try_from(text.len()).unwrap_or(u16::MAX);
+    let size = frame.size();
+    if size.width < width + 4 {
+        return;
+    }
+
+    // drawn over the border of the outer block, next to its corner
+    let area = Rect::new(size.width - width - 2, 0, width, 1);
+    frame.render_widget(Paragraph::new(text).fg(color).bold(), area);
+}
+
+/// Marks the players who joined after the game started, so that the teacher knows why they have fewer points
+#[must_use]
+pub fn mark_late_joiners(results: &ShowLeaderboard, late_joiners: &[Uuid]) -> ShowLeaderboard {
+    let mut results = results.clone();
+    for (player, _) in &mut results.players {
+        if late_joiners.contains(&player.uuid) {
+            player.nickname.push_str(" (joined late)");
+        }
+    }
+
+    results
+}
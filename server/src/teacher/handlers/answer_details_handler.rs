@@ -0,0 +1,25 @@
+use anyhow::bail;
+use common::messages::network::AnswerDetails;
+use log::debug;
+
+use crate::teacher::{states::TeacherTerminalState, terminal::TeacherTerminal};
+
+impl TeacherTerminal {
+    pub fn handle_answer_details(&mut self, details: AnswerDetails) -> anyhow::Result<()> {
+        debug!("Teacher: handling answer details");
+
+        let TeacherTerminalState::Answers(state) = &mut self.state else {
+            bail!("Teacher: received answer details, but the terminal is not in the Answers state");
+        };
+
+        if state.answers.question_index != details.question_index {
+            bail!(
+                "Teacher: received answer details, but the question index does not match the current question"
+            );
+        }
+
+        state.details = details.players;
+
+        Ok(())
+    }
+}
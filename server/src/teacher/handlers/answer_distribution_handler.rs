@@ -0,0 +1,28 @@
+use anyhow::bail;
+use common::messages::network::AnswerDistribution;
+use log::debug;
+
+use crate::teacher::{states::TeacherTerminalState, terminal::TeacherTerminal};
+
+impl TeacherTerminal {
+    pub fn handle_answer_distribution(
+        &mut self,
+        distribution: AnswerDistribution,
+    ) -> anyhow::Result<()> {
+        debug!("Teacher: handling answer distribution");
+
+        let TeacherTerminalState::Question(state) = &mut self.state else {
+            bail!("Teacher: received answer distribution, but the terminal is not in the Question state");
+        };
+
+        if state.question.question_index != distribution.question_index {
+            bail!(
+                "Teacher: received answer distribution, but the question index does not match the current question"
+            );
+        }
+
+        state.stats = distribution.stats;
+
+        Ok(())
+    }
+}
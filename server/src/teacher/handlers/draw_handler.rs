@@ -9,7 +9,11 @@ use common::{
 use ratatui::prelude::*;
 
 use crate::teacher::{
-    draw_states::{render_kick_popup, render_skip_question_popup, render_teacher_help},
+    draw_states::{
+        mark_late_joiners, render_analytics, render_answer_details, render_answer_distribution,
+        render_kick_popup, render_lock_indicator, render_question_picker, render_regrade_picker,
+        render_skip_question_popup, render_teacher_help, render_void_question_popup,
+    },
     states::TeacherTerminalState,
     terminal::TeacherTerminal,
 };
@@ -32,7 +36,7 @@ impl TerminalDraw for TeacherTerminal {
 
             match &mut self.state {
                 TeacherTerminalState::StartGame => {
-                    render::welcome(frame, &self.quiz_name);
+                    render::welcome(frame, self.pin.as_deref(), &self.quiz_name);
                 }
                 TeacherTerminalState::WaitingForGame(state) => {
                     render::waiting(
@@ -40,8 +44,10 @@ impl TerminalDraw for TeacherTerminal {
                         &mut self.players,
                         &mut state.list_state,
                         None,
+                        self.pin.as_deref(),
                         &self.quiz_name,
                     );
+                    render_lock_indicator(frame, self.locked);
                     if state.kick_popup_visible {
                         render_kick_popup(frame);
                     }
@@ -64,6 +70,8 @@ impl TerminalDraw for TeacherTerminal {
                             state.players_answered_count,
                             &mut grid,
                             None,
+                            None,
+                            None,
                             usize::try_from(state.duration_from_start.num_seconds())
                                 .unwrap_or(usize::MAX),
                             false,
@@ -71,6 +79,20 @@ impl TerminalDraw for TeacherTerminal {
                             &self.quiz_name,
                         );
 
+                        if state.distribution_visible {
+                            render_answer_distribution(
+                                frame,
+                                &state.question.question.choices,
+                                &state.stats,
+                            );
+                        }
+                        if state.paused {
+                            render::paused(
+                                frame,
+                                "The question is paused, students cannot answer",
+                                " Press p to resume ",
+                            );
+                        }
                         if state.skip_popup_visible {
                             render_skip_question_popup(frame);
                         }
@@ -87,26 +109,47 @@ impl TerminalDraw for TeacherTerminal {
                             MINIMAL_QUESTION_WIDTH,
                         );
                     } else {
-                        render::question::answers(
-                            frame,
-                            &state.answers,
-                            self.syntax_theme,
-                            &self.quiz_name,
-                        );
+                        if state.details_visible {
+                            render_answer_details(
+                                frame,
+                                state.answers.question_index,
+                                &state.details,
+                                &mut state.details_table,
+                                &self.quiz_name,
+                            );
+                        } else {
+                            render::question::answers(
+                                frame,
+                                &state.answers,
+                                self.syntax_theme,
+                                &self.quiz_name,
+                            );
+                        }
+
+                        if state.void_popup_visible {
+                            render_void_question_popup(frame);
+                        }
+                        if let Some(regrade) = &mut state.regrade {
+                            render_regrade_picker(frame, &state.answers.question.choices, regrade);
+                        }
                     }
                 }
                 TeacherTerminalState::Results(state) => {
                     render::results(
                         frame,
-                        &state.results,
+                        &mark_late_joiners(&state.results, &self.late_joiners),
                         &mut state.table_state,
                         None,
                         &self.quiz_name,
                     );
+                    render_lock_indicator(frame, self.locked);
                     if state.kick_popup_visible {
                         render_kick_popup(frame);
                     }
                 }
+                TeacherTerminalState::Analytics(state) => {
+                    render_analytics(frame, state, &self.quiz_name);
+                }
                 TeacherTerminalState::EndGame => {
                     render::end_game(frame, &self.quiz_name);
                 }
@@ -115,6 +158,10 @@ impl TerminalDraw for TeacherTerminal {
                 }
             }
 
+            if let Some(list_state) = &mut self.question_picker {
+                render_question_picker(frame, &self.questions, list_state);
+            }
+
             if self.help_visible {
                 render_teacher_help(frame);
             }
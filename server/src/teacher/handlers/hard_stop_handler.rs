@@ -5,8 +5,10 @@ use crate::{messages::lobby, teacher::terminal::TeacherTerminal};
 
 impl TerminalStop for TeacherTerminal {
     fn stop(&mut self) -> anyhow::Result<()> {
-        // lobby is on a different thread
-        self.lobby.do_send(lobby::HardStop);
+        // lobby is on a different thread; a remote lobby keeps running without us
+        if let Some(lobby) = &self.lobby {
+            lobby.do_send(lobby::HardStop);
+        }
         System::current().stop();
 
         Ok(())
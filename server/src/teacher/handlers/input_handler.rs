@@ -1,22 +1,21 @@
-use actix::Addr;
+use actix::Recipient;
 use crossterm::event::KeyCode;
 use uuid::Uuid;
 
 use log::debug;
-use ratatui::widgets::ListState;
+use ratatui::widgets::{ListState, TableState};
 
 use common::{
-    constants::PLAYER_KICKED_MESSAGE,
+    analytics::{self, AnalyticsFormat},
+    constants::{LONG_ADDED_TIME_SECS, PLAYER_KICKED_MESSAGE, SHORT_ADDED_TIME_SECS},
+    messages::network::{GameAnalytics, QuestionListItem, TeacherCommand},
+    questions::Choice,
     terminal::{actor::TerminalHandleInput, input_utils::move_in_list},
 };
 
-use crate::{
-    messages::lobby::{EndQuestion, KickPlayer, StartQuestion, SwitchToLeaderboard},
-    teacher::{
-        states::{TeacherTerminalState, WaitingForGameState},
-        terminal::TeacherTerminal,
-    },
-    Lobby,
+use crate::teacher::{
+    states::{AnalyticsState, RegradeState, TeacherTerminalState, WaitingForGameState},
+    terminal::TeacherTerminal,
 };
 
 impl TerminalHandleInput for TeacherTerminal {
@@ -35,6 +34,29 @@ impl TerminalHandleInput for TeacherTerminal {
             return;
         }
 
+        if let Some(list_state) = &mut self.question_picker {
+            if handle_question_picker(&self.control, &self.questions, list_state, key_code) {
+                self.question_picker = None;
+            }
+            return;
+        }
+
+        // the teacher can go to another question between the questions, until the game ends
+        let between_questions = match &self.state {
+            TeacherTerminalState::WaitingForGame(state) => !state.kick_popup_visible,
+            TeacherTerminalState::Answers(state) => {
+                !state.void_popup_visible && state.regrade.is_none()
+            }
+            TeacherTerminalState::Results(state) => {
+                !state.kick_popup_visible && !state.results.was_final_round
+            }
+            _ => false,
+        };
+        if key_code == KeyCode::Char('g') && between_questions && !self.questions.is_empty() {
+            self.question_picker = Some(ListState::default().with_selected(Some(0)));
+            return;
+        }
+
         match &mut self.state {
             TeacherTerminalState::StartGame => {
                 if key_code == KeyCode::Enter {
@@ -50,7 +72,7 @@ impl TerminalHandleInput for TeacherTerminal {
                 if state.kick_popup_visible {
                     if !self.players.is_empty() {
                         let player_uuid = self.players[selected].uuid;
-                        let kicked = handle_kick_player(&self.lobby, key_code, player_uuid);
+                        let kicked = handle_kick_player(&self.control, key_code, player_uuid);
 
                         if kicked {
                             state.list_state.select(Some(selected.saturating_sub(1)));
@@ -62,12 +84,11 @@ impl TerminalHandleInput for TeacherTerminal {
                 }
 
                 match key_code {
-                    KeyCode::Enter => self.lobby.do_send(StartQuestion),
-                    KeyCode::Char('x') => {
-                        if !self.players.is_empty() {
-                            state.kick_popup_visible = true;
-                        }
+                    KeyCode::Enter => self.control.do_send(TeacherCommand::StartQuestion),
+                    KeyCode::Char('x') if !self.players.is_empty() => {
+                        state.kick_popup_visible = true;
                     }
+                    KeyCode::Char('l') => toggle_lock(&self.control, self.locked),
                     _ => {}
                 };
 
@@ -77,19 +98,70 @@ impl TerminalHandleInput for TeacherTerminal {
             TeacherTerminalState::Question(state) => {
                 if state.skip_popup_visible {
                     if let KeyCode::Char('y') = key_code {
-                        self.lobby.do_send(EndQuestion {
+                        self.control.do_send(TeacherCommand::EndQuestion {
                             index: state.question.question_index,
                         });
                     }
                     state.skip_popup_visible = false;
+                    return;
                 }
-                if key_code == KeyCode::Enter {
-                    state.skip_popup_visible = true;
+
+                match key_code {
+                    KeyCode::Enter => state.skip_popup_visible = true,
+                    // the indicator changes when the lobby confirms the pause
+                    KeyCode::Char('p') if state.paused => {
+                        self.control.do_send(TeacherCommand::ResumeQuestion);
+                    }
+                    KeyCode::Char('p') => self.control.do_send(TeacherCommand::PauseQuestion),
+                    KeyCode::Char('t') => self.control.do_send(TeacherCommand::AddTime {
+                        seconds: SHORT_ADDED_TIME_SECS,
+                    }),
+                    KeyCode::Char('T') => self.control.do_send(TeacherCommand::AddTime {
+                        seconds: LONG_ADDED_TIME_SECS,
+                    }),
+                    KeyCode::Char('c') if state.question.kind.has_choices() => {
+                        state.distribution_visible = !state.distribution_visible;
+                    }
+                    _ => {}
                 }
             }
-            TeacherTerminalState::Answers(_) => {
-                if key_code == KeyCode::Enter {
-                    self.lobby.do_send(SwitchToLeaderboard);
+            TeacherTerminalState::Answers(state) => {
+                let index = state.answers.question_index;
+
+                if state.void_popup_visible {
+                    if let KeyCode::Char('y') = key_code {
+                        self.control.do_send(TeacherCommand::VoidQuestion { index });
+                    }
+                    state.void_popup_visible = false;
+                    return;
+                }
+
+                if let Some(regrade) = &mut state.regrade {
+                    let choices = &state.answers.question.choices;
+                    if handle_regrade(&self.control, index, choices, regrade, key_code) {
+                        state.regrade = None;
+                    }
+                    return;
+                }
+
+                if state.details_visible && !state.details.is_empty() {
+                    let mut selected = state.details_table.selected().unwrap_or(0);
+                    move_in_list(&mut selected, state.details.len(), key_code);
+                    state.details_table.select(Some(selected));
+                }
+
+                // the lobby shows the leaderboard with the new scores after the question is voided or graded again
+                match key_code {
+                    KeyCode::Enter => self.control.do_send(TeacherCommand::SwitchToLeaderboard),
+                    KeyCode::Char('d') => state.details_visible = !state.details_visible,
+                    KeyCode::Char('v') => state.void_popup_visible = true,
+                    KeyCode::Char('r') if state.answers.question.kind.has_choices() => {
+                        state.regrade = Some(RegradeState {
+                            list_state: ListState::default().with_selected(Some(0)),
+                            correct_choices: state.answers.question.correct_choices(),
+                        });
+                    }
+                    _ => {}
                 }
             }
             TeacherTerminalState::Results(state) => {
@@ -98,7 +170,7 @@ impl TerminalHandleInput for TeacherTerminal {
                 if state.kick_popup_visible {
                     if !self.players.is_empty() {
                         let player_uuid = state.results.players[selected].0.uuid;
-                        let kicked = handle_kick_player(&self.lobby, key_code, player_uuid);
+                        let kicked = handle_kick_player(&self.control, key_code, player_uuid);
 
                         if kicked {
                             state.table_state.select(Some(selected.saturating_sub(1)));
@@ -118,9 +190,18 @@ impl TerminalHandleInput for TeacherTerminal {
 
                 if key_code == KeyCode::Enter {
                     if state.results.was_final_round {
-                        self.state = TeacherTerminalState::EndGame;
+                        // the summary is missing only if the lobby did not send it
+                        self.state = match self.analytics.take() {
+                            Some(analytics) => TeacherTerminalState::Analytics(AnalyticsState {
+                                analytics,
+                                players_visible: false,
+                                table_state: TableState::default().with_selected(Some(0)),
+                                status: None,
+                            }),
+                            None => TeacherTerminalState::EndGame,
+                        };
                     }
-                    self.lobby.do_send(StartQuestion);
+                    self.control.do_send(TeacherCommand::StartQuestion);
                     return;
                 }
 
@@ -128,9 +209,44 @@ impl TerminalHandleInput for TeacherTerminal {
                     state.kick_popup_visible = true;
                 }
 
+                if key_code == KeyCode::Char('l') {
+                    toggle_lock(&self.control, self.locked);
+                }
+
                 move_in_list(&mut selected, self.players.len(), key_code);
                 state.table_state.select(Some(selected));
             }
+            TeacherTerminalState::Analytics(state) => {
+                let rows = if state.players_visible {
+                    state.analytics.players.len()
+                } else {
+                    state.analytics.questions.len()
+                };
+
+                match key_code {
+                    KeyCode::Enter => self.state = TeacherTerminalState::EndGame,
+                    KeyCode::Tab => {
+                        state.players_visible = !state.players_visible;
+                        state.table_state.select(Some(0));
+                    }
+                    KeyCode::Char('e') => {
+                        state.status = Some(export_analytics(
+                            &state.analytics,
+                            AnalyticsFormat::Markdown,
+                        ));
+                    }
+                    KeyCode::Char('E') => {
+                        state.status =
+                            Some(export_analytics(&state.analytics, AnalyticsFormat::Html));
+                    }
+                    _ if rows > 0 => {
+                        let mut selected = state.table_state.selected().unwrap_or(0);
+                        move_in_list(&mut selected, rows, key_code);
+                        state.table_state.select(Some(selected));
+                    }
+                    _ => {}
+                }
+            }
             TeacherTerminalState::EndGame => {
                 debug!("EndGame - doing nothing: {:?}", key_code);
             }
@@ -141,10 +257,92 @@ impl TerminalHandleInput for TeacherTerminal {
     }
 }
 
-fn handle_kick_player(lobby_addr: &Addr<Lobby>, key_code: KeyCode, player_uuid: Uuid) -> bool {
+/// Returns whether the picker should close
+fn handle_question_picker(
+    control: &Recipient<TeacherCommand>,
+    questions: &[QuestionListItem],
+    list_state: &mut ListState,
+    key_code: KeyCode,
+) -> bool {
+    let mut selected = list_state.selected().unwrap_or(0);
+
+    match key_code {
+        KeyCode::Esc | KeyCode::Char('g') => true,
+        KeyCode::Enter => {
+            control.do_send(TeacherCommand::JumpToQuestion { index: selected });
+            true
+        }
+        KeyCode::Char('d') if questions[selected].asked => {
+            control.do_send(TeacherCommand::ReopenQuestion {
+                index: selected,
+                discard_results: true,
+            });
+            true
+        }
+        _ => {
+            move_in_list(&mut selected, questions.len(), key_code);
+            list_state.select(Some(selected));
+            false
+        }
+    }
+}
+
+/// Returns whether the regrade picker should close
+fn handle_regrade(
+    control: &Recipient<TeacherCommand>,
+    index: usize,
+    choices: &[Choice],
+    regrade: &mut RegradeState,
+    key_code: KeyCode,
+) -> bool {
+    let mut selected = regrade.list_state.selected().unwrap_or(0);
+
+    match key_code {
+        KeyCode::Esc => true,
+        // a question without a correct choice is voided instead
+        KeyCode::Enter if !regrade.correct_choices.is_empty() => {
+            control.do_send(TeacherCommand::RegradeQuestion {
+                index,
+                correct_choices: regrade.correct_choices.clone(),
+            });
+            true
+        }
+        KeyCode::Char(' ') => {
+            let id = choices[selected].id;
+            if !regrade.correct_choices.remove(&id) {
+                regrade.correct_choices.insert(id);
+            }
+            false
+        }
+        _ => {
+            move_in_list(&mut selected, choices.len(), key_code);
+            regrade.list_state.select(Some(selected));
+            false
+        }
+    }
+}
+
+/// Saves the summary of the game and returns the message telling the teacher where it went
+fn export_analytics(analytics: &GameAnalytics, format: AnalyticsFormat) -> String {
+    match analytics::export(analytics, format) {
+        Ok(path) => format!("Saved to {}", path.display()),
+        Err(e) => format!("Failed to save: {e:#}"),
+    }
+}
+
+/// The lock indicator changes when the lobby confirms the new state
+fn toggle_lock(control: &Recipient<TeacherCommand>, locked: bool) {
+    control.do_send(TeacherCommand::SetLock { locked: !locked });
+}
+
+fn handle_kick_player(
+    control: &Recipient<TeacherCommand>,
+    key_code: KeyCode,
+    player_uuid: Uuid,
+) -> bool {
     match key_code {
         KeyCode::Char('y') => {
-            lobby_addr.do_send(KickPlayer {
+            control.do_send(TeacherCommand::KickPlayer {
                 player_uuid,
                 reason: Some(PLAYER_KICKED_MESSAGE.to_string()),
             });
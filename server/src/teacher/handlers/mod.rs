@@ -1,9 +1,14 @@
+pub mod answer_details_handler;
+pub mod answer_distribution_handler;
 pub mod draw_handler;
 pub mod hard_stop_handler;
 pub mod input_handler;
 pub mod next_question_handler;
+pub mod pause_update_handler;
 pub mod players_update_handler;
 pub mod question_ended_handler;
+pub mod question_time_update_handler;
 pub mod question_update_handler;
+pub mod server_network_message_handler;
 pub mod show_leaderboard_handler;
 pub mod tick_handler;
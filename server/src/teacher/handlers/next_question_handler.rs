@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use common::{messages::network::NextQuestion, terminal::actor::TerminalHandleNextQuestion};
 
 use crate::teacher::{
@@ -13,13 +15,18 @@ impl TerminalHandleNextQuestion for TeacherTerminal {
             question.question_index
         );
 
-        self.state = TeacherTerminalState::Question(QuestionState {
+        let mut state = QuestionState {
             question,
             players_answered_count: 0,
             start_time: chrono::Utc::now(),
             duration_from_start: chrono::Duration::zero(),
             skip_popup_visible: false,
-        });
+            paused: false,
+            stats: HashMap::new(),
+            distribution_visible: false,
+        };
+        state.sync_countdown(self.clock_offset, chrono::Duration::zero());
+        self.state = TeacherTerminalState::Question(state);
 
         Ok(())
     }
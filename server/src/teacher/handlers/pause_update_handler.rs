@@ -0,0 +1,29 @@
+use anyhow::bail;
+use common::messages::network::PauseUpdate;
+use log::debug;
+
+use crate::teacher::{states::TeacherTerminalState, terminal::TeacherTerminal};
+
+impl TeacherTerminal {
+    pub fn handle_pause_update(&mut self, update: PauseUpdate) -> anyhow::Result<()> {
+        debug!("Teacher: handling pause update");
+
+        let TeacherTerminalState::Question(state) = &mut self.state else {
+            bail!("Teacher: received pause update, but the terminal is not in the Question state");
+        };
+
+        if state.question.question_index != update.question_index {
+            bail!(
+                "Teacher: received pause update, but the question index does not match the current question"
+            );
+        }
+
+        // the countdown continues from the time the lobby measured
+        let elapsed = chrono::Duration::seconds(update.elapsed_seconds.try_into().unwrap_or(0));
+        state.paused = update.paused;
+        state.question.deadline = update.deadline;
+        state.sync_countdown(self.clock_offset, elapsed);
+
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ impl TerminalHandlePlayersUpdate for TeacherTerminal {
         debug!("Teacher: handling players update");
 
         self.players = update.players;
+        self.late_joiners = update.late_joiners;
 
         Ok(())
     }
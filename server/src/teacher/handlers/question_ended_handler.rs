@@ -1,6 +1,7 @@
 use anyhow::bail;
 use common::{messages::network::QuestionEnded, terminal::actor::TerminalHandleQuestionEnded};
 use log::debug;
+use ratatui::widgets::TableState;
 
 use crate::teacher::{
     states::{AnswersState, TeacherTerminalState},
@@ -24,9 +25,14 @@ impl TerminalHandleQuestionEnded for TeacherTerminal {
             );
         }
 
-        self.state = TeacherTerminalState::Answers(AnswersState {
+        self.state = TeacherTerminalState::Answers(Box::new(AnswersState {
             answers: question_ended,
-        });
+            details: Vec::new(),
+            details_visible: false,
+            details_table: TableState::default().with_selected(Some(0)),
+            void_popup_visible: false,
+            regrade: None,
+        }));
 
         Ok(())
     }
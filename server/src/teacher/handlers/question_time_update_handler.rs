@@ -0,0 +1,32 @@
+use anyhow::bail;
+use common::messages::network::QuestionTimeUpdate;
+use log::debug;
+
+use crate::teacher::{states::TeacherTerminalState, terminal::TeacherTerminal};
+
+impl TeacherTerminal {
+    pub fn handle_question_time_update(
+        &mut self,
+        update: QuestionTimeUpdate,
+    ) -> anyhow::Result<()> {
+        debug!("Teacher: handling question time update");
+
+        let TeacherTerminalState::Question(state) = &mut self.state else {
+            bail!("Teacher: received question time update, but the terminal is not in the Question state");
+        };
+
+        if state.question.question_index != update.question_index {
+            bail!(
+                "Teacher: received question time update, but the question index does not match the current question"
+            );
+        }
+
+        // the deadline moves along with the time of the question
+        let elapsed = state.duration_from_start;
+        state.question.question.time_seconds = update.time_seconds;
+        state.question.deadline = update.deadline;
+        state.sync_countdown(self.clock_offset, elapsed);
+
+        Ok(())
+    }
+}
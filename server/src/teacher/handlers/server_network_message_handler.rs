@@ -0,0 +1,51 @@
+use common::{
+    messages::ServerNetworkMessage,
+    terminal::actor::{
+        TerminalHandleNextQuestion, TerminalHandlePlayersUpdate, TerminalHandleQuestionEnded,
+        TerminalHandleQuestionUpdate, TerminalHandleServerNetworkMessage,
+        TerminalHandleShowLeaderboard,
+    },
+};
+use log::debug;
+
+use crate::teacher::terminal::TeacherTerminal;
+
+impl TerminalHandleServerNetworkMessage for TeacherTerminal {
+    /// The lobby sends the teacher the same messages as the players, whether the teacher is remote or not
+    fn handle_network_message(
+        &mut self,
+        network_message: ServerNetworkMessage,
+    ) -> anyhow::Result<()> {
+        match network_message {
+            ServerNetworkMessage::PlayersUpdate(update) => self.handle_players_update(update),
+            ServerNetworkMessage::NextQuestion(question) => self.handle_next_question(question),
+            ServerNetworkMessage::QuestionUpdate(update) => self.handle_question_update(update),
+            ServerNetworkMessage::QuestionEnded(ended) => self.handle_question_ended(ended),
+            ServerNetworkMessage::ShowLeaderboard(show) => self.handle_show_leaderboard(show),
+            ServerNetworkMessage::PauseUpdate(update) => self.handle_pause_update(update),
+            ServerNetworkMessage::QuestionTimeUpdate(update) => {
+                self.handle_question_time_update(update)
+            }
+            ServerNetworkMessage::LockUpdate(update) => {
+                self.locked = update.locked;
+                Ok(())
+            }
+            ServerNetworkMessage::AnswerDetails(details) => self.handle_answer_details(details),
+            ServerNetworkMessage::AnswerDistribution(distribution) => {
+                self.handle_answer_distribution(distribution)
+            }
+            ServerNetworkMessage::GameAnalytics(analytics) => {
+                self.analytics = Some(analytics);
+                Ok(())
+            }
+            ServerNetworkMessage::QuestionList(list) => {
+                self.questions = list.questions;
+                Ok(())
+            }
+            message => {
+                debug!("Teacher: ignoring {message:?}");
+                Ok(())
+            }
+        }
+    }
+}
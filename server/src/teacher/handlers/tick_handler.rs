@@ -6,6 +6,10 @@ impl common::terminal::actor::TerminalHandleTick for TeacherTerminal {
             return Ok(());
         };
 
+        if state.paused {
+            return Ok(());
+        }
+
         let current_time = chrono::Utc::now();
         state.duration_from_start = current_time - state.start_time;
 
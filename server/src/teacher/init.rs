@@ -15,11 +15,12 @@ pub fn run_teacher(
     lobby: Addr<Lobby>,
     tx: Sender<Addr<Teacher>>,
     quiz_name: &str,
+    pin: Option<String>,
     syntax_theme: Theme,
 ) -> anyhow::Result<()> {
     let system = actix::System::new();
 
-    system.block_on(init(lobby, tx, quiz_name, syntax_theme))?;
+    system.block_on(init(lobby, tx, quiz_name, pin, syntax_theme))?;
 
     system.run()?;
 
@@ -31,10 +32,12 @@ async fn init(
     lobby: Addr<Lobby>,
     tx: Sender<Addr<Teacher>>,
     quiz_name: &str,
+    pin: Option<String>,
     syntax_theme: Theme,
 ) -> anyhow::Result<()> {
     let teacher = TerminalActor::new(TeacherTerminal::new(
         quiz_name.to_string(),
+        pin,
         lobby.clone(),
         syntax_theme,
     ))?
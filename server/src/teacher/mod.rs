@@ -1,9 +1,11 @@
 mod draw_states;
 mod handlers;
 mod init;
+mod remote;
 mod states;
 mod terminal;
 
 pub use handlers::*;
 pub use init::*;
+pub use remote::*;
 pub use terminal::*;
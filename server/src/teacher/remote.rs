@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use actix::{fut::wrap_future, prelude::*};
+use anyhow::bail;
+use chrono::Utc;
+use futures_util::{
+    stream::{SplitSink, SplitStream},
+    SinkExt, StreamExt,
+};
+use log::{debug, error, info};
+use tokio::{net::TcpStream, sync::Mutex};
+use tokio_tungstenite::{
+    connect_async_tls_with_config, tungstenite::Message, Connector, MaybeTlsStream, WebSocketStream,
+};
+
+use common::{
+    clock::estimate_clock_offset,
+    constants::PROTOCOL_VERSION,
+    messages::{
+        network::{CanJoin, TeacherCommand, TeacherLoginRequest, TeacherLoginResponse},
+        ClientNetworkMessage, ServerNetworkMessage,
+    },
+    terminal::{actor::TerminalActor, highlight::Theme, messages::Stop},
+};
+
+use super::{terminal::TeacherTerminal, Teacher};
+
+type Socket = WebSocketStream<MaybeTlsStream<TcpStream>>;
+type Sender = Arc<Mutex<SplitSink<Socket, Message>>>;
+type Receiver = SplitStream<Socket>;
+
+/// Stands in for the lobby of a remote server: the commands of the teacher are sent over the websocket
+pub struct RemoteLobby {
+    sender: Sender,
+}
+
+impl Actor for RemoteLobby {
+    type Context = Context<Self>;
+}
+
+impl Handler<TeacherCommand> for RemoteLobby {
+    type Result = anyhow::Result<()>;
+
+    fn handle(&mut self, msg: TeacherCommand, ctx: &mut Self::Context) -> Self::Result {
+        let msg = serde_json::to_string(&ClientNetworkMessage::TeacherCommand(msg))?;
+        let sender = self.sender.clone();
+
+        wrap_future::<_, Self>(async move {
+            if let Err(e) = sender.lock().await.send(Message::Text(msg)).await {
+                error!("Failed to send the command to the server: {e}");
+            }
+        })
+        .wait(ctx);
+
+        Ok(())
+    }
+}
+
+/// Connects to the server at `addr`, logs the teacher in with `token` and runs the teacher's terminal
+/// until the teacher quits or the connection is lost.
+/// # Errors
+/// - If the server cannot be reached or does not let the teacher in
+/// - If the terminal cannot be initialized
+pub fn run_remote_teacher(
+    addr: &str,
+    tls_connector: Option<Connector>,
+    token: String,
+    syntax_theme: Theme,
+) -> anyhow::Result<()> {
+    let system = actix::System::new();
+
+    system.block_on(init(addr, tls_connector, token, syntax_theme))?;
+
+    system.run()?;
+
+    Ok(())
+}
+
+async fn init(
+    addr: &str,
+    tls_connector: Option<Connector>,
+    token: String,
+    syntax_theme: Theme,
+) -> anyhow::Result<()> {
+    let (socket, _) = connect_async_tls_with_config(addr, None, false, tls_connector).await?;
+    let (mut sender, mut receiver) = socket.split();
+
+    let login = ClientNetworkMessage::TeacherLoginRequest(TeacherLoginRequest {
+        token,
+        protocol_version: PROTOCOL_VERSION,
+    });
+    let sent_at = Utc::now();
+    sender
+        .send(Message::Text(serde_json::to_string(&login)?))
+        .await?;
+
+    let response = receive_login_response(&mut receiver).await?;
+    let clock_offset = response
+        .server_time
+        .map_or_else(chrono::Duration::zero, |server_time| {
+            estimate_clock_offset(sent_at, Utc::now(), server_time)
+        });
+    if let CanJoin::No(reason) = response.can_login {
+        bail!("The server did not let the teacher in: {reason}");
+    }
+    info!("Logged in to {addr} as a teacher");
+
+    let lobby = RemoteLobby {
+        sender: Arc::new(Mutex::new(sender)),
+    }
+    .start();
+
+    let teacher = TerminalActor::new(TeacherTerminal::remote(
+        response.quiz_name,
+        response.pin,
+        lobby.recipient(),
+        clock_offset,
+        syntax_theme,
+    ))?
+    .start();
+
+    tokio::task::spawn_local(forward_messages(receiver, teacher));
+
+    Ok(())
+}
+
+async fn receive_login_response(receiver: &mut Receiver) -> anyhow::Result<TeacherLoginResponse> {
+    while let Some(msg) = receiver.next().await {
+        let Message::Text(msg) = msg? else {
+            continue;
+        };
+
+        if let ServerNetworkMessage::TeacherLoginResponse(response) = serde_json::from_str(&msg)? {
+            return Ok(response);
+        }
+    }
+
+    bail!("The server closed the connection")
+}
+
+/// Hands the updates of the game over to the teacher, the teacher quits when the connection is lost
+async fn forward_messages(mut receiver: Receiver, teacher: Addr<Teacher>) {
+    while let Some(Ok(msg)) = receiver.next().await {
+        let Message::Text(msg) = msg else {
+            continue;
+        };
+
+        match serde_json::from_str::<ServerNetworkMessage>(&msg) {
+            Ok(msg) => teacher.do_send(msg),
+            Err(e) => debug!("Ignoring a message the teacher does not understand: {e}"),
+        }
+    }
+
+    let _ = teacher.send(Stop).await;
+    error!("The connection to the server was lost");
+}
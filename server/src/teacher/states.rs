@@ -1,6 +1,11 @@
+use std::collections::{HashMap, HashSet};
+
 use ratatui::widgets::{ListState, TableState};
+use uuid::Uuid;
 
-use common::messages::network::{NextQuestion, QuestionEnded, ShowLeaderboard};
+use common::messages::network::{
+    ChoiceStats, GameAnalytics, NextQuestion, PlayerAnswerDetail, QuestionEnded, ShowLeaderboard,
+};
 
 #[derive(Debug)]
 pub struct WaitingForGameState {
@@ -15,11 +20,44 @@ pub struct QuestionState {
     pub(super) start_time: chrono::DateTime<chrono::Utc>,
     pub(super) duration_from_start: chrono::Duration,
     pub(super) skip_popup_visible: bool,
+    pub(super) paused: bool,
+    pub(super) stats: HashMap<Uuid, ChoiceStats>, // what the players answered so far
+    pub(super) distribution_visible: bool, // hidden by default, as the screen may be shown to the players
+}
+
+impl QuestionState {
+    /// Moves the start of the countdown, so that it runs out right at the deadline of the server;
+    /// if the server did not send the deadline (or the question is paused), `elapsed` is taken as it is
+    pub(super) fn sync_countdown(
+        &mut self,
+        clock_offset: chrono::Duration,
+        elapsed: chrono::Duration,
+    ) {
+        let now = chrono::Utc::now();
+
+        self.start_time = self
+            .question
+            .local_start_time(clock_offset)
+            .unwrap_or(now - elapsed);
+        self.duration_from_start = now - self.start_time;
+    }
+}
+
+/// The choices the teacher marks as correct before the question is graded again
+#[derive(Debug)]
+pub struct RegradeState {
+    pub(super) list_state: ListState,
+    pub(super) correct_choices: HashSet<Uuid>,
 }
 
 #[derive(Debug)]
 pub struct AnswersState {
     pub(super) answers: QuestionEnded,
+    pub(super) details: Vec<PlayerAnswerDetail>, // what each of the players answered
+    pub(super) details_visible: bool,
+    pub(super) details_table: TableState,
+    pub(super) void_popup_visible: bool,
+    pub(super) regrade: Option<RegradeState>,
 }
 
 #[derive(Debug)]
@@ -29,6 +67,14 @@ pub struct ResultsState {
     pub(super) kick_popup_visible: bool,
 }
 
+#[derive(Debug)]
+pub struct AnalyticsState {
+    pub(super) analytics: GameAnalytics,
+    pub(super) players_visible: bool, // the questions are shown first
+    pub(super) table_state: TableState,
+    pub(super) status: Option<String>, // where the summary was saved to, or why it was not
+}
+
 #[derive(Debug)]
 pub struct ErrorState {
     pub(super) message: String,
@@ -39,8 +85,9 @@ pub enum TeacherTerminalState {
     StartGame,
     WaitingForGame(WaitingForGameState),
     Question(QuestionState),
-    Answers(AnswersState),
+    Answers(Box<AnswersState>),
     Results(ResultsState),
+    Analytics(AnalyticsState),
     EndGame,
     Error(ErrorState),
 }
@@ -1,14 +1,27 @@
 use actix::prelude::*;
+use ratatui::widgets::ListState;
+use uuid::Uuid;
 
-use common::{messages::network::PlayerData, terminal::highlight::Theme};
+use common::{
+    messages::network::{GameAnalytics, PlayerData, QuestionListItem, TeacherCommand},
+    terminal::highlight::Theme,
+};
 
 use crate::{teacher::states::TeacherTerminalState, Lobby};
 
 #[allow(clippy::module_name_repetitions)]
 pub struct TeacherTerminal {
     pub quiz_name: String,
-    pub lobby: Addr<Lobby>,
+    pub pin: Option<String>, // shown to the students, so that they can join
+    pub control: Recipient<TeacherCommand>, // the lobby, or the connection to a remote one
+    pub lobby: Option<Addr<Lobby>>, // stops with the teacher if it runs in this process
     pub players: Vec<PlayerData>,
+    pub late_joiners: Vec<Uuid>, // players who joined after the game started
+    pub locked: bool,            // whether new players can join, as last told by the lobby
+    pub clock_offset: chrono::Duration, // how far the clock of the lobby is ahead of ours
+    pub questions: Vec<QuestionListItem>, // to pick the question to go to
+    pub question_picker: Option<ListState>, // visible while the teacher picks a question
+    pub analytics: Option<GameAnalytics>, // summary of the game, shown after the final leaderboard
     pub help_visible: bool,
     pub state: TeacherTerminalState,
     pub syntax_theme: Theme,
@@ -16,11 +29,51 @@ pub struct TeacherTerminal {
 
 impl TeacherTerminal {
     #[must_use]
-    pub fn new(quiz_name: String, lobby: Addr<Lobby>, syntax_theme: Theme) -> Self {
+    pub fn new(
+        quiz_name: String,
+        pin: Option<String>,
+        lobby: Addr<Lobby>,
+        syntax_theme: Theme,
+    ) -> Self {
         Self {
             quiz_name,
-            lobby,
+            pin,
+            control: lobby.clone().recipient(),
+            lobby: Some(lobby),
             players: Vec::new(),
+            late_joiners: Vec::new(),
+            locked: false,
+            clock_offset: chrono::Duration::zero(),
+            questions: Vec::new(),
+            question_picker: None,
+            analytics: None,
+            help_visible: false,
+            state: TeacherTerminalState::StartGame,
+            syntax_theme,
+        }
+    }
+
+    /// Creates a teacher controlling a lobby on another computer through `control`
+    #[must_use]
+    pub fn remote(
+        quiz_name: String,
+        pin: Option<String>,
+        control: Recipient<TeacherCommand>,
+        clock_offset: chrono::Duration,
+        syntax_theme: Theme,
+    ) -> Self {
+        Self {
+            quiz_name,
+            pin,
+            control,
+            lobby: None,
+            players: Vec::new(),
+            late_joiners: Vec::new(),
+            locked: false,
+            clock_offset,
+            questions: Vec::new(),
+            question_picker: None,
+            analytics: None,
             help_visible: false,
             state: TeacherTerminalState::StartGame,
             syntax_theme,
@@ -0,0 +1,51 @@
+use tokio::io::AsyncWriteExt;
+
+use crate::connection::Connection;
+
+/// The browser student client, served on the same port as the websocket
+const INDEX_HTML: &str = include_str!("index.html");
+
+/// Returns true if the request asks to upgrade the connection to a websocket
+#[must_use]
+pub fn is_websocket_upgrade(head: &str) -> bool {
+    head.lines().skip(1).any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("upgrade")
+                && value.trim().eq_ignore_ascii_case("websocket")
+        })
+    })
+}
+
+/// Answers a plain HTTP request with the browser client and closes the connection.
+/// # Errors
+/// - If the response cannot be written to the connection
+pub async fn serve(mut connection: Connection, head: &str) -> anyhow::Result<()> {
+    let mut request_line = head.lines().next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default();
+    let path = request_line.next().unwrap_or_default();
+    let path = path.split_once('?').map_or(path, |(path, _)| path);
+
+    let response = match (method, path) {
+        ("GET", "/" | "/index.html") => response("200 OK", "text/html; charset=utf-8", INDEX_HTML),
+        ("GET", _) => response("404 Not Found", "text/plain", "Not found"),
+        _ => response("405 Method Not Allowed", "text/plain", "Method not allowed"),
+    };
+
+    connection.write_all(response.as_bytes()).await?;
+    connection.shutdown().await?;
+
+    Ok(())
+}
+
+fn response(status: &str, content_type: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {status}\r\n\
+         Content-Type: {content_type}\r\n\
+         Content-Length: {}\r\n\
+         Cache-Control: no-cache\r\n\
+         Connection: close\r\n\
+         \r\n\
+         {body}",
+        body.len()
+    )
+}
@@ -1,30 +1,37 @@
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc};
 
-use actix::{Addr, AsyncContext, Handler};
+use actix::{fut::wrap_future, ActorFutureExt, Addr, AsyncContext, Handler};
 use common::messages::{
-    network::{AnswerSelected, JoinRequest, TryJoinRequest},
+    network::{
+        AnswerSelected, CanJoin, JoinRequest, ReconnectRequest, TeacherCommand,
+        TeacherLoginRequest, TryJoinRequest,
+    },
     ClientNetworkMessage, ServerNetworkMessage,
 };
 use futures_util::stream::SplitSink;
-use tokio::{net::TcpStream, sync::Mutex};
+use tokio::sync::Mutex;
 use tungstenite::Message;
 
 use crate::{
+    connection::Connection,
     messages::{client, websocket::GracefulStop},
     websocket::{send_message, Websocket},
     Lobby,
 };
 
-use log::error;
+use log::{error, warn};
 
-pub type Sender = Arc<Mutex<SplitSink<tokio_tungstenite::WebSocketStream<TcpStream>, Message>>>;
+pub type Sender = Arc<Mutex<SplitSink<tokio_tungstenite::WebSocketStream<Connection>, Message>>>;
 
 async fn handle_try_join_request(
     lobby: Addr<Lobby>,
     msg: TryJoinRequest,
+    who: SocketAddr,
     sender: Sender,
 ) -> anyhow::Result<()> {
-    let res = lobby.send(msg).await?;
+    let res = lobby
+        .send(client::TryJoinRequest { request: msg, who })
+        .await?;
 
     let msg = serde_json::to_string(&ServerNetworkMessage::TryJoinResponse(res))?;
 
@@ -70,6 +77,62 @@ async fn handle_join_request(
     Ok(())
 }
 
+async fn handle_reconnect_request(
+    lobby: Addr<Lobby>,
+    msg: ReconnectRequest,
+    sender: Sender,
+    addr: Addr<Websocket>,
+    who: SocketAddr,
+) -> anyhow::Result<()> {
+    let res = lobby
+        .send(client::ReconnectRequest {
+            player_uuid: msg.player_uuid,
+            secret: msg.secret,
+            addr,
+            who,
+        })
+        .await?;
+
+    let msg = serde_json::to_string(&ServerNetworkMessage::ReconnectResponse(res))?;
+
+    let () = send_message(sender, Message::Text(msg)).await;
+
+    Ok(())
+}
+
+/// Returns whether the teacher was logged in
+async fn handle_teacher_login_request(
+    lobby: Addr<Lobby>,
+    msg: TeacherLoginRequest,
+    sender: Sender,
+    addr: Addr<Websocket>,
+    who: SocketAddr,
+) -> anyhow::Result<bool> {
+    let res = lobby
+        .send(client::TeacherLoginRequest {
+            request: msg,
+            addr,
+            who,
+        })
+        .await?;
+
+    let logged_in = res.can_login == CanJoin::Yes;
+    let msg = serde_json::to_string(&ServerNetworkMessage::TeacherLoginResponse(res))?;
+
+    let () = send_message(sender, Message::Text(msg)).await;
+
+    Ok(logged_in)
+}
+
+async fn handle_teacher_command(lobby: Addr<Lobby>, msg: TeacherCommand) -> anyhow::Result<()> {
+    // the teacher may be a step behind the game, which is not a reason to hang up
+    if let Err(e) = lobby.send(msg).await? {
+        warn!("Command of a remote teacher failed: {e}");
+    }
+
+    Ok(())
+}
+
 impl Handler<ClientNetworkMessage> for Websocket {
     type Result = ();
 
@@ -78,7 +141,8 @@ impl Handler<ClientNetworkMessage> for Websocket {
     fn handle(&mut self, msg: ClientNetworkMessage, ctx: &mut Self::Context) -> Self::Result {
         match msg {
             ClientNetworkMessage::TryJoinRequest(msg) => {
-                if self.player_id.is_some() {
+                // the request is repeated when the player enters a PIN, but always for the same player
+                if self.teacher || self.player_id.is_some_and(|id| id != msg.uuid) {
                     error!("Player tried to cheat by sending another TryJoinRequest",);
                     ctx.notify(GracefulStop { reason: None });
                     return;
@@ -89,6 +153,7 @@ impl Handler<ClientNetworkMessage> for Websocket {
                 tokio::spawn(handle_try_join_request(
                     self.lobby_addr.clone(),
                     msg,
+                    self.who,
                     self.sender.clone(),
                 ));
             }
@@ -121,6 +186,63 @@ impl Handler<ClientNetworkMessage> for Websocket {
                     ctx.address(),
                 ));
             }
+            ClientNetworkMessage::ReconnectRequest(msg) => {
+                // a reconnecting player starts on a fresh websocket
+                if self.player_id.is_some() {
+                    error!("Player tried to cheat by sending ReconnectRequest on a used websocket");
+                    ctx.notify(GracefulStop { reason: None });
+                    return;
+                }
+
+                self.player_id = Some(msg.player_uuid);
+
+                tokio::spawn(handle_reconnect_request(
+                    self.lobby_addr.clone(),
+                    msg,
+                    self.sender.clone(),
+                    ctx.address(),
+                    self.who,
+                ));
+            }
+            ClientNetworkMessage::TeacherLoginRequest(msg) => {
+                // a player cannot become a teacher
+                if self.player_id.is_some() || self.teacher {
+                    error!(
+                        "Player tried to cheat by sending TeacherLoginRequest on a used websocket"
+                    );
+                    ctx.notify(GracefulStop { reason: None });
+                    return;
+                }
+
+                // nothing else is handled until the lobby decides, so that the commands are not rejected meanwhile
+                let login = handle_teacher_login_request(
+                    self.lobby_addr.clone(),
+                    msg,
+                    self.sender.clone(),
+                    ctx.address(),
+                    self.who,
+                );
+                ctx.wait(wrap_future(login).map(|res, websocket: &mut Self, ctx| {
+                    match res {
+                        Ok(logged_in) => websocket.teacher = logged_in,
+                        Err(e) => error!("Failed to log the teacher in: {e}"),
+                    }
+
+                    // another guess needs a new connection
+                    if !websocket.teacher {
+                        ctx.notify(GracefulStop { reason: None });
+                    }
+                }));
+            }
+            ClientNetworkMessage::TeacherCommand(msg) => {
+                if !self.teacher {
+                    error!("Player tried to cheat by sending TeacherCommand without logging in");
+                    ctx.notify(GracefulStop { reason: None });
+                    return;
+                }
+
+                tokio::spawn(handle_teacher_command(self.lobby_addr.clone(), msg));
+            }
         }
     }
 }
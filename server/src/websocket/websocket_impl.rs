@@ -3,7 +3,7 @@ use actix::{Actor, Addr, Running};
 
 use crate::messages::websocket::GracefulStop;
 use crate::Lobby;
-use common::messages::ClientNetworkMessage;
+use common::{constants::UNSUPPORTED_MESSAGE_MSG, messages::ClientNetworkMessage};
 use futures_util::stream::SplitStream;
 use futures_util::StreamExt;
 use tokio::sync::Mutex;
@@ -11,7 +11,7 @@ use tokio::sync::Mutex;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
-use tokio::net::TcpStream;
+use crate::connection::Connection;
 use tokio::task::JoinHandle;
 
 use crate::messages::websocket::{DisconnectFromLobby, HardStop};
@@ -20,11 +20,12 @@ use tungstenite::Message;
 use uuid::Uuid;
 
 use super::Sender;
-type Receiver = SplitStream<tokio_tungstenite::WebSocketStream<TcpStream>>;
+type Receiver = SplitStream<tokio_tungstenite::WebSocketStream<Connection>>;
 
 pub struct Websocket {
     pub lobby_addr: Addr<Lobby>,
     pub player_id: Option<Uuid>,
+    pub teacher: bool, // a remote teacher logged in on this websocket
     pub receiver: Option<Receiver>,
     pub sender: Sender,
     pub reader_task: Option<JoinHandle<()>>,
@@ -34,7 +35,7 @@ pub struct Websocket {
 impl Websocket {
     pub async fn new(
         lobby: Addr<Lobby>,
-        socket: TcpStream,
+        socket: Connection,
         who: SocketAddr,
     ) -> anyhow::Result<Websocket> {
         let socket = tokio_tungstenite::accept_async(socket).await?;
@@ -43,6 +44,7 @@ impl Websocket {
 
         Ok(Websocket {
             player_id: None,
+            teacher: false,
             lobby_addr: lobby,
             receiver: Some(receiver),
             sender: Arc::new(Mutex::new(sender)),
@@ -68,13 +70,16 @@ impl Actor for Websocket {
         self.reader_task = Some(reader_task);
     }
 
-    fn stopping(&mut self, _: &mut Self::Context) -> Running {
+    fn stopping(&mut self, ctx: &mut Self::Context) -> Running {
         if let Some(reader_task) = &self.reader_task {
             reader_task.abort();
         }
 
         if let Some(player_id) = self.player_id {
-            self.lobby_addr.do_send(DisconnectFromLobby { player_id });
+            self.lobby_addr.do_send(DisconnectFromLobby {
+                player_id,
+                addr: ctx.address(),
+            });
         }
 
         Running::Stop
@@ -85,8 +90,8 @@ impl Actor for Websocket {
     }
 }
 
-async fn read_messages_from_socket<'a>(
-    mut receiver: SplitStream<tokio_tungstenite::WebSocketStream<TcpStream>>,
+async fn read_messages_from_socket(
+    mut receiver: SplitStream<tokio_tungstenite::WebSocketStream<Connection>>,
     who: SocketAddr,
     addr: Addr<Websocket>,
 ) {
@@ -106,7 +111,9 @@ async fn read_messages_from_socket<'a>(
                     }
                     Err(e) => {
                         error!("Hanging up on the client bcs parsing message failed: {}", e);
-                        addr.do_send(GracefulStop { reason: None });
+                        addr.do_send(GracefulStop {
+                            reason: Some(UNSUPPORTED_MESSAGE_MSG.to_string()),
+                        });
                     }
                 }
             }
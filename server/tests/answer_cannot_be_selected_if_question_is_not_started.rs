@@ -23,8 +23,8 @@ use tungstenite::{
 };
 
 use crate::{
-    fixtures::create_server_and_teacher::create_server_and_teacher, mocks::GetServerState,
-    utils::sample_questions,
+    fixtures::create_server_and_teacher::create_server_and_teacher,
+    mocks::get_server_state_handler::GetServerState, utils::sample_questions,
 };
 
 #[rstest]
@@ -43,6 +43,8 @@ async fn answer_cannot_be_selected_if_question_is_not_started(
         player_uuid: player.uuid,
         question_index: 0,
         answers: HashSet::from([questions[0].choices[0].id]),
+        written_answer: None,
+        order: None,
     });
 
     // try to answer before the question is started
@@ -0,0 +1,84 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::time::Duration;
+
+use common::{messages::network::TeacherCommand, questions::QuestionSet};
+use rstest::rstest;
+use server::{lobby::Lobby, messages::lobby};
+
+use crate::fixtures::{create_server::create_server_from_lobby, question::question};
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn answer_details_are_sent_to_teachers() -> anyhow::Result<()> {
+    let planets = [("Jupiter", true), ("Saturn", false)];
+    let mut lobby = Lobby::new(QuestionSet::new(vec![
+        question("Which planet is the largest?", &planets),
+        question("Which planet is the largest?", &planets),
+    ]));
+    lobby.teacher_token = utils::TEACHER_TOKEN.to_string();
+    let (server_thread, server) = create_server_from_lobby(lobby);
+
+    let (mut teacher_sender, mut teacher_receiver) = utils::login_teacher_and_drain().await?;
+
+    let (mut fst_sender, mut fst_receiver, fst_player) = utils::join_new_player().await?;
+    utils::receive_players_update(&mut teacher_receiver).await?;
+    let (_snd_sender, mut snd_receiver, snd_player) = utils::join_new_player().await?;
+    utils::receive_players_update(&mut teacher_receiver).await?;
+    utils::receive_players_update(&mut fst_receiver).await?;
+
+    utils::send_teacher_command(&mut teacher_sender, TeacherCommand::StartQuestion).await?;
+    let question = utils::receive_next_question(&mut fst_receiver).await?;
+    utils::receive_next_question(&mut snd_receiver).await?;
+    utils::receive_next_question(&mut teacher_receiver).await?;
+    utils::receive_question_list(&mut teacher_receiver).await?;
+
+    // only the first player answers, and right
+    utils::send_question_answer(&mut fst_sender, &fst_player, &question.question, 0, vec![0])
+        .await?;
+    utils::receive_question_update(&mut fst_receiver).await?;
+    utils::receive_question_update(&mut snd_receiver).await?;
+    utils::receive_question_update(&mut teacher_receiver).await?;
+    utils::receive_answer_distribution(&mut teacher_receiver).await?;
+
+    utils::send_teacher_command(
+        &mut teacher_sender,
+        TeacherCommand::EndQuestion { index: 0 },
+    )
+    .await?;
+    utils::receive_question_ended(&mut teacher_receiver).await?;
+
+    let details = utils::receive_answer_details(&mut teacher_receiver).await?;
+    assert_eq!(details.question_index, 0);
+    assert_eq!(details.players.len(), 2);
+
+    let answered = &details.players[0];
+    assert_eq!(answered.player.uuid, fst_player.uuid);
+    assert_eq!(answered.chosen_answers, vec!["Jupiter".to_string()]);
+    assert!(answered.correct);
+    assert_eq!(answered.answer_order, Some(1));
+    assert!(answered.response_time_ms.is_some());
+    assert!(answered.points > 0);
+
+    // the players who did not answer are listed last
+    let missing = &details.players[1];
+    assert_eq!(missing.player.uuid, snd_player.uuid);
+    assert!(missing.chosen_answers.is_empty());
+    assert_eq!(missing.answer_order, None);
+    assert_eq!(missing.points, 0);
+
+    // the players only get their own answer -- and then the leaderboard
+    utils::receive_question_ended(&mut fst_receiver).await?;
+    utils::receive_question_ended(&mut snd_receiver).await?;
+    utils::send_teacher_command(&mut teacher_sender, TeacherCommand::SwitchToLeaderboard).await?;
+    utils::receive_show_leaderboard(&mut fst_receiver).await?;
+    utils::receive_show_leaderboard(&mut snd_receiver).await?;
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::time::Duration;
+
+use common::{messages::network::TeacherCommand, questions::QuestionSet};
+use rstest::rstest;
+use server::{lobby::Lobby, messages::lobby};
+
+use crate::fixtures::{create_server::create_server_from_lobby, question::question};
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn answer_distribution_is_sent_to_teachers() -> anyhow::Result<()> {
+    let question = question(
+        "Which planet is the largest?",
+        &[("Jupiter", true), ("Saturn", false), ("Mars", false)],
+    );
+    let jupiter = question.choices[0].id;
+    let saturn = question.choices[1].id;
+
+    let mut lobby = Lobby::new(QuestionSet::new(vec![question]));
+    lobby.teacher_token = utils::TEACHER_TOKEN.to_string();
+    let (server_thread, server) = create_server_from_lobby(lobby);
+
+    let (mut teacher_sender, mut teacher_receiver) = utils::login_teacher_and_drain().await?;
+
+    let (mut fst_sender, mut fst_receiver, fst_player) = utils::join_new_player().await?;
+    utils::receive_players_update(&mut teacher_receiver).await?;
+    let (mut snd_sender, mut snd_receiver, snd_player) = utils::join_new_player().await?;
+    utils::receive_players_update(&mut teacher_receiver).await?;
+    utils::receive_players_update(&mut fst_receiver).await?;
+
+    utils::send_teacher_command(&mut teacher_sender, TeacherCommand::StartQuestion).await?;
+    let question = utils::receive_next_question(&mut fst_receiver).await?;
+    utils::receive_next_question(&mut snd_receiver).await?;
+    utils::receive_next_question(&mut teacher_receiver).await?;
+    utils::receive_question_list(&mut teacher_receiver).await?;
+
+    // the teacher sees which choice the first player picked
+    utils::send_question_answer(&mut fst_sender, &fst_player, &question.question, 0, vec![0])
+        .await?;
+    utils::receive_question_update(&mut fst_receiver).await?;
+    utils::receive_question_update(&mut snd_receiver).await?;
+    utils::receive_question_update(&mut teacher_receiver).await?;
+
+    let distribution = utils::receive_answer_distribution(&mut teacher_receiver).await?;
+    assert_eq!(distribution.question_index, 0);
+    assert_eq!(distribution.stats[&jupiter].players_answered_count, 1);
+    assert_eq!(distribution.stats[&saturn].players_answered_count, 0);
+
+    // a teacher logging in during the question gets the answers so far as well
+    let (_late_sender, mut late_receiver) = utils::login_teacher_and_drain().await?;
+    utils::receive_next_question(&mut late_receiver).await?;
+    utils::receive_pause_update(&mut late_receiver).await?;
+    assert_eq!(
+        utils::receive_answer_distribution(&mut late_receiver).await?,
+        distribution
+    );
+
+    // the players never get it -- the next thing they hear of is the end of the question
+    utils::send_question_answer(&mut snd_sender, &snd_player, &question.question, 0, vec![1])
+        .await?;
+    let ended = utils::receive_question_ended(&mut fst_receiver).await?;
+    utils::receive_question_ended(&mut snd_receiver).await?;
+    assert_eq!(ended.stats[&saturn].players_answered_count, 1);
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    Ok(())
+}
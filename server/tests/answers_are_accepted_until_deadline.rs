@@ -0,0 +1,100 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::time::Duration;
+use std::vec;
+
+use chrono::Utc;
+use common::questions::{Choice, Question, QuestionSet, Scoring};
+
+use rstest::rstest;
+use server::{
+    lobby::{Lobby, Phase},
+    messages::lobby::{self, StartQuestion},
+};
+
+use uuid::Uuid;
+
+use crate::fixtures::{
+    create_server::create_server_from_lobby, create_server_and_teacher::create_server_and_teacher,
+};
+use crate::mocks::get_server_state_handler::GetServerState;
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(15))]
+async fn answers_are_accepted_until_deadline() -> anyhow::Result<()> {
+    let mut lobby = Lobby::new(QuestionSet {
+        quiz_name: "test".to_string(),
+        randomize_answers: false,
+        randomize_questions: false,
+        scoring: Scoring::Classic,
+        questions: vec![Question {
+            choices: vec![Choice {
+                id: Uuid::new_v4(),
+                is_correct: true,
+                text: "right".to_string(),
+            }],
+            code_block: None,
+            text: "question".to_string(),
+            time_seconds: 1,
+            is_multichoice: false,
+            ..Default::default()
+        }],
+    });
+    lobby.answer_grace = Duration::from_millis(1500);
+
+    let (server_thread, server, teacher_thread, teacher) =
+        create_server_and_teacher(create_server_from_lobby(lobby));
+
+    // the server tells its time, so that the client can estimate how far its clock is off
+    let (mut sender, mut receiver) = utils::connect_to_server().await;
+    let (id, response) = utils::try_join_server(&mut sender, &mut receiver).await?;
+    let server_time = response
+        .server_time
+        .expect("The server did not send its time");
+    assert!((Utc::now() - server_time).num_seconds().abs() < 2);
+
+    let (fst_player, _) = utils::join_server(&mut sender, &mut receiver, id).await?;
+    let (_snd_sender, mut snd_receiver, _snd_player) = utils::join_new_player().await?;
+    utils::receive_players_update(&mut receiver).await?;
+
+    server.send(StartQuestion).await??;
+    let question = utils::receive_next_question(&mut receiver).await?;
+    utils::receive_next_question(&mut snd_receiver).await?;
+
+    // the instants are on the clock of the server, which is ours here
+    let choices_visible_at = question
+        .choices_visible_at
+        .expect("Missing choices_visible_at");
+    let deadline = question.deadline.expect("Missing deadline");
+    assert_eq!((deadline - choices_visible_at).num_seconds(), 1);
+    let until_choices = (choices_visible_at - Utc::now()).num_milliseconds();
+    assert!((until_choices - i64::try_from(question.show_choices_after * 1000)?).abs() < 1000);
+
+    // an answer that arrives a bit after the deadline still counts
+    let after_deadline = deadline + chrono::Duration::milliseconds(300) - Utc::now();
+    tokio::time::sleep(after_deadline.to_std()?).await;
+
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.phase, Phase::ActiveQuestion(0));
+
+    utils::send_question_answer(&mut sender, &fst_player, &question.question, 0, vec![0]).await?;
+    utils::receive_question_update(&mut receiver).await?;
+    utils::receive_question_update(&mut snd_receiver).await?;
+
+    // the other player does not answer, so the question ends once the grace window is over
+    let ended = utils::receive_question_ended(&mut receiver).await?;
+    assert!(ended.player_answer.is_some());
+    assert!(Utc::now() - deadline >= chrono::Duration::milliseconds(1000));
+    utils::receive_question_ended(&mut snd_receiver).await?;
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    Ok(())
+}
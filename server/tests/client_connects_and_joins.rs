@@ -6,8 +6,8 @@ use std::{thread::JoinHandle, time::Duration};
 
 use actix::Addr;
 use common::{
-    constants::DEFAULT_QUIZ_NAME,
-    messages::network::{CanJoin, JoinResponse, TryJoinResponse},
+    constants::{DEFAULT_QUIZ_NAME, PROTOCOL_VERSION, SUPPORTED_FEATURES},
+    messages::network::{CanJoin, GameSnapshot, JoinResponse, TryJoinResponse},
 };
 use rstest::rstest;
 use server::{
@@ -17,7 +17,8 @@ use server::{
 };
 
 use crate::{
-    fixtures::create_server_and_teacher::create_server_and_teacher, mocks::GetServerState,
+    fixtures::create_server_and_teacher::create_server_and_teacher,
+    mocks::get_server_state_handler::GetServerState,
 };
 
 #[rstest]
@@ -37,6 +38,9 @@ async fn client_connects_and_joins(
             can_join: CanJoin::Yes,
             uuid: id,
             quiz_name: DEFAULT_QUIZ_NAME.to_string(),
+            protocol_version: PROTOCOL_VERSION,
+            features: SUPPORTED_FEATURES.to_vec(),
+            server_time: msg.server_time,
         }
     );
 
@@ -51,6 +55,10 @@ async fn client_connects_and_joins(
 
     let (player_data, msg) = utils::join_server(&mut sender, &mut receiver, id).await?;
 
+    let state = server.send(GetServerState).await?;
+    let secret = state.joined_players[&id].reconnect_secret.clone();
+    assert!(!secret.is_empty());
+
     assert_eq!(
         msg,
         JoinResponse {
@@ -58,6 +66,8 @@ async fn client_connects_and_joins(
             can_join: CanJoin::Yes,
             uuid: id,
             quiz_name: DEFAULT_QUIZ_NAME.to_string(),
+            snapshot: GameSnapshot::WaitingForGame,
+            reconnect_secret: Some(secret),
         }
     );
 
@@ -8,12 +8,43 @@ use ::server::lobby::{run_server, Lobby};
 use actix::Addr;
 use common::{constants::DEFAULT_PORT, questions::QuestionSet};
 use rstest::fixture;
+use tokio_rustls::TlsAcceptor;
 
 /// A fixture that starts a server thread and returns the join handle and the lobby address.
 /// Fixture is run every time is is requested, do not build other fixtures on top of this one (anti-example below)
 #[must_use]
 #[fixture]
 pub fn create_server(sample_questions: QuestionSet) -> (JoinHandle<()>, Addr<Lobby>) {
+    create_server_from_lobby(Lobby::new(sample_questions))
+}
+
+/// Starts a server thread running the given `lobby` and returns the join handle and the lobby address.
+#[must_use]
+pub fn create_server_from_lobby(lobby: Lobby) -> (JoinHandle<()>, Addr<Lobby>) {
+    create_server_with_tls(lobby, None)
+}
+
+/// Starts a server thread running the given `lobby`, encrypting connections with `tls` if given.
+#[must_use]
+pub fn create_server_with_tls(
+    lobby: Lobby,
+    tls: Option<TlsAcceptor>,
+) -> (JoinHandle<()>, Addr<Lobby>) {
+    start_server(lobby, tls, false)
+}
+
+/// Starts a server thread running the given `lobby`, which announces itself on the local network.
+#[must_use]
+#[allow(dead_code)]
+pub fn create_discoverable_server(lobby: Lobby) -> (JoinHandle<()>, Addr<Lobby>) {
+    start_server(lobby, None, true)
+}
+
+fn start_server(
+    lobby: Lobby,
+    tls: Option<TlsAcceptor>,
+    discoverable: bool,
+) -> (JoinHandle<()>, Addr<Lobby>) {
     assert!(
         utils::is_port_available(DEFAULT_PORT),
         "Port {DEFAULT_PORT} is not available"
@@ -25,7 +56,7 @@ pub fn create_server(sample_questions: QuestionSet) -> (JoinHandle<()>, Addr<Lob
         .expect("Failed to parse address");
 
     let server_thread = thread::spawn(move || {
-        run_server(tx, sample_questions, addr).expect("Failed to run server");
+        run_server(tx, lobby, addr, tls, discoverable).expect("Failed to run server");
     });
 
     let server = rx.recv().expect("Failed to receive server address");
@@ -24,8 +24,14 @@ pub fn create_server_and_teacher(
 
     let server_address = server.clone();
     let teacher_thread = thread::spawn(move || {
-        run_teacher(server_address, tx, DEFAULT_QUIZ_NAME, Theme::default())
-            .expect("Failed to run teacher");
+        run_teacher(
+            server_address,
+            tx,
+            DEFAULT_QUIZ_NAME,
+            None,
+            Theme::default(),
+        )
+        .expect("Failed to run teacher");
     });
 
     let teacher = rx.recv().expect("Failed to receive teacher address");
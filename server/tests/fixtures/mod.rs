@@ -1,7 +1,4 @@
 pub mod create_server;
 pub mod create_server_and_teacher;
+pub mod question;
 pub mod sample_questions;
-
-pub use create_server::*;
-pub use create_server_and_teacher::*;
-pub use sample_questions::*;
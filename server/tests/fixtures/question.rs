@@ -0,0 +1,21 @@
+use common::questions::{Choice, Question};
+use uuid::Uuid;
+
+/// Returns a single choice question with the `choices` given by their text and whether they are right.
+#[must_use]
+#[allow(dead_code)]
+pub fn question(text: &str, choices: &[(&str, bool)]) -> Question {
+    Question {
+        text: text.to_string(),
+        time_seconds: 30,
+        choices: choices
+            .iter()
+            .map(|(text, is_correct)| Choice {
+                id: Uuid::new_v4(),
+                text: (*text).to_string(),
+                is_correct: *is_correct,
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
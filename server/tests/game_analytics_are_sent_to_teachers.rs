@@ -0,0 +1,120 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::time::Duration;
+
+use common::{
+    messages::{network::TeacherCommand, ServerNetworkMessage},
+    questions::QuestionSet,
+};
+use rstest::rstest;
+use server::{lobby::Lobby, messages::lobby};
+
+use crate::fixtures::{create_server::create_server_from_lobby, question::question};
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn game_analytics_are_sent_to_teachers() -> anyhow::Result<()> {
+    let planets = [("Jupiter", true), ("Saturn", false), ("Mars", false)];
+    let mut lobby = Lobby::new(QuestionSet::new(vec![
+        question("Which planet is the largest?", &planets),
+        question("Which planet has the most moons?", &planets),
+    ]));
+    lobby.teacher_token = utils::TEACHER_TOKEN.to_string();
+    let (server_thread, server) = create_server_from_lobby(lobby);
+
+    let (mut teacher_sender, mut teacher_receiver) = utils::login_teacher_and_drain().await?;
+
+    let (mut fst_sender, mut fst_receiver, fst_player) = utils::join_new_player().await?;
+    utils::receive_players_update(&mut teacher_receiver).await?;
+    let (mut snd_sender, mut snd_receiver, snd_player) = utils::join_new_player().await?;
+    utils::receive_players_update(&mut teacher_receiver).await?;
+    utils::receive_players_update(&mut fst_receiver).await?;
+
+    // the first question: the first player is right, the second one picks Saturn
+    utils::send_teacher_command(&mut teacher_sender, TeacherCommand::StartQuestion).await?;
+    let question = utils::receive_next_question(&mut fst_receiver).await?;
+    utils::receive_next_question(&mut snd_receiver).await?;
+
+    utils::send_question_answer(&mut fst_sender, &fst_player, &question.question, 0, vec![0])
+        .await?;
+    utils::receive_question_update(&mut fst_receiver).await?;
+    utils::receive_question_update(&mut snd_receiver).await?;
+    utils::send_question_answer(&mut snd_sender, &snd_player, &question.question, 0, vec![1])
+        .await?;
+    utils::receive_question_ended(&mut fst_receiver).await?;
+    utils::receive_question_ended(&mut snd_receiver).await?;
+
+    utils::send_teacher_command(&mut teacher_sender, TeacherCommand::SwitchToLeaderboard).await?;
+    utils::receive_show_leaderboard(&mut fst_receiver).await?;
+    utils::receive_show_leaderboard(&mut snd_receiver).await?;
+
+    // the second question: the first player picks Mars, the second one does not answer
+    utils::send_teacher_command(&mut teacher_sender, TeacherCommand::StartQuestion).await?;
+    let question = utils::receive_next_question(&mut fst_receiver).await?;
+    utils::receive_next_question(&mut snd_receiver).await?;
+
+    utils::send_question_answer(&mut fst_sender, &fst_player, &question.question, 1, vec![2])
+        .await?;
+    utils::receive_question_update(&mut fst_receiver).await?;
+    utils::receive_question_update(&mut snd_receiver).await?;
+
+    utils::send_teacher_command(
+        &mut teacher_sender,
+        TeacherCommand::EndQuestion { index: 1 },
+    )
+    .await?;
+    utils::receive_question_ended(&mut fst_receiver).await?;
+    utils::send_teacher_command(&mut teacher_sender, TeacherCommand::SwitchToLeaderboard).await?;
+    assert!(
+        utils::receive_show_leaderboard(&mut fst_receiver)
+            .await?
+            .was_final_round
+    );
+
+    // the teacher gets the summary right after the final leaderboard
+    let analytics = loop {
+        if let ServerNetworkMessage::GameAnalytics(analytics) =
+            utils::receive_server_network_msg(&mut teacher_receiver).await?
+        {
+            break analytics;
+        }
+    };
+
+    assert_eq!(analytics.questions.len(), 2);
+
+    let first = &analytics.questions[0];
+    assert_eq!(first.percent_correct, 50);
+    let distractor = first.distractor.as_ref().expect("Saturn was picked");
+    assert_eq!(
+        (distractor.answer.as_str(), distractor.count),
+        ("Saturn", 1)
+    );
+    assert!(first.median_answer_time_ms.is_some());
+
+    let second = &analytics.questions[1];
+    assert_eq!(second.percent_correct, 0);
+    assert_eq!(
+        second.distractor.as_ref().map(|d| d.answer.as_str()),
+        Some("Mars")
+    );
+
+    let player = |nickname: &str| {
+        analytics
+            .players
+            .iter()
+            .find(|player| player.nickname == nickname)
+            .expect("Player is in the summary")
+    };
+    assert_eq!(player(&fst_player.nickname).accuracy_percent, 50);
+    assert_eq!(player(&fst_player.nickname).missed_questions, vec![1]);
+    assert_eq!(player(&snd_player.nickname).accuracy_percent, 0);
+    assert_eq!(player(&snd_player.nickname).missed_questions, vec![0, 1]);
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::{fs, time::Duration};
+
+use common::messages::network::{CanJoin, GameSnapshot};
+use rstest::rstest;
+use server::{
+    lobby::{Lobby, Phase},
+    messages::lobby::{self, PauseQuestion, ResumeQuestion, StartQuestion, SwitchToLeaderboard},
+};
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{
+        create_server::create_server_from_lobby,
+        create_server_and_teacher::create_server_and_teacher,
+    },
+    mocks::get_server_state_handler::GetServerState,
+};
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn game_can_be_resumed() -> anyhow::Result<()> {
+    let snapshot_file =
+        std::env::temp_dir().join(format!("clihoot_snapshot_{}.json", Uuid::new_v4()));
+
+    // a game with two questions in random order
+    let mut questions = utils::sample_questions();
+    let mut second_question = questions[0].clone();
+    second_question.text = "Is this the second question?".to_string();
+    for choice in &mut second_question.choices {
+        choice.id = Uuid::new_v4();
+    }
+    questions.push(second_question);
+    questions.randomize_questions = true;
+    questions.randomize_answers = true;
+
+    let mut lobby = Lobby::new(questions);
+    lobby.snapshot_file = Some(snapshot_file.clone());
+
+    let (server_thread, server, teacher_thread, teacher) =
+        create_server_and_teacher(create_server_from_lobby(lobby));
+
+    let (mut sender, mut receiver, player) = utils::join_new_player().await?;
+
+    // play the first question
+    server.send(StartQuestion).await??;
+    let question = utils::receive_next_question(&mut receiver).await?;
+    utils::send_question_answer(&mut sender, &player, &question.question, 0, vec![0]).await?;
+    utils::receive_question_ended(&mut receiver).await?;
+
+    server.send(SwitchToLeaderboard).await??;
+    let leaderboard = utils::receive_show_leaderboard(&mut receiver).await?;
+
+    let state_before = server.send(GetServerState).await?;
+    assert_eq!(state_before.phase, Phase::ShowingLeaderboard(0));
+    let secret = state_before.joined_players[&player.uuid]
+        .reconnect_secret
+        .clone();
+
+    // the server goes down
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    // and is started again from the snapshot
+    let lobby = Lobby::from_snapshot(&snapshot_file)?;
+    let (server_thread, server, teacher_thread, teacher) =
+        create_server_and_teacher(create_server_from_lobby(lobby));
+
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.phase, state_before.phase);
+    assert_eq!(state.questions.questions, state_before.questions.questions);
+    assert_eq!(state.results, state_before.results);
+    assert!(state.joined_players.is_empty());
+    assert!(state.disconnected_players.contains_key(&player.uuid));
+
+    // the player gets their score back after reconnecting
+    let (_sender, _receiver, response) = utils::reconnect_to_server(player.uuid, &secret).await?;
+
+    assert_eq!(response.can_reconnect, CanJoin::Yes);
+    assert_eq!(response.player, Some(player));
+    assert_eq!(response.snapshot, GameSnapshot::Leaderboard(leaderboard));
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    fs::remove_file(snapshot_file)?;
+
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn paused_question_stays_paused_after_resuming_game() -> anyhow::Result<()> {
+    let snapshot_file =
+        std::env::temp_dir().join(format!("clihoot_snapshot_{}.json", Uuid::new_v4()));
+
+    let mut lobby = Lobby::new(utils::sample_questions());
+    lobby.snapshot_file = Some(snapshot_file.clone());
+
+    let (server_thread, server, teacher_thread, teacher) =
+        create_server_and_teacher(create_server_from_lobby(lobby));
+
+    let (_sender, mut receiver, _player) = utils::join_new_player().await?;
+
+    server.send(StartQuestion).await??;
+    utils::receive_next_question(&mut receiver).await?;
+    server.send(PauseQuestion).await??;
+
+    let state_before = server.send(GetServerState).await?;
+    assert!(state_before.paused_at.is_some());
+
+    // the server goes down while the question is paused
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    let mut lobby = Lobby::from_snapshot(&snapshot_file)?;
+    lobby.snapshot_file = Some(snapshot_file.clone());
+    let (server_thread, server, teacher_thread, teacher) =
+        create_server_and_teacher(create_server_from_lobby(lobby));
+
+    // the time the server was down does not count into the time of the question
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.phase, Phase::ActiveQuestion(0));
+    assert_eq!(state.paused_at, state_before.paused_at);
+    assert_eq!(
+        state.question_start_times,
+        state_before.question_start_times
+    );
+
+    // and the pause is saved as well
+    server.send(ResumeQuestion).await??;
+    let lobby = Lobby::from_snapshot(&snapshot_file)?;
+    assert!(lobby.paused_at.is_none());
+    assert_ne!(
+        lobby.question_start_times,
+        state_before.question_start_times
+    );
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    fs::remove_file(snapshot_file)?;
+
+    Ok(())
+}
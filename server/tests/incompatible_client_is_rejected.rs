@@ -0,0 +1,77 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::{thread::JoinHandle, time::Duration};
+
+use actix::Addr;
+use common::{
+    constants::{INCOMPATIBLE_CLIENT_MSG, LOBBY_LOCKED_MSG, UNSUPPORTED_MESSAGE_MSG},
+    messages::{network::CanJoin, ServerNetworkMessage},
+};
+use futures_util::{SinkExt, StreamExt};
+use rstest::rstest;
+use server::{messages::lobby, Lobby};
+use tungstenite::Message;
+use uuid::Uuid;
+
+use crate::{
+    fixtures::create_server::create_server, mocks::get_server_state_handler::GetServerState,
+};
+
+async fn try_join_raw(request: String) -> anyhow::Result<CanJoin> {
+    let (mut sender, mut receiver) = utils::connect_to_server().await;
+    sender.send(Message::Text(request)).await?;
+
+    let ServerNetworkMessage::TryJoinResponse(response) =
+        utils::receive_server_network_msg(&mut receiver).await?
+    else {
+        anyhow::bail!("Expected TryJoinResponse");
+    };
+
+    Ok(response.can_join)
+}
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn incompatible_client_is_rejected(
+    create_server: (JoinHandle<()>, Addr<Lobby>),
+) -> anyhow::Result<()> {
+    let (server_thread, server) = create_server;
+
+    // a client from before the handshake sends just its uuid
+    let uuid = Uuid::new_v4();
+    let can_join = try_join_raw(format!(r#"{{"TryJoinRequest":{{"uuid":"{uuid}"}}}}"#)).await?;
+    let CanJoin::No(reason) = can_join else {
+        anyhow::bail!("Old client was allowed to join");
+    };
+    assert!(reason.starts_with(INCOMPATIBLE_CLIENT_MSG));
+
+    let state = server.send(GetServerState).await?;
+    assert!(state.waiting_players.is_empty());
+
+    // features the server does not know are fine, it is the locked lobby which stops this client
+    let uuid = Uuid::new_v4();
+    let can_join = try_join_raw(format!(
+        r#"{{"TryJoinRequest":{{"uuid":"{uuid}","protocol_version":1,"features":["reconnect","teleport"]}}}}"#
+    ))
+    .await?;
+    assert_eq!(can_join, CanJoin::No(LOBBY_LOCKED_MSG.to_string()));
+
+    // a message the server does not understand is answered with a reason before hanging up
+    let (mut sender, mut receiver) = utils::connect_to_server().await;
+    sender
+        .send(Message::Text(r#"{"Teleport":{}}"#.to_string()))
+        .await?;
+
+    let Some(Ok(Message::Close(Some(frame)))) = receiver.next().await else {
+        anyhow::bail!("Expected a close frame");
+    };
+    assert_eq!(frame.reason, UNSUPPORTED_MESSAGE_MSG);
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    Ok(())
+}
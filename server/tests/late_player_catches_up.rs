@@ -0,0 +1,104 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::{thread::JoinHandle, time::Duration};
+
+use actix::Addr;
+
+use anyhow::bail;
+use common::messages::network::{CanJoin, GameSnapshot};
+use rstest::rstest;
+use server::{
+    lobby::Lobby,
+    messages::lobby::{self, EndQuestion, StartQuestion, SwitchToLeaderboard},
+    Teacher,
+};
+
+use crate::{
+    fixtures::create_server_and_teacher::create_server_and_teacher,
+    mocks::get_server_state_handler::GetServerState,
+};
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn late_player_catches_up(
+    create_server_and_teacher: (JoinHandle<()>, Addr<Lobby>, JoinHandle<()>, Addr<Teacher>),
+) -> anyhow::Result<()> {
+    let (server_thread, server, teacher_thread, teacher) = create_server_and_teacher;
+
+    let (_fst_sender, mut fst_receiver, fst_data) = utils::join_new_player().await?;
+
+    server.send(StartQuestion).await??;
+    utils::receive_next_question(&mut fst_receiver).await?;
+
+    // the second player joins during the question and gets it right away
+    let (mut snd_sender, mut snd_receiver) = utils::connect_to_server().await;
+    let (id, _) = utils::try_join_server(&mut snd_sender, &mut snd_receiver).await?;
+    let (snd_data, response) = utils::join_server(&mut snd_sender, &mut snd_receiver, id).await?;
+    assert_eq!(response.can_join, CanJoin::Yes);
+
+    let GameSnapshot::Question {
+        question,
+        player_answer,
+        ..
+    } = response.snapshot
+    else {
+        bail!("Expected the current question in the snapshot");
+    };
+    assert_eq!(question.question_index, 0);
+    assert_eq!(player_answer, None);
+
+    // the others can tell the new player joined late
+    let update = utils::receive_players_update(&mut fst_receiver).await?;
+    assert_eq!(update.players.len(), 2);
+    assert_eq!(update.late_joiners, vec![snd_data.uuid]);
+
+    // and the late player can still answer
+    utils::send_question_answer(&mut snd_sender, &snd_data, &question.question, 0, vec![1]).await?;
+    let update = utils::receive_question_update(&mut fst_receiver).await?;
+    assert_eq!(update.players_answered_count, 1);
+
+    server.send(EndQuestion { index: 0 }).await??;
+    server.send(SwitchToLeaderboard).await??;
+    utils::receive_question_ended(&mut fst_receiver).await?;
+    utils::receive_show_leaderboard(&mut fst_receiver).await?;
+
+    // the third player joins on the leaderboard, having missed the whole question
+    let (mut thd_sender, mut thd_receiver) = utils::connect_to_server().await;
+    let (id, _) = utils::try_join_server(&mut thd_sender, &mut thd_receiver).await?;
+    let (thd_data, response) = utils::join_server(&mut thd_sender, &mut thd_receiver, id).await?;
+    assert_eq!(response.can_join, CanJoin::Yes);
+
+    let GameSnapshot::Leaderboard(leaderboard) = response.snapshot else {
+        bail!("Expected the leaderboard in the snapshot");
+    };
+    assert!(leaderboard
+        .players
+        .iter()
+        .any(|(player, score)| player.uuid == thd_data.uuid && *score == 0));
+
+    let state = server.send(GetServerState).await?;
+    assert!(!state.joined_players[&fst_data.uuid].joined_late);
+    assert!(state.joined_players[&thd_data.uuid].joined_late);
+    assert_eq!(state.get_late_joiners(), vec![snd_data.uuid, thd_data.uuid]);
+
+    let report = state.get_report();
+    let thd_report = report
+        .players
+        .iter()
+        .find(|player| player.uuid == thd_data.uuid)
+        .expect("The late player is in the report");
+    assert!(thd_report.joined_late);
+    assert_eq!(thd_report.total_points, 0);
+    assert_eq!(thd_report.answers[0].answer_order, None);
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    Ok(())
+}
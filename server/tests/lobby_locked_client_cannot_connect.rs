@@ -6,7 +6,7 @@ use std::{thread::JoinHandle, time::Duration};
 
 use actix::Addr;
 use common::{
-    constants::{DEFAULT_QUIZ_NAME, LOBBY_LOCKED_MSG},
+    constants::{DEFAULT_QUIZ_NAME, LOBBY_LOCKED_MSG, PROTOCOL_VERSION, SUPPORTED_FEATURES},
     messages::network::{CanJoin, TryJoinResponse},
 };
 use rstest::rstest;
@@ -35,6 +35,9 @@ async fn lobby_locked_client_cannot_connect(
             can_join: CanJoin::No(LOBBY_LOCKED_MSG.to_string()),
             uuid: id,
             quiz_name: DEFAULT_QUIZ_NAME.to_string(),
+            protocol_version: PROTOCOL_VERSION,
+            features: SUPPORTED_FEATURES.to_vec(),
+            server_time: msg.server_time,
         }
     );
 
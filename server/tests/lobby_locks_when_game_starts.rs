@@ -0,0 +1,87 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::time::Duration;
+
+use common::{
+    constants::LOBBY_LOCKED_MSG,
+    messages::network::{CanJoin, TeacherCommand},
+};
+use rstest::rstest;
+use server::{lobby::Lobby, messages::lobby};
+
+use crate::{
+    fixtures::create_server::create_server_from_lobby,
+    mocks::get_server_state_handler::GetServerState,
+};
+
+const TOKEN: &str = "secret-token";
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn lobby_locks_when_game_starts() -> anyhow::Result<()> {
+    let mut lobby = Lobby::new(utils::sample_questions());
+    lobby.teacher_token = TOKEN.to_string();
+    lobby.lock_on_start = true;
+
+    let (server_thread, server) = create_server_from_lobby(lobby);
+
+    // a remote teacher sees the same lock indicator as the one on the server
+    let (mut teacher_sender, mut teacher_receiver) = utils::connect_to_server().await;
+    let response = utils::login_teacher(&mut teacher_sender, &mut teacher_receiver, TOKEN).await?;
+    assert_eq!(response.can_login, CanJoin::Yes);
+    utils::receive_players_update(&mut teacher_receiver).await?;
+    assert!(
+        !utils::receive_lock_update(&mut teacher_receiver)
+            .await?
+            .locked
+    );
+    utils::receive_question_list(&mut teacher_receiver).await?;
+
+    let (_player_sender, _player_receiver, _player) = utils::join_new_player().await?;
+    utils::receive_players_update(&mut teacher_receiver).await?;
+
+    // this player is still choosing a name when the game starts
+    let (mut late_sender, mut late_receiver) = utils::connect_to_server().await;
+    let (late_uuid, response) =
+        utils::try_join_server(&mut late_sender, &mut late_receiver).await?;
+    assert_eq!(response.can_join, CanJoin::Yes);
+
+    utils::send_teacher_command(&mut teacher_sender, TeacherCommand::StartQuestion).await?;
+    assert!(
+        utils::receive_lock_update(&mut teacher_receiver)
+            .await?
+            .locked
+    );
+    utils::receive_next_question(&mut teacher_receiver).await?;
+    utils::receive_question_list(&mut teacher_receiver).await?;
+
+    let (_, response) = utils::join_server(&mut late_sender, &mut late_receiver, late_uuid).await?;
+    assert_eq!(response.can_join, CanJoin::No(LOBBY_LOCKED_MSG.to_string()));
+
+    // the teacher lets the player in after all
+    utils::send_teacher_command(
+        &mut teacher_sender,
+        TeacherCommand::SetLock { locked: false },
+    )
+    .await?;
+    assert!(
+        !utils::receive_lock_update(&mut teacher_receiver)
+            .await?
+            .locked
+    );
+
+    let (_, response) = utils::join_server(&mut late_sender, &mut late_receiver, late_uuid).await?;
+    assert_eq!(response.can_join, CanJoin::Yes);
+
+    let state = server.send(GetServerState).await?;
+    assert!(!state.locked);
+    assert_eq!(state.joined_players.len(), 2);
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    Ok(())
+}
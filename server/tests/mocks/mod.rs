@@ -1,3 +1 @@
-mod get_server_state_handler;
-
-pub use get_server_state_handler::*;
+pub mod get_server_state_handler;
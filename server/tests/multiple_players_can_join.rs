@@ -13,7 +13,8 @@ use server::{
 };
 
 use crate::{
-    fixtures::create_server_and_teacher::create_server_and_teacher, mocks::GetServerState,
+    fixtures::create_server_and_teacher::create_server_and_teacher,
+    mocks::get_server_state_handler::GetServerState,
 };
 
 #[rstest]
@@ -19,8 +19,8 @@ use server::{
 };
 
 use crate::{
-    fixtures::create_server_and_teacher::create_server_and_teacher, mocks::GetServerState,
-    utils::sample_questions,
+    fixtures::create_server_and_teacher::create_server_and_teacher,
+    mocks::get_server_state_handler::GetServerState, utils::sample_questions,
 };
 
 #[rstest]
@@ -0,0 +1,90 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::{collections::HashSet, time::Duration};
+
+use common::questions::{Choice, Question, QuestionSet, QuestionType};
+use rstest::rstest;
+use server::messages::lobby::{self, StartQuestion};
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{
+        create_server::create_server, create_server_and_teacher::create_server_and_teacher,
+    },
+    mocks::get_server_state_handler::GetServerState,
+};
+
+fn ordering_question() -> Question {
+    Question {
+        kind: QuestionType::Ordering,
+        text: "Order the planets by their distance from the Sun".to_string(),
+        time_seconds: 30,
+        choices: ["Mercury", "Venus", "Earth", "Mars"]
+            .iter()
+            .map(|planet| Choice {
+                id: Uuid::new_v4(),
+                text: (*planet).to_string(),
+                is_correct: false,
+            })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn ordering_answers_are_graded() -> anyhow::Result<()> {
+    let question = ordering_question();
+    let right_order: Vec<_> = question.choices.iter().map(|choice| choice.id).collect();
+
+    let (server_thread, server, teacher_thread, teacher) =
+        create_server_and_teacher(create_server(QuestionSet::new(vec![question])));
+
+    let (mut fst_sender, mut fst_receiver, fst_player) = utils::join_new_player().await?;
+    let (mut snd_sender, mut snd_receiver, snd_player) = utils::join_new_player().await?;
+    let _fst_players_update = utils::receive_players_update(&mut fst_receiver).await?;
+
+    server.send(StartQuestion).await??;
+    let fst_question = utils::receive_next_question(&mut fst_receiver).await?;
+    let snd_question = utils::receive_next_question(&mut snd_receiver).await?;
+
+    // both players get all the items, but never in the right order
+    for question in [&fst_question, &snd_question] {
+        let order: Vec<_> = question.choices.iter().map(|choice| choice.id).collect();
+        assert_ne!(order, right_order);
+        assert_eq!(
+            order.iter().collect::<HashSet<_>>(),
+            right_order.iter().collect::<HashSet<_>>()
+        );
+    }
+
+    // the first player gets it right, the second one swaps the last two planets
+    utils::send_order_answer(&mut fst_sender, &fst_player, 0, right_order.clone()).await?;
+    let _ = utils::receive_question_update(&mut fst_receiver).await?;
+    let _ = utils::receive_question_update(&mut snd_receiver).await?;
+
+    let mut wrong_order = right_order.clone();
+    wrong_order.swap(2, 3);
+    utils::send_order_answer(&mut snd_sender, &snd_player, 0, wrong_order.clone()).await?;
+
+    let fst_ended = utils::receive_question_ended(&mut fst_receiver).await?;
+    let snd_ended = utils::receive_question_ended(&mut snd_receiver).await?;
+
+    assert_eq!(fst_ended.player_order, Some(right_order));
+    assert_eq!(snd_ended.player_order, Some(wrong_order));
+
+    let state = server.send(GetServerState).await?;
+    assert!(state.results[&0][&fst_player.uuid].points_awarded > 0);
+    assert_eq!(state.results[&0][&snd_player.uuid].points_awarded, 0);
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    Ok(())
+}
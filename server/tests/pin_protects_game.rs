@@ -0,0 +1,105 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::time::Duration;
+
+use common::{
+    constants::{
+        MAXIMAL_FAILED_ATTEMPTS, PIN_REQUIRED_MSG, TOO_MANY_PIN_ATTEMPTS_MSG, WRONG_PIN_MSG,
+    },
+    messages::{
+        network::{CanJoin, TryJoinRequest},
+        ClientNetworkMessage, ServerNetworkMessage,
+    },
+};
+use futures_util::SinkExt;
+use rstest::rstest;
+use server::{lobby::Lobby, messages::lobby};
+use tungstenite::Message;
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{
+        create_server::create_server_from_lobby,
+        create_server_and_teacher::create_server_and_teacher,
+    },
+    mocks::get_server_state_handler::GetServerState,
+};
+
+async fn try_join_with_pin(
+    sender: &mut utils::Sender,
+    receiver: &mut utils::Receiver,
+    uuid: Uuid,
+    pin: Option<&str>,
+) -> anyhow::Result<CanJoin> {
+    let msg = ClientNetworkMessage::TryJoinRequest(TryJoinRequest {
+        pin: pin.map(ToOwned::to_owned),
+        ..TryJoinRequest::new(uuid)
+    });
+    sender
+        .send(Message::Text(serde_json::to_string(&msg)?))
+        .await?;
+
+    let ServerNetworkMessage::TryJoinResponse(response) =
+        utils::receive_server_network_msg(receiver).await?
+    else {
+        anyhow::bail!("Expected TryJoinResponse");
+    };
+
+    Ok(response.can_join)
+}
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn pin_protects_game() -> anyhow::Result<()> {
+    let mut lobby = Lobby::new(utils::sample_questions());
+    lobby.pin = Some("1234".to_string());
+
+    let (server_thread, server, teacher_thread, teacher) =
+        create_server_and_teacher(create_server_from_lobby(lobby));
+
+    // the player is asked for the PIN and may try again on the same connection
+    let (mut sender, mut receiver) = utils::connect_to_server().await;
+    let uuid = Uuid::new_v4();
+
+    let can_join = try_join_with_pin(&mut sender, &mut receiver, uuid, None).await?;
+    assert_eq!(can_join, CanJoin::No(PIN_REQUIRED_MSG.to_string()));
+
+    let can_join = try_join_with_pin(&mut sender, &mut receiver, uuid, Some("4321")).await?;
+    assert_eq!(can_join, CanJoin::No(WRONG_PIN_MSG.to_string()));
+
+    let state = server.send(GetServerState).await?;
+    assert!(state.waiting_players.is_empty());
+
+    let can_join = try_join_with_pin(&mut sender, &mut receiver, uuid, Some("1234")).await?;
+    assert_eq!(can_join, CanJoin::Yes);
+
+    let (_, join_response) = utils::join_server(&mut sender, &mut receiver, uuid).await?;
+    assert_eq!(join_response.can_join, CanJoin::Yes);
+
+    // guessing is stopped after a few wrong PINs, even if the right one comes next
+    let (mut sender, mut receiver) = utils::connect_to_server().await;
+    let uuid = Uuid::new_v4();
+
+    for _ in 0..MAXIMAL_FAILED_ATTEMPTS {
+        let can_join = try_join_with_pin(&mut sender, &mut receiver, uuid, Some("0000")).await?;
+        assert_eq!(can_join, CanJoin::No(WRONG_PIN_MSG.to_string()));
+    }
+
+    let can_join = try_join_with_pin(&mut sender, &mut receiver, uuid, Some("1234")).await?;
+    assert_eq!(can_join, CanJoin::No(TOO_MANY_PIN_ATTEMPTS_MSG.to_string()));
+
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.joined_players.len(), 1);
+    assert!(state.waiting_players.is_empty());
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    Ok(())
+}
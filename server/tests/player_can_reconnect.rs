@@ -0,0 +1,142 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::{
+    collections::HashSet,
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use actix::Addr;
+
+use common::{
+    constants::{MAXIMAL_FAILED_ATTEMPTS, TOO_MANY_ATTEMPTS_MSG},
+    messages::network::{CanJoin, GameSnapshot},
+};
+use rstest::rstest;
+use server::{
+    lobby::{Lobby, Phase},
+    messages::lobby::{self, StartQuestion},
+    Teacher,
+};
+use uuid::Uuid;
+
+use crate::{
+    fixtures::create_server_and_teacher::create_server_and_teacher,
+    mocks::get_server_state_handler::GetServerState,
+};
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn player_can_reconnect(
+    create_server_and_teacher: (JoinHandle<()>, Addr<Lobby>, JoinHandle<()>, Addr<Teacher>),
+) -> anyhow::Result<()> {
+    let (server_thread, server, teacher_thread, teacher) = create_server_and_teacher;
+
+    let (_fst_sender, mut fst_receiver, _fst_data) = utils::join_new_player().await?;
+    let (snd_sender, snd_receiver, snd_data) = utils::join_new_player().await?;
+
+    let msg = utils::receive_players_update(&mut fst_receiver).await?;
+    assert_eq!(msg.players.len(), 2);
+
+    let state = server.send(GetServerState).await?;
+    let secret = state.joined_players[&snd_data.uuid]
+        .reconnect_secret
+        .clone();
+
+    // start the round and let the second player answer
+    server.send(StartQuestion).await??;
+    let question = utils::receive_next_question(&mut fst_receiver).await?;
+
+    let mut snd_sender = snd_sender;
+    utils::send_question_answer(&mut snd_sender, &snd_data, &question.question, 0, vec![1]).await?;
+
+    let msg = utils::receive_question_update(&mut fst_receiver).await?;
+    assert_eq!(msg.players_answered_count, 1);
+
+    // then their connection drops and the first player is notified about it
+    drop(snd_sender);
+    drop(snd_receiver);
+
+    let msg = utils::receive_players_update(&mut fst_receiver).await?;
+    assert_eq!(msg.players.len(), 1);
+
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.joined_players.len(), 1);
+    assert!(state.disconnected_players.contains_key(&snd_data.uuid));
+    assert!(state.results[&0].contains_key(&snd_data.uuid));
+
+    // nobody else can take over the player, even though everyone knows their uuid
+    let (_sender, _receiver, response) =
+        utils::reconnect_to_server(snd_data.uuid, "not the secret").await?;
+    assert!(matches!(response.can_reconnect, CanJoin::No(_)));
+
+    let state = server.send(GetServerState).await?;
+    assert!(state.disconnected_players.contains_key(&snd_data.uuid));
+
+    // the second player comes back with the same uuid
+    let (_snd_sender, _snd_receiver, response) =
+        utils::reconnect_to_server(snd_data.uuid, &secret).await?;
+
+    assert_eq!(response.can_reconnect, CanJoin::Yes);
+    assert_eq!(response.player, Some(snd_data.clone()));
+    assert_eq!(response.players.len(), 2);
+
+    let GameSnapshot::Question {
+        question: snapshot_question,
+        players_answered_count,
+        player_answer,
+        ..
+    } = response.snapshot
+    else {
+        panic!("Expected the snapshot of the active question");
+    };
+    assert_eq!(snapshot_question, question);
+    assert_eq!(players_answered_count, 1);
+    assert_eq!(player_answer, Some(HashSet::from([question.choices[1].id])));
+
+    let msg = utils::receive_players_update(&mut fst_receiver).await?;
+    assert_eq!(msg.players.len(), 2);
+
+    thread::sleep(Duration::from_millis(100));
+
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.phase, Phase::ActiveQuestion(0));
+    assert_eq!(state.joined_players.len(), 2);
+    assert!(state.disconnected_players.is_empty());
+
+    // a connected player cannot be hung up by someone who only knows their uuid
+    let (_sender, _receiver, response) =
+        utils::reconnect_to_server(snd_data.uuid, "not the secret").await?;
+    assert!(matches!(response.can_reconnect, CanJoin::No(_)));
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.joined_players.len(), 2);
+
+    // an unknown player cannot reconnect
+    let (_sender, _receiver, response) =
+        utils::reconnect_to_server(Uuid::new_v4(), &secret).await?;
+    assert!(matches!(response.can_reconnect, CanJoin::No(_)));
+
+    // guessing is stopped after a few wrong secrets (three were sent above), even if the right one comes next
+    for _ in 3..MAXIMAL_FAILED_ATTEMPTS {
+        let (_sender, _receiver, _) =
+            utils::reconnect_to_server(snd_data.uuid, "not the secret").await?;
+    }
+    let (_sender, _receiver, response) = utils::reconnect_to_server(snd_data.uuid, &secret).await?;
+    assert_eq!(
+        response.can_reconnect,
+        CanJoin::No(TOO_MANY_ATTEMPTS_MSG.to_string())
+    );
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.joined_players.len(), 2);
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    Ok(())
+}
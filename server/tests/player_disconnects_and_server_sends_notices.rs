@@ -10,7 +10,8 @@ use rstest::rstest;
 use server::{messages::lobby, Lobby, Teacher};
 
 use crate::{
-    fixtures::create_server_and_teacher::create_server_and_teacher, mocks::GetServerState,
+    fixtures::create_server_and_teacher::create_server_and_teacher,
+    mocks::get_server_state_handler::GetServerState,
 };
 
 #[rstest]
@@ -12,7 +12,8 @@ use rstest::rstest;
 use server::{messages::lobby, Lobby, Teacher};
 
 use crate::{
-    fixtures::create_server_and_teacher::create_server_and_teacher, mocks::GetServerState,
+    fixtures::create_server_and_teacher::create_server_and_teacher,
+    mocks::get_server_state_handler::GetServerState,
 };
 
 #[rstest]
@@ -35,7 +36,8 @@ async fn players_update_is_delivered(
     assert_eq!(
         msg,
         PlayersUpdate {
-            players: vec![fst_data.clone(), snd_data.clone(),]
+            players: vec![fst_data.clone(), snd_data.clone(),],
+            late_joiners: vec![],
         }
     );
 
@@ -0,0 +1,105 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::time::Duration;
+use std::vec;
+
+use common::questions::{Choice, Question, QuestionSet, Scoring};
+
+use rstest::rstest;
+use server::{
+    lobby::Phase,
+    messages::lobby::{self, PauseQuestion, ResumeQuestion, StartQuestion},
+};
+
+use uuid::Uuid;
+
+use crate::fixtures::create_server::create_server;
+use crate::fixtures::create_server_and_teacher::create_server_and_teacher;
+use crate::mocks::get_server_state_handler::GetServerState;
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(15))]
+async fn question_can_be_paused() -> anyhow::Result<()> {
+    let (server_thread, server) = create_server(QuestionSet {
+        quiz_name: "test".to_string(),
+        randomize_answers: false,
+        randomize_questions: false,
+        scoring: Scoring::Classic,
+        questions: vec![Question {
+            choices: vec![Choice {
+                id: Uuid::new_v4(),
+                is_correct: true,
+                text: "right".to_string(),
+            }],
+            code_block: None,
+            text: "question".to_string(),
+            time_seconds: 2,
+            is_multichoice: false,
+            ..Default::default()
+        }],
+    });
+
+    let (server_thread, server, teacher_thread, teacher) =
+        create_server_and_teacher((server_thread, server));
+
+    let (mut fst_sender, mut fst_receiver, fst_player) = utils::join_new_player().await?;
+    let (_snd_sender, mut snd_receiver, _snd_player) = utils::join_new_player().await?;
+    utils::receive_players_update(&mut fst_receiver).await?;
+
+    // nothing to pause before the question starts
+    assert!(server.send(PauseQuestion).await?.is_err());
+
+    server.send(StartQuestion).await??;
+    let question = utils::receive_next_question(&mut fst_receiver).await?;
+    utils::receive_next_question(&mut snd_receiver).await?;
+
+    server.send(PauseQuestion).await??;
+    let update = utils::receive_pause_update(&mut fst_receiver).await?;
+    assert!(update.paused);
+    assert!(utils::receive_pause_update(&mut snd_receiver).await?.paused);
+
+    // answers are not accepted while paused
+    utils::send_question_answer(&mut fst_sender, &fst_player, &question.question, 0, vec![0])
+        .await?;
+
+    // and the question does not end, even though its time would be up
+    let to_wait = question.time_seconds + question.show_choices_after;
+    tokio::time::sleep(Duration::from_secs(to_wait.try_into()?) + Duration::from_millis(500)).await;
+
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.phase, Phase::ActiveQuestion(0));
+    assert!(state.paused_at.is_some());
+    assert!(!state
+        .results
+        .get(&0)
+        .is_some_and(|results| results.contains_key(&fst_player.uuid)));
+
+    // the question goes on with the time it had left
+    server.send(ResumeQuestion).await??;
+    let update = utils::receive_pause_update(&mut fst_receiver).await?;
+    assert!(!update.paused);
+    assert!(update.elapsed_seconds < to_wait);
+    utils::receive_pause_update(&mut snd_receiver).await?;
+    assert!(server.send(ResumeQuestion).await?.is_err());
+
+    utils::send_question_answer(&mut fst_sender, &fst_player, &question.question, 0, vec![0])
+        .await?;
+    utils::receive_question_update(&mut fst_receiver).await?;
+    utils::receive_question_update(&mut snd_receiver).await?;
+
+    // the second player does not answer, so the question ends when the rest of its time is up
+    let ended = utils::receive_question_ended(&mut fst_receiver).await?;
+    assert!(ended.player_answer.is_some());
+    utils::receive_question_ended(&mut snd_receiver).await?;
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    Ok(())
+}
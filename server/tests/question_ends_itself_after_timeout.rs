@@ -5,15 +5,15 @@ mod utils;
 use std::time::Duration;
 use std::vec;
 
-use common::questions::{Choice, Question, QuestionSet};
+use common::questions::{Choice, Question, QuestionSet, Scoring};
 
 use rstest::rstest;
 use server::messages::lobby::{self, StartQuestion};
 
 use uuid::Uuid;
 
-use crate::fixtures::create_server;
-use crate::fixtures::create_server_and_teacher;
+use crate::fixtures::create_server::create_server;
+use crate::fixtures::create_server_and_teacher::create_server_and_teacher;
 
 #[rstest]
 #[tokio::test]
@@ -23,6 +23,7 @@ async fn question_ends_itself_after_timeout_test_implementation() -> anyhow::Res
         quiz_name: "test".to_string(),
         randomize_answers: false,
         randomize_questions: false,
+        scoring: Scoring::Classic,
         questions: vec![Question {
             choices: vec![Choice {
                 id: Uuid::new_v4(),
@@ -33,6 +34,7 @@ async fn question_ends_itself_after_timeout_test_implementation() -> anyhow::Res
             text: "question".to_string(),
             time_seconds: 2,
             is_multichoice: false,
+            ..Default::default()
         }],
     });
 
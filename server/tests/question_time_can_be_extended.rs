@@ -0,0 +1,128 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::time::Duration;
+use std::vec;
+
+use common::questions::{Choice, Question, QuestionSet, Scoring};
+
+use rstest::rstest;
+use server::{
+    lobby::Phase,
+    messages::lobby::{
+        self, AddTime, PauseQuestion, ReopenQuestion, ResumeQuestion, StartQuestion,
+    },
+};
+
+use uuid::Uuid;
+
+use crate::fixtures::create_server::create_server;
+use crate::fixtures::create_server_and_teacher::create_server_and_teacher;
+use crate::mocks::get_server_state_handler::GetServerState;
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(15))]
+async fn question_time_can_be_extended() -> anyhow::Result<()> {
+    let (server_thread, server) = create_server(QuestionSet {
+        quiz_name: "test".to_string(),
+        randomize_answers: false,
+        randomize_questions: false,
+        scoring: Scoring::Classic,
+        questions: vec![Question {
+            choices: vec![Choice {
+                id: Uuid::new_v4(),
+                is_correct: true,
+                text: "right".to_string(),
+            }],
+            code_block: None,
+            text: "question".to_string(),
+            time_seconds: 1,
+            is_multichoice: false,
+            ..Default::default()
+        }],
+    });
+
+    let (server_thread, server, teacher_thread, teacher) =
+        create_server_and_teacher((server_thread, server));
+
+    let (_fst_sender, mut fst_receiver, _fst_player) = utils::join_new_player().await?;
+    let (_snd_sender, mut snd_receiver, _snd_player) = utils::join_new_player().await?;
+    utils::receive_players_update(&mut fst_receiver).await?;
+
+    // there is no question to extend yet
+    assert!(server.send(AddTime { seconds: 2 }).await?.is_err());
+
+    server.send(StartQuestion).await??;
+    let question = utils::receive_next_question(&mut fst_receiver).await?;
+    utils::receive_next_question(&mut snd_receiver).await?;
+
+    assert!(server.send(AddTime { seconds: 0 }).await?.is_err());
+
+    server.send(AddTime { seconds: 2 }).await??;
+    let update = utils::receive_question_time_update(&mut fst_receiver).await?;
+    assert_eq!(update.question_index, 0);
+    assert_eq!(update.time_seconds, 3);
+    assert_eq!(
+        utils::receive_question_time_update(&mut snd_receiver)
+            .await?
+            .time_seconds,
+        3
+    );
+
+    // the question outlives its original time
+    let to_wait = question.time_seconds + question.show_choices_after;
+    tokio::time::sleep(Duration::from_secs(to_wait.try_into()?) + Duration::from_millis(500)).await;
+
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.phase, Phase::ActiveQuestion(0));
+    assert_eq!(state.extra_time[&0], 2);
+
+    // the quiz itself is not changed
+    assert_eq!(state.questions[0].time_seconds, 1);
+
+    // time added during a pause is kept for after the resume
+    server.send(PauseQuestion).await??;
+    utils::receive_pause_update(&mut fst_receiver).await?;
+    utils::receive_pause_update(&mut snd_receiver).await?;
+
+    server.send(AddTime { seconds: 1 }).await??;
+    assert_eq!(
+        utils::receive_question_time_update(&mut fst_receiver)
+            .await?
+            .time_seconds,
+        4
+    );
+    utils::receive_question_time_update(&mut snd_receiver).await?;
+
+    server.send(ResumeQuestion).await??;
+    utils::receive_pause_update(&mut fst_receiver).await?;
+    utils::receive_pause_update(&mut snd_receiver).await?;
+
+    // nobody answers, so the question ends when the extended time is up
+    let ended = utils::receive_question_ended(&mut fst_receiver).await?;
+    assert_eq!(ended.question.time_seconds, 4);
+    utils::receive_question_ended(&mut snd_receiver).await?;
+
+    // the question asked again has only its own time
+    server
+        .send(ReopenQuestion {
+            index: 0,
+            discard_results: true,
+        })
+        .await??;
+    let question = utils::receive_next_question(&mut fst_receiver).await?;
+    assert_eq!(question.question.time_seconds, 1);
+
+    let state = server.send(GetServerState).await?;
+    assert!(state.extra_time.is_empty());
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    Ok(())
+}
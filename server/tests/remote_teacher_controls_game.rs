@@ -0,0 +1,133 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::time::Duration;
+
+use common::{
+    constants::{MAXIMAL_FAILED_ATTEMPTS, TOO_MANY_ATTEMPTS_MSG, WRONG_TEACHER_TOKEN_MSG},
+    messages::network::{CanJoin, TeacherCommand},
+};
+use rstest::rstest;
+use server::{lobby::Lobby, messages::lobby};
+
+use crate::{
+    fixtures::create_server::create_server_from_lobby,
+    mocks::get_server_state_handler::GetServerState,
+};
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn remote_teacher_controls_game() -> anyhow::Result<()> {
+    let mut lobby = Lobby::new(utils::sample_questions());
+    lobby.teacher_token = utils::TEACHER_TOKEN.to_string();
+
+    // no teacher on the server, as if it was started with `--headless`
+    let (server_thread, server) = create_server_from_lobby(lobby);
+
+    // commands are not accepted before logging in
+    let (mut sender, mut receiver) = utils::connect_to_server().await;
+    utils::send_teacher_command(&mut sender, TeacherCommand::StartQuestion).await?;
+    utils::receive_close_frame(&mut receiver).await?;
+
+    let (mut sender, mut receiver) = utils::connect_to_server().await;
+    let response = utils::login_teacher(&mut sender, &mut receiver, "guess").await?;
+    assert_eq!(
+        response.can_login,
+        CanJoin::No(WRONG_TEACHER_TOKEN_MSG.to_string())
+    );
+
+    // the connection is closed after a wrong token
+    utils::receive_close_frame(&mut receiver).await?;
+
+    let state = server.send(GetServerState).await?;
+    assert!(state.locked);
+    assert!(state.remote_teachers.is_empty());
+
+    // the first teacher opens the game for the players
+    let (mut teacher_sender, mut teacher_receiver) = utils::connect_to_server().await;
+    let response = utils::login_teacher(
+        &mut teacher_sender,
+        &mut teacher_receiver,
+        utils::TEACHER_TOKEN,
+    )
+    .await?;
+    assert_eq!(response.can_login, CanJoin::Yes);
+
+    let update = utils::receive_players_update(&mut teacher_receiver).await?;
+    assert!(update.players.is_empty());
+    assert!(
+        !utils::receive_lock_update(&mut teacher_receiver)
+            .await?
+            .locked
+    );
+    let list = utils::receive_question_list(&mut teacher_receiver).await?;
+    assert!(list.questions.iter().all(|question| !question.asked));
+
+    let state = server.send(GetServerState).await?;
+    assert!(!state.locked);
+    assert_eq!(state.remote_teachers.len(), 1);
+
+    let (player_sender, mut player_receiver, player) = utils::join_new_player().await?;
+    let update = utils::receive_players_update(&mut teacher_receiver).await?;
+    assert_eq!(update.players.len(), 1);
+    assert_eq!(update.players[0].uuid, player.uuid);
+
+    // the game is driven by the remote teacher
+    utils::send_teacher_command(&mut teacher_sender, TeacherCommand::StartQuestion).await?;
+    let question = utils::receive_next_question(&mut player_receiver).await?;
+    assert_eq!(question.question_index, 0);
+    let question = utils::receive_next_question(&mut teacher_receiver).await?;
+    assert_eq!(question.question_index, 0);
+    let list = utils::receive_question_list(&mut teacher_receiver).await?;
+    assert!(list.questions[0].asked);
+
+    utils::send_teacher_command(
+        &mut teacher_sender,
+        TeacherCommand::EndQuestion { index: 0 },
+    )
+    .await?;
+    utils::receive_question_ended(&mut player_receiver).await?;
+    utils::receive_question_ended(&mut teacher_receiver).await?;
+    let details = utils::receive_answer_details(&mut teacher_receiver).await?;
+    assert_eq!(details.players.len(), 1);
+    assert_eq!(details.players[0].answer_order, None);
+
+    utils::send_teacher_command(&mut teacher_sender, TeacherCommand::SwitchToLeaderboard).await?;
+    utils::receive_show_leaderboard(&mut player_receiver).await?;
+    utils::receive_show_leaderboard(&mut teacher_receiver).await?;
+
+    // the teacher can also kick players
+    utils::send_teacher_command(
+        &mut teacher_sender,
+        TeacherCommand::KickPlayer {
+            player_uuid: player.uuid,
+            reason: None,
+        },
+    )
+    .await?;
+    utils::receive_close_frame(&mut player_receiver).await?;
+
+    drop(player_sender);
+
+    // guessing is stopped after a few wrong tokens (one was sent above), even if the right one comes next
+    for _ in 1..MAXIMAL_FAILED_ATTEMPTS {
+        let (mut sender, mut receiver) = utils::connect_to_server().await;
+        utils::login_teacher(&mut sender, &mut receiver, "guess").await?;
+    }
+    let (mut sender, mut receiver) = utils::connect_to_server().await;
+    let response = utils::login_teacher(&mut sender, &mut receiver, utils::TEACHER_TOKEN).await?;
+    assert_eq!(
+        response.can_login,
+        CanJoin::No(TOO_MANY_ATTEMPTS_MSG.to_string())
+    );
+
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.remote_teachers.len(), 1);
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::{fs, time::Duration};
+
+use rstest::rstest;
+use server::{
+    lobby::{Lobby, Phase, ResultsFormat},
+    messages::lobby::{self, KickPlayer, StartQuestion, SwitchToLeaderboard},
+};
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{
+        create_server::create_server_from_lobby,
+        create_server_and_teacher::create_server_and_teacher,
+    },
+    mocks::get_server_state_handler::GetServerState,
+};
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn results_are_written_to_file() -> anyhow::Result<()> {
+    let results_file =
+        std::env::temp_dir().join(format!("clihoot_results_{}.json", Uuid::new_v4()));
+
+    let mut lobby = Lobby::new(utils::sample_questions());
+    lobby.results_file = Some(results_file.clone());
+    lobby.results_format = ResultsFormat::Json;
+
+    let (server_thread, server, teacher_thread, teacher) =
+        create_server_and_teacher(create_server_from_lobby(lobby));
+
+    let (mut sender, mut receiver, player) = utils::join_new_player().await?;
+    let (mut kicked_sender, mut kicked_receiver, kicked_player) = utils::join_new_player().await?;
+    utils::receive_players_update(&mut receiver).await?;
+
+    // play the only question of the game
+    server.send(StartQuestion).await??;
+    let question = utils::receive_next_question(&mut receiver).await?;
+    utils::receive_next_question(&mut kicked_receiver).await?;
+    utils::send_question_answer(&mut sender, &player, &question.question, 0, vec![1]).await?;
+    utils::receive_question_update(&mut receiver).await?;
+    utils::send_question_answer(
+        &mut kicked_sender,
+        &kicked_player,
+        &question.question,
+        0,
+        vec![0],
+    )
+    .await?;
+    utils::receive_question_ended(&mut receiver).await?;
+
+    // the kicked player is not forgotten
+    server
+        .send(KickPlayer {
+            player_uuid: kicked_player.uuid,
+            reason: None,
+        })
+        .await??;
+    utils::receive_players_update(&mut receiver).await?;
+
+    server.send(SwitchToLeaderboard).await??;
+    utils::receive_show_leaderboard(&mut receiver).await?;
+
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.phase, Phase::GameEnded);
+
+    // the report is written as soon as the game ends
+    let report: serde_json::Value = serde_json::from_str(&fs::read_to_string(&results_file)?)?;
+
+    assert_eq!(report["game_ended"], true);
+    assert_eq!(report["players"].as_array().map(Vec::len), Some(2));
+
+    let player_report = &report["players"][0];
+    assert_eq!(player_report["nickname"], player.nickname);
+    assert_eq!(player_report["uuid"], player.uuid.to_string());
+    assert_eq!(player_report["rank"], 1);
+
+    let answer = &player_report["answers"][0];
+    assert_eq!(answer["chosen_answers"], serde_json::json!(["42"]));
+    assert_eq!(answer["correct"], true);
+    assert_eq!(answer["answer_order"], 1);
+    assert!(answer["latency_ms"]
+        .as_i64()
+        .is_some_and(|latency| latency >= 0));
+    assert_eq!(answer["points"], player_report["total_points"]);
+
+    let kicked_report = &report["players"][1];
+    assert_eq!(kicked_report["uuid"], kicked_player.uuid.to_string());
+    assert_eq!(kicked_report["rank"], 2);
+    assert_eq!(kicked_report["answers"][0]["correct"], false);
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    fs::remove_file(results_file)?;
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::{net::Ipv4Addr, time::Duration};
+
+use common::{
+    constants::{DEFAULT_PORT, DISCOVERY_PORT, PROTOCOL_VERSION},
+    messages::discovery::Announcement,
+};
+use rstest::rstest;
+use server::{
+    lobby::Lobby,
+    messages::lobby::{self, SetLockMessage},
+};
+use tokio::net::UdpSocket;
+
+use crate::fixtures::create_server::create_discoverable_server;
+
+async fn receive_announcement(socket: &UdpSocket) -> anyhow::Result<Announcement> {
+    let mut buffer = [0; 1024];
+    let (length, _) = socket.recv_from(&mut buffer).await?;
+
+    Ok(serde_json::from_slice(&buffer[..length])?)
+}
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn server_is_discoverable() -> anyhow::Result<()> {
+    let socket = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, DISCOVERY_PORT)).await?;
+
+    let mut lobby = Lobby::new(utils::sample_questions());
+    lobby.pin = Some("1234".to_string());
+    let (server_thread, server) = create_discoverable_server(lobby);
+
+    // without a teacher the lobby is locked
+    let announcement = receive_announcement(&socket).await?;
+    assert_eq!(
+        announcement,
+        Announcement {
+            protocol_version: PROTOCOL_VERSION,
+            quiz_name: utils::sample_questions().quiz_name,
+            port: DEFAULT_PORT,
+            tls: false,
+            locked: true,
+            pin_required: true,
+        }
+    );
+
+    // the announcements follow the state of the lobby
+    server.send(SetLockMessage { locked: false }).await?;
+    let mut announcement = receive_announcement(&socket).await?;
+    if announcement.locked {
+        // sent before the lobby was unlocked
+        announcement = receive_announcement(&socket).await?;
+    }
+    assert!(!announcement.locked);
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    Ok(())
+}
@@ -6,7 +6,9 @@ use std::thread::JoinHandle;
 use std::time::Duration;
 
 use crate::utils::sample_questions;
-use crate::{fixtures::create_server::create_server, mocks::GetServerState};
+use crate::{
+    fixtures::create_server::create_server, mocks::get_server_state_handler::GetServerState,
+};
 use actix::Addr;
 use common::{assert_questionset_eq, test_utils::compare_question_sets};
 use rstest::rstest;
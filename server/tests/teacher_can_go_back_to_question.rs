@@ -0,0 +1,151 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::time::Duration;
+
+use common::questions::{QuestionSet, Scoring};
+
+use rstest::rstest;
+use server::{
+    lobby::Phase,
+    messages::lobby::{
+        self, EndQuestion, JumpToQuestion, ReopenQuestion, StartQuestion, SwitchToLeaderboard,
+    },
+};
+
+use crate::fixtures::create_server::create_server;
+use crate::fixtures::create_server_and_teacher::create_server_and_teacher;
+use crate::fixtures::question::question;
+use crate::mocks::get_server_state_handler::GetServerState;
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(15))]
+async fn teacher_can_go_back_to_question() -> anyhow::Result<()> {
+    let (server_thread, server) = create_server(QuestionSet {
+        quiz_name: "test".to_string(),
+        randomize_answers: false,
+        randomize_questions: false,
+        scoring: Scoring::Classic,
+        questions: ["first", "second", "third"]
+            .iter()
+            .map(|text| question(text, &[("right", true)]))
+            .collect(),
+    });
+
+    let (server_thread, server, teacher_thread, teacher) =
+        create_server_and_teacher((server_thread, server));
+
+    let (mut sender, mut receiver, player) = utils::join_new_player().await?;
+
+    // the first question is answered as usual
+    server.send(StartQuestion).await??;
+    let first = utils::receive_next_question(&mut receiver).await?;
+    utils::send_question_answer(&mut sender, &player, &first.question, 0, vec![0]).await?;
+    utils::receive_question_ended(&mut receiver).await?;
+
+    server.send(SwitchToLeaderboard).await??;
+    let leaderboard = utils::receive_show_leaderboard(&mut receiver).await?;
+    let first_points = usize::try_from(
+        server.send(GetServerState).await?.results[&0][&player.uuid].points_awarded,
+    )?;
+    assert_eq!(leaderboard.players[0].1, first_points);
+    assert!(!leaderboard.was_final_round);
+
+    // a question which was not asked cannot be re-opened, but it can be jumped to
+    assert!(server
+        .send(ReopenQuestion {
+            index: 1,
+            discard_results: false
+        })
+        .await?
+        .is_err());
+
+    server.send(JumpToQuestion { index: 2 }).await??;
+    let third = utils::receive_next_question(&mut receiver).await?;
+    assert_eq!(third.question_index, 2);
+
+    // but not while another question is running
+    assert!(server.send(JumpToQuestion { index: 0 }).await?.is_err());
+
+    utils::send_question_answer(&mut sender, &player, &third.question, 2, vec![0]).await?;
+    utils::receive_question_ended(&mut receiver).await?;
+    let third_points = usize::try_from(
+        server.send(GetServerState).await?.results[&2][&player.uuid].points_awarded,
+    )?;
+
+    // the player does not answer the re-opened question again, so the previous answer counts (once)
+    server
+        .send(ReopenQuestion {
+            index: 0,
+            discard_results: false,
+        })
+        .await??;
+    assert_eq!(
+        utils::receive_next_question(&mut receiver)
+            .await?
+            .question_index,
+        0
+    );
+    server.send(EndQuestion { index: 0 }).await??;
+    assert!(utils::receive_question_ended(&mut receiver)
+        .await?
+        .player_answer
+        .is_some());
+
+    server.send(SwitchToLeaderboard).await??;
+    let leaderboard = utils::receive_show_leaderboard(&mut receiver).await?;
+    assert_eq!(leaderboard.players[0].1, first_points + third_points);
+    assert!(!leaderboard.was_final_round);
+
+    // the results list the questions which were asked, even after going back to an earlier one
+    let report = server.send(GetServerState).await?.get_report();
+    let reported = report.players[0]
+        .answers
+        .iter()
+        .map(|answer| answer.question_index)
+        .collect::<Vec<_>>();
+    assert_eq!(reported, vec![0, 2]);
+
+    // once discarded, the previous answer is gone
+    server
+        .send(ReopenQuestion {
+            index: 0,
+            discard_results: true,
+        })
+        .await??;
+    utils::receive_next_question(&mut receiver).await?;
+    server.send(EndQuestion { index: 0 }).await??;
+    assert!(utils::receive_question_ended(&mut receiver)
+        .await?
+        .player_answer
+        .is_none());
+
+    server.send(SwitchToLeaderboard).await??;
+    let leaderboard = utils::receive_show_leaderboard(&mut receiver).await?;
+    assert_eq!(leaderboard.players[0].1, third_points);
+
+    // the game goes on with the question which was skipped, which is the last one left
+    server.send(StartQuestion).await??;
+    let second = utils::receive_next_question(&mut receiver).await?;
+    assert_eq!(second.question_index, 1);
+    server.send(EndQuestion { index: 1 }).await??;
+    utils::receive_question_ended(&mut receiver).await?;
+
+    server.send(SwitchToLeaderboard).await??;
+    assert!(
+        utils::receive_show_leaderboard(&mut receiver)
+            .await?
+            .was_final_round
+    );
+    assert_eq!(server.send(GetServerState).await?.phase, Phase::GameEnded);
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    Ok(())
+}
@@ -61,7 +61,8 @@ async fn teacher_can_kick_player(
     assert_eq!(
         msg,
         PlayersUpdate {
-            players: vec![snd_data.clone(),]
+            players: vec![snd_data.clone(),],
+            late_joiners: vec![],
         }
     );
 
@@ -7,7 +7,7 @@ use std::{thread::JoinHandle, time::Duration};
 use actix::Addr;
 
 use common::{
-    constants::{DEFAULT_QUIZ_NAME, LOBBY_LOCKED_MSG},
+    constants::{DEFAULT_QUIZ_NAME, LOBBY_LOCKED_MSG, PROTOCOL_VERSION, SUPPORTED_FEATURES},
     messages::network::{CanJoin, TryJoinResponse},
 };
 use rstest::rstest;
@@ -17,7 +17,8 @@ use server::{
 };
 
 use crate::{
-    fixtures::create_server_and_teacher::create_server_and_teacher, mocks::GetServerState,
+    fixtures::create_server_and_teacher::create_server_and_teacher,
+    mocks::get_server_state_handler::GetServerState,
 };
 
 #[rstest]
@@ -47,7 +48,10 @@ async fn teacher_can_lock_game(
         TryJoinResponse {
             can_join: CanJoin::No(LOBBY_LOCKED_MSG.to_string()),
             quiz_name: DEFAULT_QUIZ_NAME.to_string(),
-            uuid
+            protocol_version: PROTOCOL_VERSION,
+            features: SUPPORTED_FEATURES.to_vec(),
+            server_time: msg.server_time,
+            uuid,
         }
     );
 
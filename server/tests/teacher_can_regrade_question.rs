@@ -0,0 +1,124 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::{collections::HashSet, time::Duration};
+
+use common::questions::{QuestionSet, Scoring};
+use rstest::rstest;
+use server::{
+    lobby::Phase,
+    messages::lobby::{self, RegradeQuestion, StartQuestion, VoidQuestion},
+};
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{
+        create_server::create_server, create_server_and_teacher::create_server_and_teacher,
+        question::question,
+    },
+    mocks::get_server_state_handler::GetServerState,
+};
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn teacher_can_regrade_question() -> anyhow::Result<()> {
+    let choices = [("right", true), ("wrong", false)];
+    let first = question("first", &choices);
+    let wrong_choice = first.choices[1].id;
+
+    let (server_thread, server, teacher_thread, teacher) =
+        create_server_and_teacher(create_server(QuestionSet {
+            scoring: Scoring::Flat,
+            ..QuestionSet::new(vec![first, question("second", &choices)])
+        }));
+
+    let (mut fst_sender, mut fst_receiver, fst_player) = utils::join_new_player().await?;
+    let (mut snd_sender, mut snd_receiver, snd_player) = utils::join_new_player().await?;
+    let _fst_players_update = utils::receive_players_update(&mut fst_receiver).await?;
+
+    // the first player picks the choice marked as right, the second one the other
+    server.send(StartQuestion).await??;
+    let question = utils::receive_next_question(&mut fst_receiver).await?;
+    let _ = utils::receive_next_question(&mut snd_receiver).await?;
+
+    utils::send_question_answer(&mut fst_sender, &fst_player, &question.question, 0, vec![0])
+        .await?;
+    let _ = utils::receive_question_update(&mut fst_receiver).await?;
+    let _ = utils::receive_question_update(&mut snd_receiver).await?;
+    utils::send_question_answer(&mut snd_sender, &snd_player, &question.question, 0, vec![1])
+        .await?;
+    let _ = utils::receive_question_ended(&mut fst_receiver).await?;
+    let _ = utils::receive_question_ended(&mut snd_receiver).await?;
+
+    // only the choices of the question can become the correct ones
+    assert!(server
+        .send(RegradeQuestion {
+            index: 0,
+            correct_choices: HashSet::from([Uuid::new_v4()]),
+        })
+        .await?
+        .is_err());
+
+    // it turns out the other choice was the right one
+    server
+        .send(RegradeQuestion {
+            index: 0,
+            correct_choices: HashSet::from([wrong_choice]),
+        })
+        .await??;
+
+    let leaderboard = utils::receive_show_leaderboard(&mut fst_receiver).await?;
+    let _ = utils::receive_show_leaderboard(&mut snd_receiver).await?;
+    assert_eq!(leaderboard.players[0], (snd_player.clone(), 1));
+    assert_eq!(leaderboard.players[1], (fst_player.clone(), 0));
+
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.phase, Phase::ShowingLeaderboard(0));
+    assert!(state.questions[0].choices[1].is_correct);
+    assert!(!state.questions[0].choices[0].is_correct);
+
+    // once the leaderboard is shown, the question cannot be graded again
+    assert!(server.send(VoidQuestion { index: 0 }).await?.is_err());
+
+    // both players answer the second question right, but it is voided
+    server.send(StartQuestion).await??;
+    let question = utils::receive_next_question(&mut fst_receiver).await?;
+    let _ = utils::receive_next_question(&mut snd_receiver).await?;
+
+    utils::send_question_answer(&mut fst_sender, &fst_player, &question.question, 1, vec![0])
+        .await?;
+    let _ = utils::receive_question_update(&mut fst_receiver).await?;
+    let _ = utils::receive_question_update(&mut snd_receiver).await?;
+    utils::send_question_answer(&mut snd_sender, &snd_player, &question.question, 1, vec![0])
+        .await?;
+    let _ = utils::receive_question_ended(&mut fst_receiver).await?;
+    let _ = utils::receive_question_ended(&mut snd_receiver).await?;
+
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.results[&1][&fst_player.uuid].points_awarded, 1);
+
+    server.send(VoidQuestion { index: 1 }).await??;
+
+    let leaderboard = utils::receive_show_leaderboard(&mut fst_receiver).await?;
+    let _ = utils::receive_show_leaderboard(&mut snd_receiver).await?;
+    assert!(leaderboard.was_final_round);
+    assert_eq!(leaderboard.players[0], (snd_player.clone(), 1));
+    assert_eq!(leaderboard.players[1], (fst_player.clone(), 0));
+
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.phase, Phase::GameEnded);
+    assert!(state.voided_questions.contains(&1));
+    assert!(state.results[&1]
+        .values()
+        .all(|record| record.points_awarded == 0));
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::{path::PathBuf, sync::Arc, time::Duration};
+
+use common::{
+    constants::DEFAULT_PORT,
+    messages::{
+        network::{CanJoin, TryJoinRequest},
+        ClientNetworkMessage, ServerNetworkMessage,
+    },
+};
+use futures_util::{SinkExt, StreamExt};
+use rstest::rstest;
+use server::{connection::load_tls_acceptor, messages::lobby, Lobby};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+use tokio_rustls::{
+    rustls::{pki_types::ServerName, ClientConfig, RootCertStore},
+    TlsConnector,
+};
+use tokio_tungstenite::{connect_async, connect_async_tls_with_config, Connector};
+use tungstenite::Message;
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{
+        create_server::create_server_with_tls,
+        create_server_and_teacher::create_server_and_teacher, sample_questions::sample_questions,
+    },
+    mocks::get_server_state_handler::GetServerState,
+};
+
+/// Writes a freshly generated self-signed certificate for localhost and its key to temporary files
+fn self_signed_certificate() -> anyhow::Result<(PathBuf, PathBuf)> {
+    let cert = rcgen::generate_simple_self_signed(vec!["localhost".to_string()])?;
+
+    let id = Uuid::new_v4();
+    let cert_file = std::env::temp_dir().join(format!("clihoot_cert_{id}.pem"));
+    let key_file = std::env::temp_dir().join(format!("clihoot_key_{id}.pem"));
+
+    std::fs::write(&cert_file, cert.serialize_pem()?)?;
+    std::fs::write(&key_file, cert.serialize_private_key_pem())?;
+
+    Ok((cert_file, key_file))
+}
+
+fn client_config(cert_file: &PathBuf) -> anyhow::Result<Arc<ClientConfig>> {
+    let mut roots = RootCertStore::empty();
+    for cert in rustls_pemfile::certs(&mut std::io::BufReader::new(std::fs::File::open(
+        cert_file,
+    )?)) {
+        roots.add(cert?)?;
+    }
+
+    Ok(Arc::new(
+        ClientConfig::builder()
+            .with_root_certificates(roots)
+            .with_no_client_auth(),
+    ))
+}
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn tls_connections_are_encrypted() -> anyhow::Result<()> {
+    let (cert_file, key_file) = self_signed_certificate()?;
+    let acceptor = load_tls_acceptor(&cert_file, &key_file)?;
+    let config = client_config(&cert_file)?;
+
+    let (server_thread, server, teacher_thread, teacher) = create_server_and_teacher(
+        create_server_with_tls(Lobby::new(sample_questions()), Some(acceptor)),
+    );
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    // an unencrypted client cannot talk to the server
+    assert!(connect_async(format!("ws://localhost:{DEFAULT_PORT}"))
+        .await
+        .is_err());
+
+    // an encrypted one, trusting the certificate, can join
+    let (stream, _) = connect_async_tls_with_config(
+        format!("wss://localhost:{DEFAULT_PORT}"),
+        None,
+        false,
+        Some(Connector::Rustls(config.clone())),
+    )
+    .await?;
+    let (mut sender, mut receiver) = stream.split();
+
+    let uuid = Uuid::new_v4();
+    let msg = ClientNetworkMessage::TryJoinRequest(TryJoinRequest::new(uuid));
+    sender
+        .send(Message::Text(serde_json::to_string(&msg)?))
+        .await?;
+
+    let Some(Ok(Message::Text(response))) = receiver.next().await else {
+        anyhow::bail!("Expected a text message");
+    };
+    let ServerNetworkMessage::TryJoinResponse(response) = serde_json::from_str(&response)? else {
+        anyhow::bail!("Expected TryJoinResponse");
+    };
+    assert_eq!(response.can_join, CanJoin::Yes);
+
+    let state = server.send(GetServerState).await?;
+    assert!(state.waiting_players.contains(&uuid));
+
+    // the browser client is served over https too
+    let socket = TcpStream::connect(("localhost", DEFAULT_PORT)).await?;
+    let mut socket = TlsConnector::from(config)
+        .connect(ServerName::try_from("localhost")?, socket)
+        .await?;
+    socket
+        .write_all(b"GET / HTTP/1.1\r\nHost: localhost\r\n\r\n")
+        .await?;
+
+    let mut page = Vec::new();
+    let _ = socket.read_to_end(&mut page).await;
+    assert!(String::from_utf8_lossy(&page).starts_with("HTTP/1.1 200 OK\r\n"));
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    std::fs::remove_file(cert_file)?;
+    std::fs::remove_file(key_file)?;
+
+    Ok(())
+}
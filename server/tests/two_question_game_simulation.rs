@@ -13,7 +13,7 @@ use actix::Addr;
 use anyhow::anyhow;
 use common::{
     messages::network::{ChoiceStats, PlayerData, QuestionEnded},
-    questions::{Choice, CodeBlock, Question, QuestionSet},
+    questions::{Choice, CodeBlock, Question, QuestionSet, Scoring},
 };
 use rstest::rstest;
 use server::{
@@ -23,8 +23,10 @@ use server::{
 use uuid::Uuid;
 
 use crate::{
-    fixtures::{create_server, create_server_and_teacher},
-    mocks::GetServerState,
+    fixtures::{
+        create_server::create_server, create_server_and_teacher::create_server_and_teacher,
+    },
+    mocks::get_server_state_handler::GetServerState,
 };
 
 const QUIZ_NAME: &str = "The most epic quiz ever";
@@ -69,6 +71,7 @@ fn get_questions() -> QuestionSet {
         time_seconds: Q1_TIME,
         is_multichoice: true,
         choices: vec![q1_choice1, q1_choice2, q1_choice3, q1_choice4],
+        ..Default::default()
     };
 
     let q2_choice1 = Choice {
@@ -92,12 +95,14 @@ fn get_questions() -> QuestionSet {
         time_seconds: Q2_TIME,
         is_multichoice: true,
         choices: vec![q2_choice1, q2_choice2],
+        ..Default::default()
     };
 
     QuestionSet {
         quiz_name: QUIZ_NAME.to_string(),
         randomize_answers: false,
         randomize_questions: false,
+        scoring: Scoring::Classic,
         questions: vec![q1.clone(), q2.clone()],
     }
 }
@@ -413,9 +418,15 @@ async fn assert_state_after_ending(
         "Second player's results not found in the results map"
     ))?;
 
-    assert_eq!(first_player_results.points_awarded, fst_points_2);
+    assert_eq!(
+        usize::try_from(first_player_results.points_awarded)?,
+        fst_points_2
+    );
 
-    assert_eq!(second_player_results.points_awarded, snd_points_2);
+    assert_eq!(
+        usize::try_from(second_player_results.points_awarded)?,
+        snd_points_2
+    );
 
     // timestamp of the first player should be lower than the second player's
     assert!(first_player_results.timestamp < second_player_results.timestamp);
@@ -1,13 +1,19 @@
-use std::{path::Path, thread, time::Duration};
+use std::{collections::HashSet, path::Path, thread, time::Duration};
 
 use anyhow::{bail, Ok};
 use common::messages::network::{
-    AnswerSelected, CanJoin, JoinRequest, JoinResponse, NextQuestion, PlayerData, PlayersUpdate,
-    QuestionEnded, QuestionUpdate, ShowLeaderboard, TryJoinRequest, TryJoinResponse,
+    AnswerDetails, AnswerDistribution, AnswerSelected, CanJoin, JoinRequest, JoinResponse,
+    LockUpdate, NextQuestion, PauseUpdate, PlayerData, PlayersUpdate, QuestionEnded, QuestionList,
+    QuestionTimeUpdate, QuestionUpdate, ReconnectRequest, ReconnectResponse, ShowLeaderboard,
+    TeacherCommand, TeacherLoginRequest, TeacherLoginResponse, TryJoinRequest, TryJoinResponse,
+    WrittenAnswer,
 };
 use common::messages::ServerNetworkMessage;
 use common::questions;
-use common::{constants::DEFAULT_PORT, messages::ClientNetworkMessage};
+use common::{
+    constants::{DEFAULT_PORT, PROTOCOL_VERSION},
+    messages::ClientNetworkMessage,
+};
 use futures_util::SinkExt;
 use futures_util::{
     stream::{SplitSink, SplitStream},
@@ -38,6 +44,10 @@ pub fn sample_questions() -> questions::QuestionSet {
         .expect("Failed to load sample questions")
 }
 
+/// Token the remote teachers log in with in the tests
+#[allow(dead_code)]
+pub const TEACHER_TOKEN: &str = "secret-token";
+
 pub type Sender = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
 pub type Receiver = SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>;
 
@@ -68,7 +78,7 @@ pub async fn try_join_server(
     thread::sleep(Duration::from_millis(100));
 
     let id = Uuid::new_v4();
-    let msg = ClientNetworkMessage::TryJoinRequest(TryJoinRequest { uuid: id });
+    let msg = ClientNetworkMessage::TryJoinRequest(TryJoinRequest::new(id));
 
     sender
         .send(Message::Text(serde_json::to_string(&msg)?))
@@ -147,6 +157,35 @@ pub async fn join_new_player() -> anyhow::Result<(Sender, Receiver, PlayerData)>
     Ok((sender, receiver, player_data))
 }
 
+#[allow(dead_code)]
+/// Opens a new connection to the server and asks it to reattach the player with `id`,
+/// proving who they are with the `secret` they got when joining.
+/// # Panics
+/// - if the server cannot be connected to.
+pub async fn reconnect_to_server(
+    id: Uuid,
+    secret: &str,
+) -> anyhow::Result<(Sender, Receiver, ReconnectResponse)> {
+    let (mut sender, mut receiver) = connect_to_server().await;
+
+    let msg = ClientNetworkMessage::ReconnectRequest(ReconnectRequest {
+        player_uuid: id,
+        secret: secret.to_owned(),
+    });
+
+    sender
+        .send(Message::Text(serde_json::to_string(&msg)?))
+        .await?;
+
+    let ServerNetworkMessage::ReconnectResponse(msg) =
+        receive_server_network_msg(&mut receiver).await?
+    else {
+        bail!("Expected ReconnectResponse")
+    };
+
+    Ok((sender, receiver, msg))
+}
+
 #[allow(dead_code)]
 /// Receives a message from the server and returns it.
 /// # Panics
@@ -165,7 +204,7 @@ pub async fn receive_server_network_msg(
 /// Receives a message from the server and returns it.
 /// # Panics
 /// - if failed to receive message, will panic.
-pub async fn receive_close_frame(receiver: &mut Receiver) -> anyhow::Result<CloseFrame> {
+pub async fn receive_close_frame(receiver: &mut Receiver) -> anyhow::Result<CloseFrame<'static>> {
     let msg = receiver.next().await.expect("Failed to receive message")?;
 
     let Message::Close(Some(msg)) = msg else {
@@ -215,6 +254,72 @@ pub async fn receive_players_update(receiver: &mut Receiver) -> anyhow::Result<P
     Ok(update)
 }
 
+#[allow(dead_code)]
+pub async fn receive_pause_update(receiver: &mut Receiver) -> anyhow::Result<PauseUpdate> {
+    let ServerNetworkMessage::PauseUpdate(update) = receive_server_network_msg(receiver).await?
+    else {
+        bail!("Expected PauseUpdate")
+    };
+
+    Ok(update)
+}
+
+#[allow(dead_code)]
+pub async fn receive_question_time_update(
+    receiver: &mut Receiver,
+) -> anyhow::Result<QuestionTimeUpdate> {
+    let ServerNetworkMessage::QuestionTimeUpdate(update) =
+        receive_server_network_msg(receiver).await?
+    else {
+        bail!("Expected QuestionTimeUpdate")
+    };
+
+    Ok(update)
+}
+
+#[allow(dead_code)]
+pub async fn receive_question_list(receiver: &mut Receiver) -> anyhow::Result<QuestionList> {
+    let ServerNetworkMessage::QuestionList(list) = receive_server_network_msg(receiver).await?
+    else {
+        bail!("Expected QuestionList")
+    };
+
+    Ok(list)
+}
+
+#[allow(dead_code)]
+pub async fn receive_answer_details(receiver: &mut Receiver) -> anyhow::Result<AnswerDetails> {
+    let ServerNetworkMessage::AnswerDetails(details) = receive_server_network_msg(receiver).await?
+    else {
+        bail!("Expected AnswerDetails")
+    };
+
+    Ok(details)
+}
+
+#[allow(dead_code)]
+pub async fn receive_answer_distribution(
+    receiver: &mut Receiver,
+) -> anyhow::Result<AnswerDistribution> {
+    let ServerNetworkMessage::AnswerDistribution(distribution) =
+        receive_server_network_msg(receiver).await?
+    else {
+        bail!("Expected AnswerDistribution")
+    };
+
+    Ok(distribution)
+}
+
+#[allow(dead_code)]
+pub async fn receive_lock_update(receiver: &mut Receiver) -> anyhow::Result<LockUpdate> {
+    let ServerNetworkMessage::LockUpdate(update) = receive_server_network_msg(receiver).await?
+    else {
+        bail!("Expected LockUpdate")
+    };
+
+    Ok(update)
+}
+
 #[allow(dead_code)]
 pub async fn receive_show_leaderboard(receiver: &mut Receiver) -> anyhow::Result<ShowLeaderboard> {
     let ServerNetworkMessage::ShowLeaderboard(show) = receive_server_network_msg(receiver).await?
@@ -248,6 +353,8 @@ pub async fn send_question_answer(
                 }
             })
             .collect(),
+        written_answer: None,
+        order: None,
     });
 
     // send the answer
@@ -257,3 +364,106 @@ pub async fn send_question_answer(
 
     Ok(())
 }
+
+#[allow(dead_code)]
+pub async fn send_written_answer(
+    sender: &mut Sender,
+    player: &PlayerData,
+    index: usize,
+    answer: WrittenAnswer,
+) -> anyhow::Result<()> {
+    let answer = ClientNetworkMessage::AnswerSelected(AnswerSelected {
+        player_uuid: player.uuid,
+        question_index: index,
+        answers: HashSet::new(),
+        written_answer: Some(answer),
+        order: None,
+    });
+
+    // send the answer
+    sender
+        .send(Message::Text(serde_json::to_string(&answer)?))
+        .await?;
+
+    Ok(())
+}
+
+#[allow(dead_code)]
+pub async fn send_order_answer(
+    sender: &mut Sender,
+    player: &PlayerData,
+    index: usize,
+    order: Vec<Uuid>,
+) -> anyhow::Result<()> {
+    let answer = ClientNetworkMessage::AnswerSelected(AnswerSelected {
+        player_uuid: player.uuid,
+        question_index: index,
+        answers: HashSet::new(),
+        written_answer: None,
+        order: Some(order),
+    });
+
+    // send the answer
+    sender
+        .send(Message::Text(serde_json::to_string(&answer)?))
+        .await?;
+
+    Ok(())
+}
+
+#[allow(dead_code)]
+/// Logs in as a remote teacher with the `token` and returns the response.
+pub async fn login_teacher(
+    sender: &mut Sender,
+    receiver: &mut Receiver,
+    token: &str,
+) -> anyhow::Result<TeacherLoginResponse> {
+    let msg = ClientNetworkMessage::TeacherLoginRequest(TeacherLoginRequest {
+        token: token.to_string(),
+        protocol_version: PROTOCOL_VERSION,
+    });
+
+    sender
+        .send(Message::Text(serde_json::to_string(&msg)?))
+        .await?;
+
+    let ServerNetworkMessage::TeacherLoginResponse(response) =
+        receive_server_network_msg(receiver).await?
+    else {
+        bail!("Expected TeacherLoginResponse")
+    };
+
+    Ok(response)
+}
+
+#[allow(dead_code)]
+/// Connects as a remote teacher with [`TEACHER_TOKEN`] and receives the state of the game
+/// every teacher gets after logging in (the players, the lock and the list of questions).
+pub async fn login_teacher_and_drain() -> anyhow::Result<(Sender, Receiver)> {
+    let (mut sender, mut receiver) = connect_to_server().await;
+
+    let response = login_teacher(&mut sender, &mut receiver, TEACHER_TOKEN).await?;
+    if response.can_login != CanJoin::Yes {
+        bail!("Teacher could not log in: {:?}", response.can_login);
+    }
+
+    receive_players_update(&mut receiver).await?;
+    receive_lock_update(&mut receiver).await?;
+    receive_question_list(&mut receiver).await?;
+
+    Ok((sender, receiver))
+}
+
+#[allow(dead_code)]
+pub async fn send_teacher_command(
+    sender: &mut Sender,
+    command: TeacherCommand,
+) -> anyhow::Result<()> {
+    let msg = ClientNetworkMessage::TeacherCommand(command);
+
+    sender
+        .send(Message::Text(serde_json::to_string(&msg)?))
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::{thread::JoinHandle, time::Duration};
+
+use actix::Addr;
+use common::{
+    constants::DEFAULT_PORT,
+    messages::network::{CanJoin, JoinResponse, TryJoinResponse},
+};
+use futures_util::{SinkExt, StreamExt};
+use rstest::rstest;
+use server::{
+    lobby::{Lobby, Phase},
+    messages::lobby::{self, StartQuestion},
+    Teacher,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+use tungstenite::Message;
+use uuid::Uuid;
+
+use crate::{
+    fixtures::create_server_and_teacher::create_server_and_teacher,
+    mocks::get_server_state_handler::GetServerState,
+};
+
+async fn http_get(path: &str) -> anyhow::Result<String> {
+    let mut socket = TcpStream::connect(("localhost", DEFAULT_PORT)).await?;
+    socket
+        .write_all(format!("GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").as_bytes())
+        .await?;
+
+    // the server closes the connection after the response
+    let mut response = String::new();
+    socket.read_to_string(&mut response).await?;
+
+    Ok(response)
+}
+
+async fn receive_text(receiver: &mut utils::Receiver) -> anyhow::Result<String> {
+    let Message::Text(text) = receiver.next().await.expect("Connection closed")? else {
+        anyhow::bail!("Expected a text message");
+    };
+
+    Ok(text)
+}
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn web_client_is_served(
+    create_server_and_teacher: (JoinHandle<()>, Addr<Lobby>, JoinHandle<()>, Addr<Teacher>),
+) -> anyhow::Result<()> {
+    let (server_thread, server, teacher_thread, teacher) = create_server_and_teacher;
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let page = http_get("/").await?;
+    assert!(page.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(page.contains("Content-Type: text/html"));
+    assert!(page.contains("new WebSocket("));
+
+    let missing = http_get("/favicon.ico").await?;
+    assert!(missing.starts_with("HTTP/1.1 404 Not Found\r\n"));
+
+    // play as a headless browser: the messages below are written exactly as the page sends them
+    let (mut sender, mut receiver) = utils::connect_to_server().await;
+    let uuid = Uuid::new_v4();
+
+    sender
+        .send(Message::Text(format!(
+            r#"{{"TryJoinRequest":{{"uuid":"{uuid}","protocol_version":1,"features":["reconnect","written_answers","ordering"]}}}}"#
+        )))
+        .await?;
+    let response = receive_text(&mut receiver).await?;
+    let response: TryJoinResponse = serde_json::from_value(
+        serde_json::from_str::<serde_json::Value>(&response)?["TryJoinResponse"].take(),
+    )?;
+    assert_eq!(response.can_join, CanJoin::Yes);
+
+    sender
+        .send(Message::Text(format!(
+            r#"{{"JoinRequest":{{"player_data":{{"uuid":"{uuid}","nickname":"Browser","color":"Cyan"}}}}}}"#
+        )))
+        .await?;
+    let response = receive_text(&mut receiver).await?;
+    let response: JoinResponse = serde_json::from_value(
+        serde_json::from_str::<serde_json::Value>(&response)?["JoinResponse"].take(),
+    )?;
+    assert_eq!(response.can_join, CanJoin::Yes);
+    assert_eq!(response.players[0].nickname, "Browser");
+
+    server.send(StartQuestion).await??;
+    let question = utils::receive_next_question(&mut receiver).await?;
+    let choice = question.question.choices[1].id;
+
+    sender
+        .send(Message::Text(format!(
+            r#"{{"AnswerSelected":{{"player_uuid":"{uuid}","question_index":0,"answers":["{choice}"]}}}}"#
+        )))
+        .await?;
+    let ended = utils::receive_question_ended(&mut receiver).await?;
+    assert_eq!(ended.player_answer, Some([choice].into()));
+
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.phase, Phase::AfterQuestion(0));
+    assert!(state.results[&0][&uuid].points_awarded > 0);
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+mod fixtures;
+mod mocks;
+mod utils;
+
+use std::{path::Path, time::Duration};
+
+use common::{
+    messages::network::WrittenAnswer,
+    questions::{QuestionSet, QuestionType},
+};
+use rstest::rstest;
+use server::{
+    lobby::Phase,
+    messages::lobby::{self, StartQuestion, SwitchToLeaderboard},
+};
+
+use crate::{
+    fixtures::{
+        create_server::create_server, create_server_and_teacher::create_server_and_teacher,
+    },
+    mocks::get_server_state_handler::GetServerState,
+};
+
+#[rstest]
+#[tokio::test]
+#[timeout(Duration::from_secs(10))]
+async fn written_answers_are_graded() -> anyhow::Result<()> {
+    let questions =
+        QuestionSet::from_file(Path::new("../common/tests/files/ok_question_types.yaml"))?;
+
+    let (server_thread, server, teacher_thread, teacher) =
+        create_server_and_teacher(create_server(questions));
+
+    let (mut fst_sender, mut fst_receiver, fst_player) = utils::join_new_player().await?;
+    let (mut snd_sender, mut snd_receiver, snd_player) = utils::join_new_player().await?;
+    let _fst_players_update = utils::receive_players_update(&mut fst_receiver).await?;
+
+    // the first question expects a text answer
+    server.send(StartQuestion).await??;
+    let question = utils::receive_next_question(&mut fst_receiver).await?;
+    let _ = utils::receive_next_question(&mut snd_receiver).await?;
+
+    assert_eq!(question.question.kind, QuestionType::Text);
+    assert!(question.question.choices.is_empty());
+
+    utils::send_written_answer(
+        &mut fst_sender,
+        &fst_player,
+        0,
+        WrittenAnswer::Text("  prague ".to_string()),
+    )
+    .await?;
+    let _ = utils::receive_question_update(&mut fst_receiver).await?;
+    let _ = utils::receive_question_update(&mut snd_receiver).await?;
+
+    utils::send_written_answer(
+        &mut snd_sender,
+        &snd_player,
+        0,
+        WrittenAnswer::Text("Brno".to_string()),
+    )
+    .await?;
+
+    // everybody answered, so the question ends and the players see their answers
+    let fst_ended = utils::receive_question_ended(&mut fst_receiver).await?;
+    let snd_ended = utils::receive_question_ended(&mut snd_receiver).await?;
+
+    assert_eq!(
+        fst_ended.player_written_answer,
+        Some(WrittenAnswer::Text("  prague ".to_string()))
+    );
+    assert_eq!(
+        snd_ended.player_written_answer,
+        Some(WrittenAnswer::Text("Brno".to_string()))
+    );
+
+    let state = server.send(GetServerState).await?;
+    assert_eq!(state.phase, Phase::AfterQuestion(0));
+    assert!(state.results[&0][&fst_player.uuid].points_awarded > 0);
+    assert_eq!(state.results[&0][&snd_player.uuid].points_awarded, 0);
+
+    // the second question expects a number
+    server.send(SwitchToLeaderboard).await??;
+    let _ = utils::receive_show_leaderboard(&mut fst_receiver).await?;
+    let _ = utils::receive_show_leaderboard(&mut snd_receiver).await?;
+
+    server.send(StartQuestion).await??;
+    let question = utils::receive_next_question(&mut fst_receiver).await?;
+    let _ = utils::receive_next_question(&mut snd_receiver).await?;
+
+    assert_eq!(question.question.kind, QuestionType::Numeric);
+
+    utils::send_written_answer(&mut fst_sender, &fst_player, 1, WrittenAnswer::Number(8.0)).await?;
+    let _ = utils::receive_question_update(&mut fst_receiver).await?;
+
+    let state = server.send(GetServerState).await?;
+    assert!(state.results[&1][&fst_player.uuid].points_awarded > 0);
+
+    server.send(lobby::HardStop).await?;
+    server_thread.join().expect("Server thread panicked");
+
+    teacher.send(common::terminal::messages::Stop).await??;
+    teacher_thread.join().expect("Teacher thread panicked");
+
+    Ok(())
+}